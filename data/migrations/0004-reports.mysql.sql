@@ -0,0 +1,28 @@
+CREATE TABLE
+    IF NOT EXISTS `reports` (
+        `id` INTEGER NOT NULL AUTO_INCREMENT,
+        `reporter_id` INTEGER NOT NULL,
+        `reporter_name` VARCHAR(16) NOT NULL,
+        `reportee_name` VARCHAR(16) NOT NULL,
+        `message` TEXT NOT NULL,
+        `status` INTEGER NOT NULL DEFAULT 0,
+        `assignee` VARCHAR(16),
+        `resolution` TEXT,
+        `reporter_map` INTEGER NOT NULL,
+        `reporter_x` INTEGER NOT NULL,
+        `reporter_y` INTEGER NOT NULL,
+        `reporter_chat` TEXT,
+        `reportee_map` INTEGER,
+        `reportee_x` INTEGER,
+        `reportee_y` INTEGER,
+        `reportee_chat` TEXT,
+        `reporter_notified` INTEGER NOT NULL DEFAULT 0,
+        `created_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        `resolved_at` DATETIME,
+        FOREIGN KEY (`reporter_id`) REFERENCES `characters` (`id`) ON DELETE CASCADE,
+        PRIMARY KEY (`id`)
+    );
+
+CREATE INDEX IF NOT EXISTS `idx_reports_status` ON `reports` (`status`);
+
+CREATE INDEX IF NOT EXISTS `idx_reports_reporter_id` ON `reports` (`reporter_id`);
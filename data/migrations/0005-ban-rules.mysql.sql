@@ -0,0 +1,10 @@
+ALTER TABLE `bans`
+    ADD COLUMN `id` INTEGER NOT NULL AUTO_INCREMENT PRIMARY KEY FIRST,
+    MODIFY COLUMN `ip` VARCHAR(49),
+    ADD COLUMN `hdid` VARCHAR(32) AFTER `ip`,
+    ADD COLUMN `reason` TEXT AFTER `duration`,
+    ADD COLUMN `banned_by` VARCHAR(16) AFTER `reason`;
+
+ALTER TABLE `history` MODIFY COLUMN `ip` VARCHAR(45) NOT NULL;
+
+CREATE INDEX IF NOT EXISTS `idx_bans_hdid` ON `bans` (`hdid`);
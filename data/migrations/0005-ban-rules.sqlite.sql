@@ -0,0 +1,32 @@
+CREATE TABLE
+    `bans_new` (
+        `id` INTEGER PRIMARY KEY,
+        `account_id` INTEGER,
+        `ip` VARCHAR(49),
+        `hdid` VARCHAR(32),
+        `duration` INTEGER,
+        `reason` TEXT,
+        `banned_by` VARCHAR(16),
+        `created_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        FOREIGN KEY (`account_id`) REFERENCES `accounts` (`id`) ON DELETE CASCADE
+    );
+
+INSERT INTO
+    `bans_new` (`account_id`, `ip`, `duration`, `created_at`)
+SELECT
+    `account_id`,
+    `ip`,
+    `duration`,
+    `created_at`
+FROM
+    `bans`;
+
+DROP TABLE `bans`;
+
+ALTER TABLE `bans_new` RENAME TO `bans`;
+
+CREATE INDEX IF NOT EXISTS `idx_bans_account_id` ON `bans` (`account_id`);
+
+CREATE INDEX IF NOT EXISTS `idx_bans_ip` ON `bans` (`ip`);
+
+CREATE INDEX IF NOT EXISTS `idx_bans_hdid` ON `bans` (`hdid`);
@@ -0,0 +1,18 @@
+CREATE TABLE
+    IF NOT EXISTS `audit_log` (
+        `id` INTEGER PRIMARY KEY,
+        `character_id` INTEGER,
+        `admin_name` VARCHAR(16) NOT NULL,
+        `admin_level` INTEGER NOT NULL,
+        `command` VARCHAR(32) NOT NULL,
+        `target` VARCHAR(64),
+        `args` TEXT,
+        `map` INTEGER NOT NULL,
+        `x` INTEGER NOT NULL,
+        `y` INTEGER NOT NULL,
+        `created_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
+
+CREATE INDEX IF NOT EXISTS `idx_audit_log_admin_name` ON `audit_log` (`admin_name`);
+
+CREATE INDEX IF NOT EXISTS `idx_audit_log_target` ON `audit_log` (`target`);
@@ -0,0 +1,21 @@
+CREATE TABLE
+    IF NOT EXISTS `boss_kills` (
+        `id` INTEGER PRIMARY KEY,
+        `npc_id` INTEGER NOT NULL,
+        `map` INTEGER NOT NULL,
+        `killer_character_id` INTEGER NOT NULL,
+        `duration` INTEGER NOT NULL,
+        `created_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
+
+CREATE TABLE
+    IF NOT EXISTS `boss_kill_participants` (
+        `boss_kill_id` INTEGER NOT NULL,
+        `character_id` INTEGER NOT NULL,
+        `damage_dealt` INTEGER NOT NULL,
+        PRIMARY KEY (`boss_kill_id`, `character_id`)
+    );
+
+CREATE INDEX IF NOT EXISTS `idx_boss_kills_npc_id` ON `boss_kills` (`npc_id`);
+
+CREATE INDEX IF NOT EXISTS `idx_boss_kill_participants_character_id` ON `boss_kill_participants` (`character_id`);
@@ -0,0 +1,9 @@
+CREATE TABLE
+    IF NOT EXISTS `character_pets` (
+        `character_id` INTEGER NOT NULL,
+        `npc_id` INTEGER NOT NULL,
+        `experience` INTEGER NOT NULL DEFAULT 0,
+        `summoned` INTEGER NOT NULL DEFAULT 0,
+        PRIMARY KEY (`character_id`, `npc_id`),
+        FOREIGN KEY (`character_id`) REFERENCES `characters` (`id`) ON DELETE CASCADE
+    );
@@ -0,0 +1 @@
+ALTER TABLE `board_posts` MODIFY COLUMN `character_id` INTEGER;
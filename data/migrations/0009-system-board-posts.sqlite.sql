@@ -0,0 +1,33 @@
+CREATE TABLE
+    `board_posts_new` (
+        `id` INTEGER PRIMARY KEY,
+        `board_id` TINYINT NOT NULL,
+        `character_id` INTEGER,
+        `subject` VARCHAR(32) NOT NULL,
+        `body` VARCHAR(2048) NOT NULL,
+        `created_at` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        FOREIGN KEY (`character_id`) REFERENCES `characters` (`id`) ON DELETE CASCADE
+    );
+
+INSERT INTO
+    `board_posts_new` (
+        `id`,
+        `board_id`,
+        `character_id`,
+        `subject`,
+        `body`,
+        `created_at`
+    )
+SELECT
+    `id`,
+    `board_id`,
+    `character_id`,
+    `subject`,
+    `body`,
+    `created_at`
+FROM
+    `board_posts`;
+
+DROP TABLE `board_posts`;
+
+ALTER TABLE `board_posts_new` RENAME TO `board_posts`;
@@ -0,0 +1,2 @@
+ALTER TABLE `characters`
+    ADD COLUMN `pk_ticks` INTEGER NOT NULL DEFAULT 0 AFTER `usage`;
@@ -0,0 +1,124 @@
+use std::cmp;
+
+use config::{Config, ConfigError, File};
+use eolib::{data::THREE_MAX, protocol::r#pub::server::DropRecord};
+
+#[derive(Debug, Deserialize)]
+pub struct BossAdd {
+    pub npc_id: i32,
+    #[serde(default = "default_amount")]
+    pub amount: i32,
+    /// Movement speed, the same as a map spawn's spawn type (0-6)
+    #[serde(default = "default_speed")]
+    pub speed: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BossPhase {
+    /// Phase starts once the boss drops to or below this HP percentage
+    pub hp_percentage: i32,
+    /// Said by the boss when the phase starts
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub adds: Vec<BossAdd>,
+    /// Seconds between add waves, 0 to only spawn them when the phase starts
+    #[serde(default)]
+    pub add_wave_seconds: i32,
+    /// Seconds between quake and effect mechanics, 0 to disable them
+    #[serde(default)]
+    pub mechanic_seconds: i32,
+    #[serde(default)]
+    pub quake: i32,
+    #[serde(default)]
+    pub effect_id: i32,
+    #[serde(default)]
+    pub effect_radius: i32,
+    #[serde(default)]
+    pub effect_damage: i32,
+    #[serde(default)]
+    pub drops: Vec<DropRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Boss {
+    pub npc_id: i32,
+    /// Seconds into the fight before the boss enrages, 0 to never enrage
+    #[serde(default)]
+    pub enrage_seconds: i32,
+    #[serde(default = "default_enrage_damage_multiplier")]
+    pub enrage_damage_multiplier: f32,
+    #[serde(default)]
+    pub enrage_message: String,
+    pub phases: Vec<BossPhase>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Bosses {
+    pub bosses: Vec<Boss>,
+}
+
+impl Bosses {
+    pub fn new() -> Result<Self, ConfigError> {
+        let s = Config::builder()
+            .add_source(File::with_name("config/Bosses.ron").required(false))
+            .add_source(File::with_name("config/Bosses.local.ron").required(false))
+            .set_default("bosses", Vec::<String>::new())?
+            .build()?;
+
+        let mut s: Bosses = s.try_deserialize()?;
+
+        for boss in s.bosses.iter_mut() {
+            boss.phases
+                .sort_by_key(|phase| cmp::Reverse(phase.hp_percentage));
+
+            for drop in boss
+                .phases
+                .iter_mut()
+                .flat_map(|phase| phase.drops.iter_mut())
+            {
+                drop.rate = drop.rate.clamp(0, 100);
+                drop.min_amount = drop.min_amount.clamp(0, THREE_MAX);
+                drop.max_amount = drop.max_amount.clamp(0, THREE_MAX);
+                drop.min_amount = cmp::min(drop.min_amount, drop.max_amount);
+                drop.max_amount = cmp::max(drop.min_amount, drop.max_amount);
+                drop.rate *= 640;
+            }
+
+            for add in boss
+                .phases
+                .iter_mut()
+                .flat_map(|phase| phase.adds.iter_mut())
+            {
+                add.speed = add.speed.clamp(0, 6);
+            }
+        }
+
+        Ok(s)
+    }
+
+    pub fn get(&self, npc_id: i32) -> Option<&Boss> {
+        self.bosses.iter().find(|boss| boss.npc_id == npc_id)
+    }
+}
+
+impl Boss {
+    /// Index of the last phase whose threshold the boss has dropped to
+    pub fn get_phase(&self, hp_percentage: i32) -> Option<usize> {
+        self.phases
+            .iter()
+            .rposition(|phase| hp_percentage <= phase.hp_percentage)
+    }
+}
+
+fn default_amount() -> i32 {
+    1
+}
+
+fn default_speed() -> i32 {
+    1
+}
+
+fn default_enrage_damage_multiplier() -> f32 {
+    2.0
+}
@@ -12,25 +12,39 @@ use evalexpr::{DefaultNumericTypes, HashMapContext, context_map, eval_float_with
 use rand::RngExt;
 use std::cmp;
 
-use crate::{EXP_TABLE, FORMULAS, QUEST_DB, SETTINGS, db::DbHandle, player::PlayerHandle};
+use crate::{
+    EXP_TABLE, FORMULAS, QUEST_DB, SETTINGS, db::DbHandle, player::PlayerHandle,
+    utils::is_party_shared_quest,
+};
 
 mod add_bank_item;
 mod add_item;
 mod calculate_stats;
+mod camping_tracker;
+pub use camping_tracker::{CampingTracker, get_camping_reward};
 mod can_hold;
 mod create;
 mod delete;
 mod destroy_equipment;
+mod do_quest_actions;
 mod equip;
 mod equipment_slot;
 mod get_equipment;
 mod get_spawn_coords;
 mod get_spawn_map;
 mod get_stats;
+mod karma;
 mod load;
+mod movement_tracker;
 pub use equipment_slot::EquipmentSlot;
+pub use movement_tracker::MovementTracker;
+mod quest_repeat;
+mod quest_rules;
+mod quest_stat;
+mod remap_quests;
 mod remove_item;
 mod reset;
+mod shared_quest_items;
 mod spell_target;
 pub use spell_target::SpellTarget;
 mod equip_result;
@@ -106,9 +120,22 @@ pub struct Character {
     pub usage_ticks: i32,
     pub quests: Vec<QuestProgress>,
     pub captcha_open: bool,
+    /// In-game hour on the character's map, set by the map
+    pub hour: u32,
     pub warp_suck_ticks: i32,
     pub ghost_ticks: i32,
+    /// Seconds left before the character's PK flag wears off
+    pub pk_ticks: i32,
+    pub karma_ticks: i32,
     pub auto_pickup_items: Vec<i32>,
+    pub pets: Vec<CharacterPet>,
+    pub recent_chat: Vec<String>,
+    pub movement: MovementTracker,
+    pub camping: CampingTracker,
+    /// How many quest states are currently being entered, guards against quests that loop
+    pub quest_action_depth: i32,
+    /// Quest reload generation the quest progress was last remapped to
+    pub quest_generation: usize,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -119,6 +146,12 @@ pub struct QuestProgress {
     pub player_kills: i32,
     pub done_at: Option<NaiveDateTime>,
     pub completions: i32,
+    // Not persisted, only used by rules checked within the same session
+    pub item_uses: Vec<(i32, i32)>,
+    pub spell_uses: Vec<(i32, i32)>,
+    pub roll: i32,
+    /// Items looted by party members counted towards GotItems in the current state
+    pub shared_items: Vec<(i32, i32)>,
 }
 
 impl QuestProgress {
@@ -135,6 +168,73 @@ impl QuestProgress {
             None => 0,
         }
     }
+
+    pub fn get_npc_kills_json(&self) -> String {
+        let mut npc_kills = String::from('{');
+        for (index, (npc_id, kills)) in self.npc_kills.iter().enumerate() {
+            npc_kills.push_str(&format!(
+                "\"{}\":{}{}",
+                npc_id,
+                kills,
+                if index < self.npc_kills.len() - 1 {
+                    ","
+                } else {
+                    ""
+                }
+            ));
+        }
+        npc_kills.push('}');
+        npc_kills
+    }
+
+    pub fn add_item_use(&mut self, item_id: i32) {
+        match self.item_uses.iter_mut().find(|(id, _)| item_id == *id) {
+            Some((_, uses)) => *uses += 1,
+            None => self.item_uses.push((item_id, 1)),
+        }
+    }
+
+    pub fn get_item_uses(&self, item_id: i32) -> i32 {
+        match self.item_uses.iter().find(|(id, _)| item_id == *id) {
+            Some((_, uses)) => *uses,
+            None => 0,
+        }
+    }
+
+    pub fn add_spell_use(&mut self, spell_id: i32) {
+        match self.spell_uses.iter_mut().find(|(id, _)| spell_id == *id) {
+            Some((_, uses)) => *uses += 1,
+            None => self.spell_uses.push((spell_id, 1)),
+        }
+    }
+
+    pub fn get_spell_uses(&self, spell_id: i32) -> i32 {
+        match self.spell_uses.iter().find(|(id, _)| spell_id == *id) {
+            Some((_, uses)) => *uses,
+            None => 0,
+        }
+    }
+
+    pub fn add_shared_items(&mut self, item_id: i32, amount: i32) {
+        match self.shared_items.iter_mut().find(|(id, _)| item_id == *id) {
+            Some((_, shared)) => *shared += amount,
+            None => self.shared_items.push((item_id, amount)),
+        }
+    }
+
+    pub fn get_shared_items(&self, item_id: i32) -> i32 {
+        match self.shared_items.iter().find(|(id, _)| item_id == *id) {
+            Some((_, shared)) => *shared,
+            None => 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CharacterPet {
+    pub npc_id: i32,
+    pub experience: i32,
+    pub summoned: bool,
 }
 
 impl Character {
@@ -275,6 +375,18 @@ impl Character {
         self.spells.iter().any(|spell| spell.id == spell_id)
     }
 
+    pub fn record_chat(&mut self, message: &str) {
+        if SETTINGS.reports.chat_history == 0 {
+            return;
+        }
+
+        if self.recent_chat.len() >= SETTINGS.reports.chat_history {
+            self.recent_chat.remove(0);
+        }
+
+        self.recent_chat.push(message.to_owned());
+    }
+
     pub fn is_guild_leader(&self) -> bool {
         self.guild_rank == Some(1)
     }
@@ -300,10 +412,21 @@ impl Character {
         }
     }
 
+    /// Moves a quest to a state and runs the state's actions, like a quest's own SetState
+    pub fn set_quest_state(&mut self, quest_id: i32, state: i32) {
+        self.save_quest_progress(quest_id, state);
+        self.do_quest_actions(quest_id);
+    }
+
     pub async fn save(
         &mut self,
         db: &DbHandle,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let _lock = QUEST_DB.lock_progress().await;
+        for message in self.remap_quests() {
+            warn!("{}", message);
+        }
+
         if self.id > 0 {
             self.update(db).await
         } else {
@@ -350,19 +473,7 @@ impl Character {
                             _ => return false,
                         };
 
-                        let done_at = match progress.done_at {
-                            Some(done_at) => done_at,
-                            None => return false,
-                        };
-
-                        let diff = (Utc::now().naive_utc() - done_at).num_days() as i32;
-                        if diff < 1 {
-                            progress.completions >= times_per_day
-                        } else {
-                            progress.completions = 0;
-                            progress.done_at = None;
-                            false
-                        }
+                        progress.on_repeat_cooldown(times_per_day)
                     } else {
                         rule.name == "InputNpc" && rule.args[0] == Arg::Int(action_id)
                     }
@@ -391,9 +502,14 @@ impl Character {
         }
     }
 
-    pub fn killed_npc(&mut self, npc_id: i32) {
+    /// `shared` is set when the kill was made by a party member
+    pub fn killed_npc(&mut self, npc_id: i32, shared: bool) {
         let mut quests_progressed = Vec::new();
         for progress in self.quests.iter_mut() {
+            if shared && !is_party_shared_quest(progress.id) {
+                continue;
+            }
+
             let quest = match QUEST_DB.get(&progress.id) {
                 Some(quest) => quest,
                 None => continue,
@@ -480,7 +596,9 @@ impl Character {
         }
     }
 
-    pub fn entered_coord(&mut self) {
+    /// EnterCoord fires on stepping onto the coord and LeaveCoord on stepping
+    /// off of it, standing still or walking elsewhere triggers neither
+    pub fn entered_coord(&mut self, previous_coords: Coords) {
         let mut quests_progressed = Vec::new();
         let map_id = self.map_id;
         let coords = self.coords;
@@ -496,10 +614,14 @@ impl Character {
             };
 
             let rule = match state.rules.iter().find(|rule| {
-                rule.name == "EnterCoord"
-                    && rule.args[0] == Arg::Int(map_id)
-                    && rule.args[1] == Arg::Int(coords.x)
-                    && rule.args[2] == Arg::Int(coords.y)
+                let is_coord = |coords: Coords| {
+                    rule.args.len() == 3
+                        && rule.args[0] == Arg::Int(map_id)
+                        && rule.args[1] == Arg::Int(coords.x)
+                        && rule.args[2] == Arg::Int(coords.y)
+                };
+                (rule.name == "EnterCoord" && is_coord(coords))
+                    || (rule.name == "LeaveCoord" && is_coord(previous_coords) && !is_coord(coords))
             }) {
                 Some(rule) => rule,
                 None => continue,
@@ -522,96 +644,4 @@ impl Character {
             self.do_quest_actions(quest_id);
         }
     }
-
-    fn do_quest_actions(&mut self, quest_id: i32) {
-        let state = match self.quests.iter().find(|progress| progress.id == quest_id) {
-            Some(progress) => progress.state,
-            None => return,
-        };
-
-        let quest = match QUEST_DB.get(&quest_id) {
-            Some(quest) => quest,
-            None => return,
-        };
-
-        let state = match quest.states.get(state as usize) {
-            Some(state) => state,
-            None => return,
-        };
-
-        let player = match self.player {
-            Some(ref player) => player,
-            None => return,
-        };
-
-        for action in state.actions.iter() {
-            match action.name.as_str() {
-                "AddNpcText" | "AddNpcChat" | "AddNpcInput" => {}
-                "End" => {
-                    self.quests
-                        .iter_mut()
-                        .find(|q| q.id == quest_id)
-                        .unwrap()
-                        .done_at = Some(Utc::now().naive_utc());
-                }
-                "ResetDaily" => {
-                    let progress = self.quests.iter_mut().find(|q| q.id == quest_id).unwrap();
-                    if progress.done_at.is_none() {
-                        progress.done_at = Some(Utc::now().naive_utc());
-                    }
-                    progress.completions += 1;
-                    progress.state = 0;
-                }
-                "Reset" => {
-                    let progress = self.quests.iter_mut().find(|q| q.id == quest_id).unwrap();
-                    if progress.done_at.is_none() {
-                        self.quests.retain(|q| q.id != quest_id)
-                    } else {
-                        progress.state = 0;
-                    }
-                }
-                _ => player.quest_action(action.name.to_owned(), action.args.to_owned()),
-            }
-        }
-
-        if let Some(rule) = state.rules.iter().find(|rule| rule.name == "Always")
-            && let Some(progress) = self.quests.iter_mut().find(|q| q.id == quest_id)
-            && let Some(next_state) = quest
-                .states
-                .iter()
-                .position(|state| state.name == rule.goto)
-        {
-            progress.state = next_state as i32;
-            self.do_quest_actions(quest_id);
-        }
-
-        if let Some(rule) = state.rules.iter().find(|rule| rule.name == "GotItems")
-            && let Some(progress) = self.quests.iter_mut().find(|q| q.id == quest_id)
-        {
-            let item_id = match rule.args[0] {
-                Arg::Int(item_id) => item_id,
-                _ => return,
-            };
-
-            let amount = match rule.args[1] {
-                Arg::Int(amount) => amount,
-                _ => return,
-            };
-
-            let item = match self.items.iter().find(|i| i.id == item_id) {
-                Some(item) => item,
-                None => return,
-            };
-
-            if item.amount >= amount
-                && let Some(next_state) = quest
-                    .states
-                    .iter()
-                    .position(|state| state.name == rule.goto)
-            {
-                progress.state = next_state as i32;
-                self.do_quest_actions(quest_id);
-            }
-        }
-    }
 }
@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+
+/// Recent kills of the same spawn npcs, used to cut the rewards of spawn campers
+#[derive(Debug, Clone, Default)]
+pub struct CampingTracker {
+    kills: Vec<SpawnKills>,
+}
+
+#[derive(Debug, Clone)]
+struct SpawnKills {
+    map_id: i32,
+    npc_index: i32,
+    count: i32,
+    last_kill: DateTime<Utc>,
+}
+
+impl CampingTracker {
+    /// Records a kill, returning how many times the npc has been killed
+    /// without a break of `window` seconds
+    pub fn add_kill(
+        &mut self,
+        map_id: i32,
+        npc_index: i32,
+        now: DateTime<Utc>,
+        window: i64,
+    ) -> i32 {
+        self.kills
+            .retain(|kills| now.signed_duration_since(kills.last_kill).num_seconds() < window);
+
+        match self
+            .kills
+            .iter_mut()
+            .find(|kills| kills.map_id == map_id && kills.npc_index == npc_index)
+        {
+            Some(kills) => {
+                kills.count += 1;
+                kills.last_kill = now;
+                kills.count
+            }
+            None => {
+                self.kills.push(SpawnKills {
+                    map_id,
+                    npc_index,
+                    count: 1,
+                    last_kill: now,
+                });
+                1
+            }
+        }
+    }
+}
+
+/// Percent of the normal EXP and drop rates left after `kills` kills of the same npc
+pub fn get_camping_reward(kills: i32, free_kills: i32, penalty: i32, min_reward: i32) -> i32 {
+    let reward = 100 - (kills - free_kills).max(0) * penalty.max(0);
+    reward.clamp(min_reward.clamp(0, 100), 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use super::{CampingTracker, get_camping_reward};
+
+    #[test]
+    fn camping_kills_reset_after_window() {
+        let mut tracker = CampingTracker::default();
+        let now = Utc::now();
+
+        assert_eq!(tracker.add_kill(1, 0, now, 60), 1);
+        assert_eq!(tracker.add_kill(1, 0, now + Duration::seconds(30), 60), 2);
+        assert_eq!(tracker.add_kill(1, 1, now + Duration::seconds(30), 60), 1);
+        assert_eq!(tracker.add_kill(1, 0, now + Duration::seconds(120), 60), 1);
+
+        assert_eq!(get_camping_reward(10, 10, 10, 20), 100);
+        assert_eq!(get_camping_reward(13, 10, 10, 20), 70);
+        assert_eq!(get_camping_reward(50, 10, 10, 20), 20);
+    }
+}
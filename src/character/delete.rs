@@ -4,6 +4,14 @@ use super::Character;
 
 impl Character {
     pub async fn delete(&self, db: &DbHandle) -> anyhow::Result<()> {
+        // Keep the character's history rows for auditing, they still reference
+        // the character by name in their details
+        db.execute(&insert_params(
+            r"UPDATE `history` SET `character_id` = NULL WHERE `character_id` = :character_id;",
+            &[("character_id", &self.id)],
+        ))
+        .await?;
+
         db.execute(&insert_params(
             r"DELETE FROM `characters` WHERE `id` = :character_id;",
             &[("character_id", &self.id)],
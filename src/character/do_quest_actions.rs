@@ -0,0 +1,264 @@
+use chrono::Utc;
+use eolib::protocol::net::{
+    PacketAction, PacketFamily,
+    server::{
+        RecoverPlayerServerPacket, RecoverReplyServerPacket, StatSkillPlayerServerPacket,
+        StatSkillRemoveServerPacket, StatSkillTakeServerPacket,
+    },
+};
+use rand::RngExt;
+
+use crate::{QUEST_DB, SPELL_DB};
+
+use super::{
+    Character, QuestProgress,
+    quest_rules::{get_int_arg, get_str_arg},
+};
+
+/// Deepest chain of states entering other states (SetState, SetQuestState,
+/// StartQuest and rules met straight away) before the rest is dropped
+const MAX_QUEST_ACTION_DEPTH: i32 = 32;
+
+impl Character {
+    pub(super) fn do_quest_actions(&mut self, quest_id: i32) {
+        if self.quest_action_depth >= MAX_QUEST_ACTION_DEPTH {
+            warn!(
+                "Quest {} for {} exceeded {} nested state changes, check it for a loop",
+                quest_id, self.name, MAX_QUEST_ACTION_DEPTH
+            );
+            return;
+        }
+
+        self.quest_action_depth += 1;
+        self.run_quest_actions(quest_id);
+        self.quest_action_depth -= 1;
+    }
+
+    fn run_quest_actions(&mut self, quest_id: i32) {
+        // Shared item credit only counts towards the state it was earned in
+        let state = match self
+            .quests
+            .iter_mut()
+            .find(|progress| progress.id == quest_id)
+        {
+            Some(progress) => {
+                progress.shared_items.clear();
+                progress.state
+            }
+            None => return,
+        };
+
+        let quest = match QUEST_DB.get(&quest_id) {
+            Some(quest) => quest,
+            None => return,
+        };
+
+        let state = match quest.states.get(state as usize) {
+            Some(state) => state,
+            None => return,
+        };
+
+        let player = match self.player {
+            Some(ref player) => player.to_owned(),
+            None => return,
+        };
+
+        let mut lost_spells = Vec::new();
+        for action in state.actions.iter() {
+            let args = &action.args;
+            match action.name.as_str() {
+                "AddNpcText" | "AddNpcChat" | "AddNpcInput" => {}
+                "End" => {
+                    if let Some(progress) = self.quests.iter_mut().find(|q| q.id == quest_id) {
+                        progress.done_at = Some(Utc::now().naive_utc());
+                    }
+                }
+                "ResetDaily" => {
+                    if let Some(progress) = self.quests.iter_mut().find(|q| q.id == quest_id) {
+                        if progress.done_at.is_none() {
+                            progress.done_at = Some(Utc::now().naive_utc());
+                        }
+                        progress.completions += 1;
+                        progress.state = 0;
+                    }
+                }
+                "Reset" => {
+                    if let Some(progress) = self.quests.iter_mut().find(|q| q.id == quest_id) {
+                        if progress.done_at.is_none() {
+                            self.quests.retain(|q| q.id != quest_id)
+                        } else {
+                            progress.state = 0;
+                        }
+                    }
+                }
+                "SetState" => {
+                    if let Some(next_state) =
+                        get_str_arg(args, 0).and_then(|name| get_state_index(quest_id, &name))
+                    {
+                        self.save_quest_progress(quest_id, next_state);
+                        return self.do_quest_actions(quest_id);
+                    }
+                }
+                "StartQuest" => {
+                    if let Some(id) = get_int_arg(args, 0)
+                        && QUEST_DB.contains_key(&id)
+                        && !self.quests.iter().any(|q| q.id == id)
+                    {
+                        let state = get_str_arg(args, 1)
+                            .and_then(|name| get_state_index(id, &name))
+                            .unwrap_or(0);
+                        self.quests.push(QuestProgress {
+                            id,
+                            state,
+                            ..Default::default()
+                        });
+                        self.do_quest_actions(id);
+                    }
+                }
+                "ResetQuest" => {
+                    if let Some(id) = get_int_arg(args, 0) {
+                        self.quests.retain(|q| q.id != id);
+
+                        // Nothing left for the rest of the state's actions to act on
+                        if id == quest_id {
+                            return;
+                        }
+                    }
+                }
+                "SetQuestState" => {
+                    if let Some(id) = get_int_arg(args, 0)
+                        && let Some(state) =
+                            get_str_arg(args, 1).and_then(|name| get_state_index(id, &name))
+                    {
+                        self.save_quest_progress(id, state);
+                        self.do_quest_actions(id);
+
+                        if id == quest_id {
+                            return;
+                        }
+                    }
+                }
+                "Roll" => {
+                    if let Some(max) = get_int_arg(args, 0)
+                        && max > 0
+                        && let Some(progress) = self.quests.iter_mut().find(|q| q.id == quest_id)
+                    {
+                        let mut rng = rand::rng();
+                        progress.roll = rng.random_range(1..=max);
+                    }
+                }
+                "GiveSpell" => {
+                    if let Some(spell_id) = get_int_arg(args, 0)
+                        && spell_id > 0
+                        && (spell_id as usize) <= SPELL_DB.skills.len()
+                        && !self.has_spell(spell_id)
+                    {
+                        self.add_spell(spell_id);
+                        player.send(
+                            PacketAction::Take,
+                            PacketFamily::StatSkill,
+                            &StatSkillTakeServerPacket {
+                                spell_id,
+                                gold_amount: self.get_item_amount(1),
+                            },
+                        );
+                    }
+                }
+                "RemoveSpell" => {
+                    if let Some(spell_id) = get_int_arg(args, 0)
+                        && self.has_spell(spell_id)
+                    {
+                        self.remove_spell(spell_id);
+                        lost_spells.push(spell_id);
+                        player.send(
+                            PacketAction::Remove,
+                            PacketFamily::StatSkill,
+                            &StatSkillRemoveServerPacket { spell_id },
+                        );
+                    }
+                }
+                "GiveStat" | "RemoveStat" | "SetStat" => {
+                    let (name, amount) = match (get_str_arg(args, 0), get_int_arg(args, 1)) {
+                        (Some(name), Some(amount)) => (name, amount),
+                        _ => continue,
+                    };
+
+                    let level = self.level;
+                    let changed = match action.name.as_str() {
+                        "GiveStat" => self.add_quest_stat(&name, amount),
+                        "RemoveStat" => self.add_quest_stat(&name, -amount),
+                        _ => self.set_quest_stat(&name, amount),
+                    };
+
+                    if changed {
+                        self.send_quest_stats(level);
+                    }
+                }
+                _ => player.quest_action(action.name.to_owned(), action.args.to_owned()),
+            }
+        }
+
+        for spell_id in lost_spells {
+            self.lost_spell(spell_id);
+        }
+
+        self.check_quest_state(quest_id);
+    }
+
+    fn send_quest_stats(&self, previous_level: i32) {
+        let player = match self.player {
+            Some(ref player) => player,
+            None => return,
+        };
+
+        let leveled = self.level != previous_level;
+
+        player.send(
+            PacketAction::Player,
+            PacketFamily::StatSkill,
+            &StatSkillPlayerServerPacket {
+                stat_points: self.stat_points,
+                stats: self.get_character_stats_update(),
+            },
+        );
+
+        player.send(
+            PacketAction::Reply,
+            PacketFamily::Recover,
+            &RecoverReplyServerPacket {
+                experience: self.experience,
+                karma: self.karma,
+                level_up: if leveled { Some(self.level) } else { None },
+                stat_points: if leveled {
+                    Some(self.stat_points)
+                } else {
+                    None
+                },
+                skill_points: if leveled {
+                    Some(self.skill_points)
+                } else {
+                    None
+                },
+            },
+        );
+
+        player.send(
+            PacketAction::Player,
+            PacketFamily::Recover,
+            &RecoverPlayerServerPacket {
+                hp: self.hp,
+                tp: self.tp,
+            },
+        );
+    }
+}
+
+fn get_state_index(quest_id: i32, name: &str) -> Option<i32> {
+    QUEST_DB.get(&quest_id).and_then(|quest| {
+        quest
+            .states
+            .iter()
+            .position(|state| state.name.eq_ignore_ascii_case(name))
+            .map(|index| index as i32)
+    })
+}
@@ -161,6 +161,7 @@ impl Character {
         }
 
         self.calculate_stats();
+        self.check_quest_rules();
 
         result
     }
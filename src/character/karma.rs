@@ -0,0 +1,46 @@
+use std::cmp;
+
+use crate::{SETTINGS, settings::KarmaTier};
+
+use super::Character;
+
+impl Character {
+    pub fn get_karma_tier(&self) -> Option<&'static KarmaTier> {
+        SETTINGS
+            .karma
+            .tiers
+            .iter()
+            .find(|tier| (tier.min_karma..=tier.max_karma).contains(&self.karma))
+    }
+
+    /// Wanted characters are hunted by guards on town maps
+    pub fn is_wanted(&self) -> bool {
+        self.pk_ticks > 0 || self.karma < SETTINGS.guards.wanted_karma
+    }
+
+    pub fn get_shop_buy_price(&self, price: i32) -> i32 {
+        match self.get_karma_tier() {
+            Some(tier) if price > 0 => {
+                cmp::max((price as f32 * tier.shop_buy_multiplier).ceil() as i32, 1)
+            }
+            _ => price,
+        }
+    }
+
+    /// Never more than the adjusted buy price so items can't be bought back for a profit
+    pub fn get_shop_sell_price(&self, sell_price: i32, buy_price: i32) -> i32 {
+        let price = match self.get_karma_tier() {
+            Some(tier) if sell_price > 0 => cmp::max(
+                (sell_price as f32 * tier.shop_sell_multiplier).floor() as i32,
+                1,
+            ),
+            _ => sell_price,
+        };
+
+        if buy_price > 0 {
+            cmp::min(price, self.get_shop_buy_price(buy_price))
+        } else {
+            price
+        }
+    }
+}
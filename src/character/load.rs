@@ -7,11 +7,11 @@ use eolib::protocol::{
 };
 
 use crate::{
-    SETTINGS,
+    QUEST_DB, SETTINGS,
     db::{DbHandle, Row, insert_params},
 };
 
-use super::{Character, QuestProgress};
+use super::{Character, CharacterPet, QuestProgress};
 
 impl Character {
     pub async fn load(db: &DbHandle, id: i32) -> anyhow::Result<Self> {
@@ -45,7 +45,16 @@ impl Character {
             &[("character_id", &id)],
         );
 
-        let (row, items, bank, spells, quest_progress, auto_pickup) = match tokio::join!(
+        let pets_query = insert_params(
+            include_str!("../sql/get_character_pets.sql"),
+            &[("character_id", &id)],
+        );
+
+        // Stored quest progress must match the generation it's loaded with
+        let _lock = QUEST_DB.lock_progress().await;
+        let quest_generation = QUEST_DB.generation();
+
+        let (row, items, bank, spells, quest_progress, auto_pickup, pets) = match tokio::join!(
             db.query_one(&character_query),
             db.try_query_map(&inventory_query, |row| Ok(Item {
                 id: row
@@ -105,10 +114,23 @@ impl Character {
                 completions: row
                     .get_int(5)
                     .ok_or(anyhow::anyhow!("Failed to get quest progress completions"))?,
+                ..Default::default()
             })),
             db.try_query_map(&auto_pickup_query, |row| row
                 .get_int(0)
-                .ok_or(anyhow::anyhow!("Failed to get auto pickup id")))
+                .ok_or(anyhow::anyhow!("Failed to get auto pickup id"))),
+            db.try_query_map(&pets_query, |row| Ok(CharacterPet {
+                npc_id: row
+                    .get_int(0)
+                    .ok_or(anyhow::anyhow!("Failed to get pet npc id"))?,
+                experience: row
+                    .get_int(1)
+                    .ok_or(anyhow::anyhow!("Failed to get pet experience"))?,
+                summoned: row
+                    .get_int(2)
+                    .ok_or(anyhow::anyhow!("Failed to get pet summoned"))?
+                    == 1,
+            }))
         ) {
             (
                 Ok(Some(character)),
@@ -117,13 +139,23 @@ impl Character {
                 Ok(spells),
                 Ok(quest_progress),
                 Ok(auto_pickup),
-            ) => (character, items, bank, spells, quest_progress, auto_pickup),
-            (Err(e), _, _, _, _, _)
-            | (_, Err(e), _, _, _, _)
-            | (_, _, Err(e), _, _, _)
-            | (_, _, _, Err(e), _, _)
-            | (_, _, _, _, Err(e), _)
-            | (_, _, _, _, _, Err(e)) => {
+                Ok(pets),
+            ) => (
+                character,
+                items,
+                bank,
+                spells,
+                quest_progress,
+                auto_pickup,
+                pets,
+            ),
+            (Err(e), _, _, _, _, _, _)
+            | (_, Err(e), _, _, _, _, _)
+            | (_, _, Err(e), _, _, _, _)
+            | (_, _, _, Err(e), _, _, _)
+            | (_, _, _, _, Err(e), _, _)
+            | (_, _, _, _, _, Err(e), _)
+            | (_, _, _, _, _, _, Err(e)) => {
                 return Err(anyhow::anyhow!(
                     "Failed to load character ID: {} data: {}",
                     id,
@@ -144,7 +176,9 @@ impl Character {
                 character.bank = bank;
                 character.spells = spells;
                 character.quests = quest_progress;
+                character.quest_generation = quest_generation;
                 character.auto_pickup_items = auto_pickup;
+                character.pets = pets;
                 Ok(character)
             }
             None => Err(anyhow::anyhow!("Failed to parse character ID: {} data", id)),
@@ -208,6 +242,7 @@ impl Character {
             hidden: row.get_int(50)? == 1,
             guild_name: row.get_string(51),
             guild_tag: row.get_string(52),
+            pk_ticks: row.get_int(53)?,
             warp_suck_ticks: SETTINGS.world.warp_suck_rate,
             ghost_ticks: SETTINGS.world.ghost_rate,
             ..Default::default()
@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+
+/// Step timing and violation history used to validate a character's movement
+#[derive(Debug, Clone, Default)]
+pub struct MovementTracker {
+    last_step: Option<DateTime<Utc>>,
+    /// Steps that can be taken right now, refilled over time up to the burst size
+    allowance: f64,
+    violations: i32,
+    last_violation: Option<DateTime<Utc>>,
+}
+
+impl MovementTracker {
+    /// Records a step, returning false if it came faster than the walk speed allows
+    pub fn step(&mut self, now: DateTime<Utc>, step_ms: i64, burst: i32) -> bool {
+        let burst = burst.max(1) as f64;
+
+        self.allowance = match self.last_step {
+            Some(last_step) => {
+                let elapsed = now.signed_duration_since(last_step).num_milliseconds();
+                (self.allowance + elapsed as f64 / step_ms.max(1) as f64).min(burst)
+            }
+            None => burst,
+        };
+
+        self.last_step = Some(now);
+
+        if self.allowance < 1.0 {
+            return false;
+        }
+
+        self.allowance -= 1.0;
+        true
+    }
+
+    /// Counts a violation, forgetting earlier ones after `decay` seconds without any
+    pub fn add_violation(&mut self, now: DateTime<Utc>, decay: i64) -> i32 {
+        if let Some(last_violation) = self.last_violation
+            && now.signed_duration_since(last_violation).num_seconds() >= decay
+        {
+            self.violations = 0;
+        }
+
+        self.last_violation = Some(now);
+        self.violations += 1;
+        self.violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use super::MovementTracker;
+
+    #[test]
+    fn step_allows_burst_then_walk_speed() {
+        let mut tracker = MovementTracker::default();
+        let start = Utc::now();
+
+        for _ in 0..3 {
+            assert!(tracker.step(start, 360, 3));
+        }
+        assert!(!tracker.step(start, 360, 3));
+
+        let mut now = start;
+        for _ in 0..10 {
+            now += Duration::milliseconds(360);
+            assert!(tracker.step(now, 360, 3));
+        }
+        assert!(!tracker.step(now + Duration::milliseconds(100), 360, 3));
+    }
+
+    #[test]
+    fn violations_decay() {
+        let mut tracker = MovementTracker::default();
+        let now = Utc::now();
+
+        assert_eq!(tracker.add_violation(now, 60), 1);
+        assert_eq!(tracker.add_violation(now + Duration::seconds(30), 60), 2);
+        assert_eq!(tracker.add_violation(now + Duration::seconds(90), 60), 1);
+    }
+}
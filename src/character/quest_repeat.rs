@@ -0,0 +1,158 @@
+use chrono::{Datelike, Duration, NaiveDateTime, NaiveTime, Utc, Weekday};
+use eoplus::{Arg, Quest};
+
+use crate::{SETTINGS, settings::RepeatPeriod};
+
+use super::QuestProgress;
+
+const DAILY: RepeatPeriod = RepeatPeriod::Daily;
+
+impl QuestProgress {
+    /// Checks a DoneDaily rule, clearing completions from a finished repeat
+    /// period. Returns true while no more completions are allowed
+    pub fn on_repeat_cooldown(&mut self, times: i32) -> bool {
+        let done_at = match self.done_at {
+            Some(done_at) => done_at,
+            None => return false,
+        };
+
+        let period = get_repeat_period(self.id);
+        if let Some(end) = get_period_end(period, done_at)
+            && Utc::now().naive_utc() >= end
+        {
+            self.completions = 0;
+            self.done_at = None;
+            return false;
+        }
+
+        self.completions >= get_repeat_limit(period, times)
+    }
+
+    /// Describes when a completed repeatable quest can be done again
+    pub fn get_repeat_status(&self, quest: &Quest) -> Option<String> {
+        let done_at = match self.done_at {
+            Some(done_at) if self.state == 0 => done_at,
+            _ => return None,
+        };
+
+        let times = quest
+            .states
+            .first()
+            .and_then(|state| state.rules.iter().find(|rule| rule.name == "DoneDaily"))
+            .and_then(|rule| match rule.args.first() {
+                Some(Arg::Int(times)) => Some(*times),
+                _ => None,
+            })
+            .unwrap_or(1);
+
+        let period = get_repeat_period(self.id);
+        let limit = get_repeat_limit(period, times);
+
+        let end = match get_period_end(period, done_at) {
+            Some(end) => end,
+            None => return Some(format!("Completed {}/{} times", self.completions, limit)),
+        };
+
+        let remaining = end - Utc::now().naive_utc();
+        if remaining <= Duration::zero() {
+            return None;
+        }
+
+        Some(if self.completions >= limit {
+            format!("Repeatable in {}", format_remaining(remaining))
+        } else {
+            format!(
+                "Completed {}/{} times, resets in {}",
+                self.completions,
+                limit,
+                format_remaining(remaining)
+            )
+        })
+    }
+}
+
+fn get_repeat_period(quest_id: i32) -> &'static RepeatPeriod {
+    SETTINGS
+        .quests
+        .repeat
+        .iter()
+        .find(|repeat| repeat.quest == quest_id)
+        .map_or(&DAILY, |repeat| &repeat.period)
+}
+
+fn get_repeat_limit(period: &RepeatPeriod, times: i32) -> i32 {
+    match period {
+        RepeatPeriod::Lifetime { limit } => *limit,
+        _ => times,
+    }
+}
+
+/// When completions made since `done_at` stop counting, None if they never do
+fn get_period_end(period: &RepeatPeriod, done_at: NaiveDateTime) -> Option<NaiveDateTime> {
+    match period {
+        RepeatPeriod::Daily => Some(done_at + Duration::days(1)),
+        RepeatPeriod::Hours { hours } => Some(done_at + Duration::hours(*hours)),
+        RepeatPeriod::Weekly => Some(get_next_weekly_reset(
+            done_at,
+            SETTINGS
+                .quests
+                .weekly_reset_day
+                .parse()
+                .unwrap_or(Weekday::Mon),
+            SETTINGS.quests.weekly_reset_hour,
+        )),
+        RepeatPeriod::Lifetime { .. } => None,
+    }
+}
+
+fn get_next_weekly_reset(after: NaiveDateTime, day: Weekday, hour: u32) -> NaiveDateTime {
+    let time = NaiveTime::from_hms_opt(hour, 0, 0).unwrap_or_default();
+    let days_ahead = (day.num_days_from_monday() + 7 - after.weekday().num_days_from_monday()) % 7;
+    let reset = (after.date() + Duration::days(days_ahead as i64)).and_time(time);
+
+    if reset > after {
+        reset
+    } else {
+        reset + Duration::weeks(1)
+    }
+}
+
+fn format_remaining(remaining: Duration) -> String {
+    let (amount, unit) = if remaining.num_days() >= 1 {
+        (remaining.num_days(), "day")
+    } else if remaining.num_hours() >= 1 {
+        (remaining.num_hours(), "hour")
+    } else {
+        (remaining.num_minutes().max(1), "minute")
+    };
+
+    format!("{} {}{}", amount, unit, if amount == 1 { "" } else { "s" })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Weekday};
+
+    use super::get_next_weekly_reset;
+
+    #[test]
+    fn next_weekly_reset() {
+        // 2024-01-03 is a Wednesday
+        let at = |day, hour| {
+            NaiveDate::from_ymd_opt(2024, 1, day)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+        };
+
+        assert_eq!(get_next_weekly_reset(at(3, 12), Weekday::Mon, 0), at(8, 0));
+        assert_eq!(
+            get_next_weekly_reset(at(3, 12), Weekday::Wed, 18),
+            at(3, 18)
+        );
+        assert_eq!(
+            get_next_weekly_reset(at(3, 18), Weekday::Wed, 18),
+            at(10, 18)
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use eolib::protocol::Gender;
+use eoplus::{Arg, Rule};
+
+use crate::{
+    QUEST_DB,
+    clock::{TimeOfDay, get_time_of_day, is_hour_between},
+    utils::eval_rpn,
+};
+
+use super::{Character, QuestProgress};
+
+impl Character {
+    /// Re-checks the condition rules of every active quest, call after
+    /// anything a rule might depend on has changed
+    pub fn check_quest_rules(&mut self) {
+        let quest_ids: Vec<i32> = self.quests.iter().map(|progress| progress.id).collect();
+        for quest_id in quest_ids {
+            self.check_quest_state(quest_id);
+        }
+    }
+
+    pub(super) fn check_quest_state(&mut self, quest_id: i32) {
+        let progress = match self.quests.iter().find(|progress| progress.id == quest_id) {
+            Some(progress) => progress,
+            None => return,
+        };
+
+        let quest = match QUEST_DB.get(&quest_id) {
+            Some(quest) => quest,
+            None => return,
+        };
+
+        let state = match quest.states.get(progress.state as usize) {
+            Some(state) => state,
+            None => return,
+        };
+
+        let next_state = match state
+            .rules
+            .iter()
+            .filter(|rule| self.quest_rule_met(progress, rule))
+            .find_map(|rule| {
+                quest
+                    .states
+                    .iter()
+                    .position(|state| state.name == rule.goto)
+            }) {
+            Some(next_state) => next_state,
+            None => return,
+        };
+
+        if let Some(progress) = self.quests.iter_mut().find(|q| q.id == quest_id) {
+            progress.state = next_state as i32;
+            self.do_quest_actions(quest_id);
+        }
+    }
+
+    pub fn killed_player(&mut self) {
+        self.progress_quests(|progress, rule| {
+            if rule.name != "KilledPlayers" {
+                return false;
+            }
+
+            progress.player_kills += 1;
+            matches!(get_int_arg(&rule.args, 0), Some(kills) if progress.player_kills >= kills)
+        });
+    }
+
+    pub fn used_item(&mut self, item_id: i32) {
+        self.progress_quests(|progress, rule| {
+            if rule.name != "UsedItem" || get_int_arg(&rule.args, 0) != Some(item_id) {
+                return false;
+            }
+
+            progress.add_item_use(item_id);
+            matches!(get_int_arg(&rule.args, 1), Some(uses) if progress.get_item_uses(item_id) >= uses)
+        });
+    }
+
+    pub fn used_spell(&mut self, spell_id: i32) {
+        self.progress_quests(|progress, rule| {
+            if rule.name != "UsedSpell" || get_int_arg(&rule.args, 0) != Some(spell_id) {
+                return false;
+            }
+
+            progress.add_spell_use(spell_id);
+            matches!(get_int_arg(&rule.args, 1), Some(uses) if progress.get_spell_uses(spell_id) >= uses)
+        });
+    }
+
+    /// LostSpell only fires when a known spell is removed, never for a
+    /// spell the character didn't have to begin with
+    pub fn lost_spell(&mut self, spell_id: i32) {
+        self.progress_quests(|_, rule| {
+            rule.name == "LostSpell" && get_int_arg(&rule.args, 0) == Some(spell_id)
+        });
+    }
+
+    fn progress_quests<F>(&mut self, mut progressed: F)
+    where
+        F: FnMut(&mut QuestProgress, &Rule) -> bool,
+    {
+        let mut quests_progressed = Vec::new();
+        for progress in self.quests.iter_mut() {
+            let quest = match QUEST_DB.get(&progress.id) {
+                Some(quest) => quest,
+                None => continue,
+            };
+
+            let state = match quest.states.get(progress.state as usize) {
+                Some(state) => state,
+                None => continue,
+            };
+
+            let rule = match state.rules.iter().find(|rule| progressed(progress, rule)) {
+                Some(rule) => rule,
+                None => continue,
+            };
+
+            if let Some(next_state) = quest
+                .states
+                .iter()
+                .position(|state| state.name == rule.goto)
+            {
+                progress.state = next_state as i32;
+                quests_progressed.push(progress.id);
+            }
+        }
+
+        for quest_id in quests_progressed {
+            self.do_quest_actions(quest_id);
+        }
+    }
+
+    /// Checks rules that depend only on the character's current state,
+    /// event rules like TalkedToNpc are handled where the event happens
+    fn quest_rule_met(&self, progress: &QuestProgress, rule: &Rule) -> bool {
+        let args = &rule.args;
+        match rule.name.as_str() {
+            "Always" => true,
+            "GotItems" => match (get_int_arg(args, 0), get_int_arg(args, 1)) {
+                (Some(item_id), Some(amount)) => {
+                    self.get_item_amount(item_id) + progress.get_shared_items(item_id) >= amount
+                }
+                _ => false,
+            },
+            "LostItems" => match (get_int_arg(args, 0), get_int_arg(args, 1)) {
+                (Some(item_id), Some(amount)) => self.get_item_amount(item_id) < amount,
+                _ => false,
+            },
+            "IsGender" => match args.first() {
+                Some(Arg::Int(gender)) => i32::from(self.gender) == *gender,
+                Some(Arg::Str(gender)) => match gender.to_lowercase().as_str() {
+                    "female" | "f" => self.gender == Gender::Female,
+                    "male" | "m" => self.gender == Gender::Male,
+                    _ => false,
+                },
+                None => false,
+            },
+            "IsClass" => get_int_arg(args, 0) == Some(self.class),
+            "IsRace" => get_int_arg(args, 0) == Some(self.skin),
+            "IsWearing" => match get_int_arg(args, 0) {
+                Some(item_id) if item_id > 0 => self.is_wearing(item_id),
+                _ => false,
+            },
+            "GotSpell" => match get_int_arg(args, 0) {
+                Some(spell_id) => {
+                    let level = get_int_arg(args, 1).unwrap_or(0);
+                    self.spells
+                        .iter()
+                        .any(|spell| spell.id == spell_id && spell.level >= level)
+                }
+                None => false,
+            },
+            "CitizenOf" => match get_str_arg(args, 0) {
+                Some(home) => self.home.eq_ignore_ascii_case(&home),
+                None => false,
+            },
+            "IsDay" => get_time_of_day(self.hour) == TimeOfDay::Day,
+            "IsNight" => get_time_of_day(self.hour) == TimeOfDay::Night,
+            "HourBetween" => match (get_int_arg(args, 0), get_int_arg(args, 1)) {
+                (Some(start), Some(end)) if start >= 0 && end >= 0 => {
+                    is_hour_between(self.hour, start as u32, end as u32)
+                }
+                _ => false,
+            },
+            "Rolled" => get_int_arg(args, 0) == Some(progress.roll),
+            "StatIs" | "StatNot" | "StatGreater" | "StatLess" => {
+                let stat = match get_str_arg(args, 0).and_then(|name| self.get_quest_stat(&name)) {
+                    Some(stat) => stat,
+                    None => return false,
+                };
+
+                let value = match get_int_arg(args, 1) {
+                    Some(value) => value,
+                    None => return false,
+                };
+
+                match rule.name.as_str() {
+                    "StatIs" => stat == value,
+                    "StatNot" => stat != value,
+                    "StatGreater" => stat > value,
+                    _ => stat < value,
+                }
+            }
+            "StatBetween" => {
+                match (
+                    get_str_arg(args, 0).and_then(|name| self.get_quest_stat(&name)),
+                    get_int_arg(args, 1),
+                    get_int_arg(args, 2),
+                ) {
+                    (Some(stat), Some(min), Some(max)) => stat >= min && stat <= max,
+                    _ => false,
+                }
+            }
+            "StatRpn" => match get_str_arg(args, 0) {
+                Some(expression) => {
+                    eval_rpn(&expression, |name| self.get_quest_stat(name)).unwrap_or(0) != 0
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn is_wearing(&self, item_id: i32) -> bool {
+        let equipment = &self.equipment;
+        [
+            equipment.boots,
+            equipment.accessory,
+            equipment.gloves,
+            equipment.belt,
+            equipment.armor,
+            equipment.necklace,
+            equipment.hat,
+            equipment.shield,
+            equipment.weapon,
+        ]
+        .iter()
+        .chain(equipment.ring.iter())
+        .chain(equipment.armlet.iter())
+        .chain(equipment.bracer.iter())
+        .any(|id| *id == item_id)
+    }
+}
+
+pub(super) fn get_int_arg(args: &[Arg], index: usize) -> Option<i32> {
+    match args.get(index) {
+        Some(Arg::Int(value)) => Some(*value),
+        Some(Arg::Str(value)) => value.parse().ok(),
+        None => None,
+    }
+}
+
+pub(super) fn get_str_arg(args: &[Arg], index: usize) -> Option<String> {
+    match args.get(index) {
+        Some(Arg::Str(value)) => Some(value.to_owned()),
+        Some(Arg::Int(value)) => Some(value.to_string()),
+        None => None,
+    }
+}
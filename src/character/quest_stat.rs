@@ -0,0 +1,95 @@
+use crate::EXP_TABLE;
+
+use super::Character;
+
+impl Character {
+    /// Gets a stat by the name used in EO+ stat rules and actions
+    pub fn get_quest_stat(&self, name: &str) -> Option<i32> {
+        Some(match name.to_lowercase().as_str() {
+            "level" => self.level,
+            "exp" => self.experience,
+            "str" => self.adj_strength,
+            "int" => self.adj_intelligence,
+            "wis" => self.adj_wisdom,
+            "agi" => self.adj_agility,
+            "con" => self.adj_constitution,
+            "cha" => self.adj_charisma,
+            "base_str" => self.base_strength,
+            "base_int" => self.base_intelligence,
+            "base_wis" => self.base_wisdom,
+            "base_agi" => self.base_agility,
+            "base_con" => self.base_constitution,
+            "base_cha" => self.base_charisma,
+            "hp" => self.hp,
+            "maxhp" => self.max_hp,
+            "tp" => self.tp,
+            "maxtp" => self.max_tp,
+            "maxsp" => self.max_sp,
+            "weight" => self.weight,
+            "maxweight" => self.max_weight,
+            "statpoints" => self.stat_points,
+            "skillpoints" => self.skill_points,
+            "karma" => self.karma,
+            "mindam" => self.min_damage,
+            "maxdam" => self.max_damage,
+            "accuracy" => self.accuracy,
+            "evade" => self.evasion,
+            "armor" => self.armor,
+            "gender" => i32::from(self.gender),
+            "hairstyle" => self.hair_style,
+            "haircolor" => self.hair_color,
+            "race" => self.skin,
+            "class" => self.class,
+            "admin" => i32::from(self.admin_level),
+            "gold" => self.get_item_amount(1),
+            "bankgold" => self.gold_bank,
+            "usage" => self.usage,
+            _ => return None,
+        })
+    }
+
+    /// Sets a stat by the name used in EO+ stat actions, returns false for
+    /// stats that can't be changed by quests
+    pub fn set_quest_stat(&mut self, name: &str, value: i32) -> bool {
+        let value = value.max(0);
+        match name.to_lowercase().as_str() {
+            "level" => self.level = value.min(EXP_TABLE.len() as i32 - 2),
+            "exp" => self.experience = value,
+            "str" | "base_str" => self.base_strength = value,
+            "int" | "base_int" => self.base_intelligence = value,
+            "wis" | "base_wis" => self.base_wisdom = value,
+            "agi" | "base_agi" => self.base_agility = value,
+            "con" | "base_con" => self.base_constitution = value,
+            "cha" | "base_cha" => self.base_charisma = value,
+            "hp" => self.hp = value,
+            "tp" => self.tp = value,
+            "statpoints" => self.stat_points = value,
+            "skillpoints" => self.skill_points = value,
+            "karma" => self.karma = value.min(2000),
+            _ => return false,
+        }
+
+        self.calculate_stats();
+        self.hp = self.hp.min(self.max_hp);
+        self.tp = self.tp.min(self.max_tp);
+        true
+    }
+
+    /// Adds to a stat by name, base stats are changed rather than the adjusted ones
+    pub fn add_quest_stat(&mut self, name: &str, amount: i32) -> bool {
+        let current = match name.to_lowercase().as_str() {
+            "str" => self.base_strength,
+            "int" => self.base_intelligence,
+            "wis" => self.base_wisdom,
+            "agi" => self.base_agility,
+            "con" => self.base_constitution,
+            "cha" => self.base_charisma,
+            name => match self.get_quest_stat(name) {
+                Some(value) => value,
+                None => return false,
+            },
+        };
+
+        self.set_quest_stat(name, current.saturating_add(amount))
+    }
+}
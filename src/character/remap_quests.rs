@@ -0,0 +1,37 @@
+use crate::{
+    QUEST_DB,
+    quest_db::{StateRemap, get_lost_state_message, get_removed_quest_message, remap_quest_state},
+};
+
+use super::Character;
+
+impl Character {
+    /// Re-resolves quest progress for every reload since it was last remapped,
+    /// returning messages for progress that had to be reset or cleared
+    pub fn remap_quests(&mut self) -> Vec<String> {
+        let (history, generation) = QUEST_DB.get_remaps_since(self.quest_generation);
+        self.quest_generation = generation;
+
+        let mut lost = Vec::new();
+        for remaps in history {
+            let name = &self.name;
+            self.quests.retain_mut(|progress| {
+                match remap_quest_state(&remaps, progress.id, progress.state) {
+                    StateRemap::Unchanged => {}
+                    StateRemap::Moved(state) => progress.state = state,
+                    StateRemap::Lost(state) => {
+                        progress.state = 0;
+                        lost.push(get_lost_state_message(name, progress.id, &state));
+                    }
+                    StateRemap::Removed => {
+                        lost.push(get_removed_quest_message(name, progress.id));
+                        return false;
+                    }
+                }
+                true
+            });
+        }
+
+        lost
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{QUEST_DB, utils::is_party_shared_quest};
+
+use super::{Character, QuestProgress, quest_rules::get_int_arg};
+
+impl Character {
+    /// How many more of an item this character needs for a party shared GotItems objective
+    pub fn get_shared_quest_item_need(&self, item_id: i32) -> i32 {
+        self.quests
+            .iter()
+            .map(|progress| self.get_quest_item_need(progress, item_id))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Counts items looted by a party member towards party shared GotItems
+    /// objectives without giving the character the items themselves
+    pub fn add_shared_quest_item(&mut self, item_id: i32, amount: i32) {
+        let credits: Vec<(i32, i32)> = self
+            .quests
+            .iter()
+            .map(|progress| {
+                (
+                    progress.id,
+                    self.get_quest_item_need(progress, item_id).min(amount),
+                )
+            })
+            .filter(|(_, credit)| *credit > 0)
+            .collect();
+
+        for (quest_id, credit) in credits {
+            if let Some(progress) = self.quests.iter_mut().find(|q| q.id == quest_id) {
+                progress.add_shared_items(item_id, credit);
+            }
+
+            self.check_quest_state(quest_id);
+        }
+    }
+
+    fn get_quest_item_need(&self, progress: &QuestProgress, item_id: i32) -> i32 {
+        if !is_party_shared_quest(progress.id) {
+            return 0;
+        }
+
+        let quest = match QUEST_DB.get(&progress.id) {
+            Some(quest) => quest,
+            None => return 0,
+        };
+
+        let state = match quest.states.get(progress.state as usize) {
+            Some(state) => state,
+            None => return 0,
+        };
+
+        let have = self.get_item_amount(item_id) + progress.get_shared_items(item_id);
+
+        state
+            .rules
+            .iter()
+            .filter(|rule| rule.name == "GotItems" && get_int_arg(&rule.args, 0) == Some(item_id))
+            .filter_map(|rule| get_int_arg(&rule.args, 1))
+            .map(|required| (required - have).max(0))
+            .max()
+            .unwrap_or(0)
+    }
+}
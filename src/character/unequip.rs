@@ -149,6 +149,7 @@ impl Character {
         }
 
         self.calculate_stats();
+        self.check_quest_rules();
         true
     }
 }
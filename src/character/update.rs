@@ -71,6 +71,16 @@ impl Character {
             )
             .await?;
 
+        let old_pets = db
+            .query_map(
+                &insert_params(
+                    include_str!("../sql/get_character_pets.sql"),
+                    &[("character_id", &self.id)],
+                ),
+                |row| row.get_int(0).unwrap(),
+            )
+            .await?;
+
         db.start_transaction().await?;
 
         db.execute(&insert_params(
@@ -127,6 +137,7 @@ impl Character {
                 ("skill_points", &(self.skill_points as u32)),
                 ("karma", &(self.karma as u32)),
                 ("usage", &self.usage),
+                ("pk_ticks", &self.pk_ticks),
             ],
         ))
         .await?;
@@ -244,20 +255,7 @@ impl Character {
         }
 
         for quest in &self.quests {
-            let mut npc_kills = String::from('{');
-            for (index, (npc_id, kills)) in quest.npc_kills.iter().enumerate() {
-                npc_kills.push_str(&format!(
-                    "\"{}\":{}{}",
-                    npc_id,
-                    kills,
-                    if index < quest.npc_kills.len() - 1 {
-                        ","
-                    } else {
-                        ""
-                    }
-                ));
-            }
-            npc_kills.push('}');
+            let npc_kills = quest.get_npc_kills_json();
 
             if !old_quests.iter().any(|q| q.id == quest.id) {
                 db.execute(&insert_params(
@@ -310,6 +308,34 @@ impl Character {
             }
         }
 
+        for pet in &self.pets {
+            let summoned = i32::from(pet.summoned);
+
+            if !old_pets.contains(&pet.npc_id) {
+                db.execute(&insert_params(
+                    include_str!("../sql/create_character_pet.sql"),
+                    &[
+                        ("character_id", &self.id),
+                        ("npc_id", &pet.npc_id),
+                        ("experience", &pet.experience),
+                        ("summoned", &summoned),
+                    ],
+                ))
+                .await?;
+            } else {
+                db.execute(&insert_params(
+                    include_str!("../sql/update_character_pet.sql"),
+                    &[
+                        ("character_id", &self.id),
+                        ("npc_id", &pet.npc_id),
+                        ("experience", &pet.experience),
+                        ("summoned", &summoned),
+                    ],
+                ))
+                .await?;
+            }
+        }
+
         db.commit_transaction().await?;
 
         Ok(())
@@ -0,0 +1,97 @@
+use chrono::{Local, Timelike};
+
+use crate::SETTINGS;
+
+const SECONDS_PER_HOUR: i32 = 3600;
+const SECONDS_PER_DAY: i32 = 86_400;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum TimeOfDay {
+    Day,
+    Night,
+}
+
+/// In-game time of day, advanced by the world once a second
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Clock {
+    seconds: i32,
+    remainder: i32,
+}
+
+impl Clock {
+    pub fn new() -> Self {
+        let mut clock = Self::default();
+        if SETTINGS.clock.day_length > 0 {
+            clock.set_hour(SETTINGS.clock.start_hour);
+        } else {
+            clock.tick(0);
+        }
+        clock
+    }
+
+    /// Advances the clock by one real second, a `day_length` of 0 follows
+    /// the server's local time instead
+    pub fn tick(&mut self, day_length: i32) {
+        if day_length <= 0 {
+            self.seconds = Local::now().num_seconds_from_midnight() as i32;
+            return;
+        }
+
+        self.remainder += SECONDS_PER_DAY;
+        self.seconds = (self.seconds + self.remainder / day_length) % SECONDS_PER_DAY;
+        self.remainder %= day_length;
+    }
+
+    pub fn set_hour(&mut self, hour: u32) {
+        self.seconds = (hour % 24) as i32 * SECONDS_PER_HOUR;
+        self.remainder = 0;
+    }
+
+    pub fn get_hour(&self) -> u32 {
+        (self.seconds / SECONDS_PER_HOUR) as u32
+    }
+
+    pub fn get_minute(&self) -> u32 {
+        (self.seconds % SECONDS_PER_HOUR / 60) as u32
+    }
+}
+
+pub fn get_time_of_day(hour: u32) -> TimeOfDay {
+    if is_hour_between(
+        hour,
+        SETTINGS.clock.day_start_hour,
+        SETTINGS.clock.night_start_hour,
+    ) {
+        TimeOfDay::Day
+    } else {
+        TimeOfDay::Night
+    }
+}
+
+/// Whether `hour` is at or after `start` and before `end`, wrapping past midnight
+pub fn is_hour_between(hour: u32, start: u32, end: u32) -> bool {
+    if start <= end {
+        (start..end).contains(&hour)
+    } else {
+        hour >= start || hour < end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Clock;
+
+    #[test]
+    fn clock_keeps_fractional_progress() {
+        let mut clock = Clock::default();
+        clock.set_hour(23);
+
+        // 86400 / 7000 doesn't divide evenly, a full day must still wrap exactly
+        for _ in 0..7000 {
+            clock.tick(7000);
+        }
+
+        assert_eq!(clock.get_hour(), 23);
+        assert_eq!(clock.get_minute(), 0);
+    }
+}
@@ -10,6 +10,7 @@ pub enum ArgType {
 
 #[derive(Debug, Deserialize)]
 pub struct Arg {
+    pub name: String,
     pub r#type: ArgType,
     #[serde(default)]
     pub required: bool,
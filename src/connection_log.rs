@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
 
+use crate::SETTINGS;
+
 #[derive(Debug)]
 pub struct ConnectionLog {
     entries: HashMap<String, Entry>,
@@ -14,28 +16,40 @@ impl ConnectionLog {
         }
     }
 
-    pub fn get_num_of_connections(&self, ip: &str) -> i32 {
-        match self.entries.get(ip) {
-            Some(entry) => entry.connections,
-            None => 0,
-        }
-    }
-
-    pub fn get_last_connect(&self, ip: &str) -> Option<DateTime<Utc>> {
-        self.entries.get(ip).map(|entry| entry.last_connect)
-    }
-
     pub fn len(&self) -> i32 {
         self.entries.values().map(|entry| entry.connections).sum()
     }
 
-    pub fn add_connection(&mut self, ip: &str) {
-        if let Some(entry) = self.entries.get_mut(ip) {
-            entry.connections += 1;
-            entry.last_connect = Utc::now();
-        } else {
-            self.entries.insert(ip.to_string(), Entry::new());
+    /// Checks the connection limits and counts the connection if they allow it
+    pub fn add_connection(&mut self, ip: &str) -> Result<(), ConnectionRejection> {
+        if self.len() >= SETTINGS.server.max_connections {
+            return Err(ConnectionRejection::ServerFull);
         }
+
+        match self.entries.get_mut(ip) {
+            Some(entry) => {
+                if SETTINGS.server.ip_reconnect_limit != 0
+                    && (Utc::now() - entry.last_connect).num_seconds()
+                        < SETTINGS.server.ip_reconnect_limit.into()
+                {
+                    return Err(ConnectionRejection::ReconnectedTooQuickly);
+                }
+
+                if SETTINGS.server.max_connections_per_ip != 0
+                    && entry.connections >= SETTINGS.server.max_connections_per_ip
+                {
+                    return Err(ConnectionRejection::TooManyConnections(entry.connections));
+                }
+
+                entry.connections += 1;
+                entry.last_connect = Utc::now();
+            }
+            None => {
+                self.entries.insert(ip.to_string(), Entry::new());
+            }
+        }
+
+        Ok(())
     }
 
     pub fn remove_connection(&mut self, ip: &str) {
@@ -49,6 +63,14 @@ impl ConnectionLog {
     }
 }
 
+#[derive(Debug)]
+pub enum ConnectionRejection {
+    ServerFull,
+    ReconnectedTooQuickly,
+    /// How many connections the IP already has
+    TooManyConnections(i32),
+}
+
 #[derive(Debug)]
 pub struct Entry {
     pub connections: i32,
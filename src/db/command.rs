@@ -3,7 +3,7 @@ use tokio::sync::oneshot;
 use crate::db::{DbRequest, Row};
 
 pub enum Command {
-    Execute(DbRequest, oneshot::Sender<anyhow::Result<()>>),
+    Execute(DbRequest, oneshot::Sender<anyhow::Result<u64>>),
     Query(DbRequest, oneshot::Sender<anyhow::Result<Vec<Row>>>),
     StartTransaction(oneshot::Sender<anyhow::Result<()>>),
     CommitTransaction(oneshot::Sender<anyhow::Result<()>>),
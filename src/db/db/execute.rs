@@ -2,17 +2,17 @@ use super::super::{Db, PreparedQuery};
 use crate::db::{db::is_mysql_connection_closed, params::sqlite_named_params};
 
 impl Db {
-    pub async fn execute(&mut self, query: &str) -> anyhow::Result<()> {
+    pub async fn execute(&mut self, query: &str) -> anyhow::Result<u64> {
         let result = self.execute_inner(query).await;
         self.with_transaction_rollback(result).await
     }
 
-    pub async fn execute_prepared(&mut self, query: &PreparedQuery) -> anyhow::Result<()> {
+    pub async fn execute_prepared(&mut self, query: &PreparedQuery) -> anyhow::Result<u64> {
         let result = self.execute_prepared_inner(query).await;
         self.with_transaction_rollback(result).await
     }
 
-    pub(super) async fn execute_inner(&mut self, query: &str) -> anyhow::Result<()> {
+    pub(super) async fn execute_inner(&mut self, query: &str) -> anyhow::Result<u64> {
         match self.try_execute_inner(query).await {
             Err(e) if is_mysql_connection_closed(&e) => {
                 self.reconnect_mysql().await?;
@@ -22,20 +22,26 @@ impl Db {
         }
     }
 
-    async fn try_execute_inner(&mut self, query: &str) -> anyhow::Result<()> {
-        let last_insert_id_update = match self.connection {
+    async fn try_execute_inner(&mut self, query: &str) -> anyhow::Result<u64> {
+        let (affected_rows, last_insert_id_update) = match self.connection {
             crate::db::Connection::Mysql(ref mut mysql) => {
                 Self::execute_mysql_raw(&mut mysql.conn, query).await?;
-                Some(mysql.conn.last_insert_id())
+                (
+                    mysql.conn.affected_rows(),
+                    Some(mysql.conn.last_insert_id()),
+                )
             }
             crate::db::Connection::Sqlite(ref mut conn) => {
                 Self::execute_sqlite_raw(conn, query)?;
                 let last_id = conn.last_insert_rowid();
-                if last_id > 0 {
-                    Some(Some(last_id as u64))
-                } else {
-                    None
-                }
+                (
+                    conn.changes(),
+                    if last_id > 0 {
+                        Some(Some(last_id as u64))
+                    } else {
+                        None
+                    },
+                )
             }
         };
 
@@ -43,10 +49,10 @@ impl Db {
             self.last_insert_id = last_insert_id;
         }
 
-        Ok(())
+        Ok(affected_rows)
     }
 
-    async fn execute_prepared_inner(&mut self, query: &PreparedQuery) -> anyhow::Result<()> {
+    async fn execute_prepared_inner(&mut self, query: &PreparedQuery) -> anyhow::Result<u64> {
         match self.try_execute_prepared_inner(query).await {
             Err(e) if is_mysql_connection_closed(&e) => {
                 self.reconnect_mysql().await?;
@@ -56,20 +62,26 @@ impl Db {
         }
     }
 
-    async fn try_execute_prepared_inner(&mut self, query: &PreparedQuery) -> anyhow::Result<()> {
-        let last_insert_id_update = match self.connection {
+    async fn try_execute_prepared_inner(&mut self, query: &PreparedQuery) -> anyhow::Result<u64> {
+        let (affected_rows, last_insert_id_update) = match self.connection {
             crate::db::Connection::Mysql(ref mut mysql) => {
                 Self::execute_mysql_prepared(&mut mysql.conn, query).await?;
-                Some(mysql.conn.last_insert_id())
+                (
+                    mysql.conn.affected_rows(),
+                    Some(mysql.conn.last_insert_id()),
+                )
             }
             crate::db::Connection::Sqlite(ref mut conn) => {
-                Self::execute_sqlite_prepared(conn, query)?;
+                let affected_rows = Self::execute_sqlite_prepared(conn, query)?;
                 let last_id = conn.last_insert_rowid();
-                if last_id > 0 {
-                    Some(Some(last_id as u64))
-                } else {
-                    None
-                }
+                (
+                    affected_rows,
+                    if last_id > 0 {
+                        Some(Some(last_id as u64))
+                    } else {
+                        None
+                    },
+                )
             }
         };
 
@@ -77,7 +89,7 @@ impl Db {
             self.last_insert_id = last_insert_id;
         }
 
-        Ok(())
+        Ok(affected_rows)
     }
 
     async fn execute_mysql_raw(conn: &mut mysql_async::Conn, query: &str) -> anyhow::Result<()> {
@@ -106,11 +118,10 @@ impl Db {
     fn execute_sqlite_prepared(
         conn: &mut rusqlite::Connection,
         query: &PreparedQuery,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<u64> {
         let mut stmt = conn.prepare(&query.query)?;
         let sqlite_values = query.params.to_sqlite_values();
         let named_params = sqlite_named_params(&sqlite_values);
-        stmt.execute(named_params.as_slice())?;
-        Ok(())
+        Ok(stmt.execute(named_params.as_slice())? as u64)
     }
 }
@@ -16,6 +16,15 @@ impl DbHandle {
     }
 
     pub async fn execute<Q>(&self, query: &Q) -> anyhow::Result<()>
+    where
+        Q: IntoDbRequest + ?Sized,
+    {
+        self.execute_affected(query).await?;
+        Ok(())
+    }
+
+    /// Executes the query and returns the number of rows it changed
+    pub async fn execute_affected<Q>(&self, query: &Q) -> anyhow::Result<u64>
     where
         Q: IntoDbRequest + ?Sized,
     {
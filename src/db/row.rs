@@ -21,6 +21,16 @@ impl Row {
         }
     }
 
+    /// Like get_string but also accepts values that were read back as numbers,
+    /// for free text columns that may contain only digits
+    pub fn get_text(&self, index: usize) -> Option<String> {
+        match self.get(index) {
+            SqlValue::String(s) => Some(s),
+            SqlValue::Int(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+
     pub fn get_int(&self, index: usize) -> Option<i32> {
         match self.get(index) {
             SqlValue::Int(n) => Some(n),
@@ -28,6 +38,15 @@ impl Row {
         }
     }
 
+    /// Reads integers that may not fit in an i32, such as sums cast to text
+    pub fn get_big_int(&self, index: usize) -> Option<i64> {
+        match self.get(index) {
+            SqlValue::Int(n) => Some(n as i64),
+            SqlValue::String(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+
     pub fn get_date(&self, index: usize) -> Option<chrono::NaiveDateTime> {
         match self.get(index) {
             SqlValue::Date(d) => Some(d),
@@ -0,0 +1,29 @@
+use eolib::data::{EoReader, EoReaderError, EoSerialize, EoSerializeError, EoWriter};
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct ClockPingServerPacket {
+    pub hour: i32,
+    pub minute: i32,
+    pub night: bool,
+    /// Real seconds a full in-game day lasts, 0 when following server time
+    pub day_length: i32,
+}
+
+impl EoSerialize for ClockPingServerPacket {
+    fn deserialize(reader: &EoReader) -> Result<Self, EoReaderError> {
+        let mut packet = Self::default();
+        packet.hour = reader.get_char();
+        packet.minute = reader.get_char();
+        packet.night = reader.get_char() == 1;
+        packet.day_length = reader.get_three();
+        Ok(packet)
+    }
+
+    fn serialize(&self, writer: &mut EoWriter) -> Result<(), EoSerializeError> {
+        writer.add_char(self.hour)?;
+        writer.add_char(self.minute)?;
+        writer.add_char(if self.night { 1 } else { 0 })?;
+        writer.add_three(self.day_length)?;
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ pub const ACTION_CONFIG: u8 = 220;
 pub const ACTION_SWAP: u8 = 35;
 pub const FAMILY_BOSS: u8 = 52;
 pub const FAMILY_CAPTCHA: u8 = 249;
+pub const FAMILY_CLOCK: u8 = 53;
 pub const ACCOUNT_REPLY_WRONG_PIN: i32 = 8;
 pub const AVATAR_CHANGE_TYPE_SKIN: i32 = 4;
 pub const AVATAR_CHANGE_TYPE_GENDER: i32 = 5;
@@ -32,6 +33,9 @@ pub use account_config_server_packet::AccountConfigServerPacket;
 mod boss_ping_server_packet;
 pub use boss_ping_server_packet::BossPingServerPacket;
 
+mod clock_ping_server_packet;
+pub use clock_ping_server_packet::ClockPingServerPacket;
+
 mod captcha_open_server_packet;
 pub use captcha_open_server_packet::CaptchaOpenServerPacket;
 
@@ -17,6 +17,7 @@ pub struct Formulas {
     pub hit_rate: String,
     pub damage: String,
     pub party_exp_share: String,
+    pub pk_karma_loss: String,
     pub classes: Vec<ClassFormulas>,
 }
 
@@ -3,9 +3,23 @@ use std::cmp;
 use config::{Config, ConfigError, File};
 use eolib::{data::THREE_MAX, protocol::r#pub::server::DropRecord};
 
+use crate::clock::{TimeOfDay, get_time_of_day};
+
 #[derive(Debug, Deserialize)]
 pub struct GlobalDrops {
     pub drops: Vec<DropRecord>,
+    #[serde(default)]
+    pub timed: Vec<TimedDrops>,
+}
+
+/// Drops only rolled during the day or night
+#[derive(Debug, Deserialize)]
+pub struct TimedDrops {
+    pub time_of_day: TimeOfDay,
+    /// Npc the drops are added to, every npc if not set
+    #[serde(default)]
+    pub npc_id: Option<i32>,
+    pub drops: Vec<DropRecord>,
 }
 
 impl GlobalDrops {
@@ -18,12 +32,22 @@ impl GlobalDrops {
         let mut s: GlobalDrops = match config_result {
             Ok(config) => match config.try_deserialize() {
                 Ok(global_drops) => global_drops,
-                Err(_) => GlobalDrops { drops: Vec::new() },
+                Err(_) => GlobalDrops {
+                    drops: Vec::new(),
+                    timed: Vec::new(),
+                },
+            },
+            Err(_) => GlobalDrops {
+                drops: Vec::new(),
+                timed: Vec::new(),
             },
-            Err(_) => GlobalDrops { drops: Vec::new() },
         };
 
-        for drop in s.drops.iter_mut() {
+        for drop in s
+            .drops
+            .iter_mut()
+            .chain(s.timed.iter_mut().flat_map(|timed| timed.drops.iter_mut()))
+        {
             drop.rate = drop.rate.clamp(0, 100);
             drop.min_amount = drop.min_amount.clamp(0, THREE_MAX);
             drop.max_amount = drop.max_amount.clamp(0, THREE_MAX);
@@ -34,4 +58,15 @@ impl GlobalDrops {
 
         Ok(s)
     }
+
+    /// Timed drops that apply to an npc at the given in-game hour
+    pub fn get_timed(&self, npc_id: i32, hour: u32) -> impl Iterator<Item = &DropRecord> {
+        let time_of_day = get_time_of_day(hour);
+        self.timed
+            .iter()
+            .filter(move |timed| {
+                timed.time_of_day == time_of_day && timed.npc_id.is_none_or(|id| id == npc_id)
+            })
+            .flat_map(|timed| timed.drops.iter())
+    }
 }
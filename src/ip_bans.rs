@@ -0,0 +1,66 @@
+use std::sync::RwLock;
+
+use chrono::NaiveDateTime;
+use ipnet::IpNet;
+
+use crate::{
+    db::DbHandle,
+    utils::{get_ban_minutes_remaining, parse_ip_range},
+};
+
+struct IpBan {
+    range: IpNet,
+    duration: i32,
+    created_at: NaiveDateTime,
+}
+
+/// IP and range bans kept in memory so connecting players don't scan the
+/// bans table, reloaded whenever a ban is added or removed
+pub struct IpBans {
+    bans: RwLock<Vec<IpBan>>,
+}
+
+impl IpBans {
+    pub fn new() -> Self {
+        Self {
+            bans: RwLock::new(Vec::new()),
+        }
+    }
+
+    pub async fn load(&self, db: &DbHandle) -> anyhow::Result<()> {
+        let bans = db
+            .query_map(include_str!("sql/get_ip_bans.sql"), |row| {
+                Some(IpBan {
+                    range: parse_ip_range(&row.get_string(0)?)?,
+                    duration: row.get_int(1)?,
+                    created_at: row.get_date(2)?,
+                })
+            })
+            .await?;
+
+        *self.bans.write().unwrap() = bans.into_iter().flatten().collect();
+        Ok(())
+    }
+
+    /// Longest remaining ban in minutes covering the ip (0 = permanent)
+    pub fn get_ban_duration(&self, ip: &str) -> Option<i32> {
+        let ip = ip.parse::<std::net::IpAddr>().ok()?.to_canonical();
+
+        let mut longest: Option<i32> = None;
+        for ban in self.bans.read().unwrap().iter() {
+            if !ban.range.contains(&ip) {
+                continue;
+            }
+
+            match get_ban_minutes_remaining(ban.duration, ban.created_at) {
+                Some(0) => return Some(0),
+                Some(remaining) => {
+                    longest = Some(longest.map_or(remaining, |l| l.max(remaining)));
+                }
+                None => {}
+            }
+        }
+
+        longest
+    }
+}
@@ -22,6 +22,14 @@ pub struct Lang {
     pub wedding_error: String,
     pub evacuate_warning: String,
     pub evacuate_last_warning: String,
+    pub shutdown_warning: String,
+    pub shutdown_warning_reason: String,
+    pub shutdown_cancelled: String,
+    pub maintenance_on: String,
+    pub maintenance_off: String,
+    pub maintenance_kick_warning: String,
+    pub boss_killed: String,
+    pub pet_level_up: String,
 }
 
 impl Lang {
@@ -9,23 +9,28 @@ extern crate log;
 #[macro_use]
 extern crate serde_derive;
 
-use std::{collections::HashMap, time::Duration};
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
 
 use chrono::Utc;
 use eolib::protocol::r#pub::{
     Ecf, Eif, Enf, Esf,
     server::{DropFile, InnFile, ShopFile, SkillMasterFile, TalkFile},
 };
-use eoplus::Quest;
 use lazy_static::lazy_static;
 
 #[macro_use]
 mod utils;
 mod arenas;
 mod character;
+mod clock;
 mod db;
 mod deep;
 use arenas::Arenas;
+mod bosses;
+use bosses::Bosses;
 mod commands;
 use commands::Commands;
 mod player_commands;
@@ -34,39 +39,61 @@ use player_commands::PlayerCommands;
 mod connection_log;
 mod formulas;
 use formulas::Formulas;
+mod ip_bans;
+use ip_bans::IpBans;
 mod emails;
 mod errors;
 mod lang;
 mod map;
 mod player;
+mod quest_db;
+use quest_db::QuestDb;
 mod settings;
 use settings::Settings;
 mod packet_rate_limits;
 use packet_rate_limits::PacketRateLimits;
 mod global_drops;
 use global_drops::GlobalDrops;
+mod npc_abilities;
+use npc_abilities::NpcAbilities;
+mod pets;
+use pets::Pets;
 mod sln;
 use sln::ping_sln;
+mod spawns;
+mod weather;
+use weather::Weathers;
+mod tls;
+use tls::Tls;
 mod world;
 
-use tokio::{net::TcpListener, signal, time};
-use tokio_tungstenite::accept_async;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    signal, time,
+};
+use tokio_tungstenite::{
+    WebSocketStream, accept_hdr_async,
+    tungstenite::handshake::server::{Request, Response},
+};
 use world::WorldHandle;
 
 use crate::{
+    connection_log::ConnectionRejection,
     db::{Connection, DbHandle},
     emails::Emails,
     lang::Lang,
-    player::PlayerHandle,
+    player::{PlayerHandle, WebStream},
     utils::{
-        load_class_file, load_drop_file, load_inn_file, load_item_file, load_npc_file, load_quests,
-        load_shop_file, load_skill_master_file, load_spell_file, load_talk_file,
+        get_forwarded_ip, is_trusted_proxy, load_class_file, load_drop_file, load_inn_file,
+        load_item_file, load_npc_file, load_quests, load_shop_file, load_skill_master_file,
+        load_spell_file, load_talk_file, read_proxy_header, validate_quests,
     },
 };
 
 lazy_static! {
     static ref SETTINGS: Settings = Settings::new().expect("Failed to load settings!");
     static ref ARENAS: Arenas = Arenas::new().expect("Failed to load arenas!");
+    static ref BOSSES: Bosses = Bosses::new().expect("Failed to load bosses!");
     static ref PACKET_RATE_LIMITS: PacketRateLimits =
         PacketRateLimits::new().expect("Failed to load packet rate limits!");
     static ref COMMANDS: Commands = Commands::new().expect("Failed to load commands!");
@@ -77,6 +104,10 @@ lazy_static! {
     static ref EMAILS: Emails = Emails::new().expect("Failed to load emails!");
     static ref GLOBAL_DROPS: GlobalDrops =
         GlobalDrops::new().expect("Failed to load global drops!");
+    static ref NPC_ABILITIES: NpcAbilities =
+        NpcAbilities::new().expect("Failed to load npc abilities!");
+    static ref PETS: Pets = Pets::new().expect("Failed to load pets!");
+    static ref WEATHERS: Weathers = Weathers::new().expect("Failed to load weather!");
     static ref CLASS_DB: Ecf = load_class_file().expect("Failed to load ECF file!");
     static ref DROP_DB: DropFile = load_drop_file().expect("Failed to load Drop file!");
     static ref INN_DB: InnFile = load_inn_file().expect("Failed to load Inn file!");
@@ -87,7 +118,8 @@ lazy_static! {
         load_skill_master_file().expect("Failed to load Skill Master file!");
     static ref SPELL_DB: Esf = load_spell_file().expect("Failed to load ESF file!");
     static ref TALK_DB: TalkFile = load_talk_file().expect("Failed to load Talk file!");
-    static ref QUEST_DB: HashMap<i32, Quest> = load_quests();
+    static ref QUEST_DB: QuestDb = QuestDb::new(load_quests());
+    static ref IP_BANS: IpBans = IpBans::new();
     static ref EXP_TABLE: [i32; 254] = load_exp_table();
 }
 
@@ -102,6 +134,16 @@ async fn main() -> anyhow::Result<()> {
         }
     }
     pretty_env_logger::init();
+
+    if std::env::args().nth(1).as_deref() == Some("validate-quests") {
+        let issues = validate_quests();
+        for issue in &issues {
+            println!("{}", issue);
+        }
+        println!("{} quest issues found", issues.len());
+        std::process::exit(if issues.is_empty() { 0 } else { 1 });
+    }
+
     println!(
         "__________
 \\______   \\ ____  ____  ______ ______________  __
@@ -132,6 +174,7 @@ async fn main() -> anyhow::Result<()> {
     });
 
     crate::db::run_startup_migrations(&db, SETTINGS.database.driver.as_str()).await?;
+    IP_BANS.load(&db).await?;
 
     if let Some(row) = db
         .query_one(
@@ -156,6 +199,9 @@ async fn main() -> anyhow::Result<()> {
     info!("NPCs: {}", NPC_DB.npcs.len());
     info!("Skills: {}", SPELL_DB.skills.len());
     info!("Quests: {}", QUEST_DB.len());
+    for issue in validate_quests() {
+        warn!("{}", issue);
+    }
 
     let world = WorldHandle::new(db.clone());
     {
@@ -187,6 +233,8 @@ async fn main() -> anyhow::Result<()> {
         });
     }
 
+    tokio::spawn(shutdown_signals(world.clone()));
+
     if SETTINGS.sln.enabled {
         let mut sln_interval = time::interval(Duration::from_secs(SETTINGS.sln.rate as u64 * 60));
         tokio::spawn(async move {
@@ -207,6 +255,7 @@ async fn main() -> anyhow::Result<()> {
     );
 
     let mut websocket_listener = None;
+    let mut tls = None;
     if !SETTINGS.server.websocket_port.is_empty() {
         websocket_listener = Some(
             TcpListener::bind(format!(
@@ -217,197 +266,109 @@ async fn main() -> anyhow::Result<()> {
             .unwrap(),
         );
 
+        if !SETTINGS.server.tls_cert.is_empty() && !SETTINGS.server.tls_key.is_empty() {
+            let websocket_tls = Tls::new().expect("Failed to load TLS certificate!");
+            tokio::spawn(websocket_tls.clone().watch());
+            tls = Some(websocket_tls);
+        }
+
         info!(
-            "listening for websockets at {}:{}",
-            SETTINGS.server.host, SETTINGS.server.websocket_port
+            "listening for {} at {}:{}",
+            if tls.is_some() {
+                "secure websockets"
+            } else {
+                "websockets"
+            },
+            SETTINGS.server.host,
+            SETTINGS.server.websocket_port
         );
     }
 
-    let mut server_world = world.clone();
+    let server_world = world.clone();
     let server_db = db.clone();
     tokio::spawn(async move {
-        while server_world.is_alive {
-            let (socket, addr) = tcp_listener.accept().await.unwrap();
-            let ip = addr.ip().to_string();
-            let now = Utc::now();
-
-            let player_count = server_world
-                .get_connection_count()
-                .await
-                .expect("Failed to get connection count. Timeout");
-            if player_count >= SETTINGS.server.max_connections {
-                warn!("{} has been disconnected because the server is full", addr);
-                continue;
-            }
-
-            // Check reconnect rate limiting
-            match server_world.get_ip_last_connect(&ip).await {
-                Ok(Some(last_connect)) => {
-                    let time_since_last_connect = now - last_connect;
-                    if SETTINGS.server.ip_reconnect_limit != 0
-                        && time_since_last_connect.num_seconds()
-                            < SETTINGS.server.ip_reconnect_limit.into()
+        loop {
+            let (mut socket, addr) = tcp_listener.accept().await.unwrap();
+            let world = server_world.clone();
+            let db = server_db.clone();
+            tokio::spawn(async move {
+                let ip = if SETTINGS.server.proxy_protocol && is_trusted_proxy(&addr.ip()) {
+                    match time::timeout(
+                        Duration::from_secs(SETTINGS.server.hangup_delay as u64),
+                        read_proxy_header(&mut socket),
+                    )
+                    .await
                     {
-                        warn!(
-                            "{} has been disconnected because it reconnected too quickly",
-                            addr
-                        );
-                        continue;
+                        Ok(Ok(ip)) => ip.unwrap_or(addr.ip()),
+                        Ok(Err(e)) => {
+                            warn!("Invalid PROXY protocol header from {}: {}", addr, e);
+                            return;
+                        }
+                        Err(_) => {
+                            warn!("Timed out waiting for PROXY protocol header from {}", addr);
+                            return;
+                        }
                     }
+                } else {
+                    addr.ip()
                 }
-                Ok(None) => {
-                    // First connection from this IP, allow it
-                }
-                Err(e) => {
-                    warn!("Failed to check last connect time for {}: {}", ip, e);
-                    // Continue anyway - we have other rate limiting checks
-                }
-            }
+                .to_string();
 
-            let num_of_connections = server_world
-                .get_ip_connection_count(&ip)
-                .await
-                .expect("Failed to get IP connection count. Timeout");
-            if SETTINGS.server.max_connections_per_ip != 0
-                && num_of_connections >= SETTINGS.server.max_connections_per_ip
-            {
-                warn!(
-                    "{} has been disconnected because there are already {} connections from {}",
-                    addr, num_of_connections, ip
-                );
-                continue;
-            }
+                if !add_connection(&world, &ip, addr).await {
+                    return;
+                }
 
-            server_world
-                .add_connection(&ip)
-                .await
-                .expect("Failed to add connection. Timeout");
-
-            let player_id = server_world
-                .get_next_player_id()
-                .await
-                .expect("Failed to get next player id. Timeout");
-
-            let player = PlayerHandle::new(
-                player_id,
-                Socket::Standard(socket),
-                ip,
-                now,
-                server_world.clone(),
-                server_db.clone(),
-            );
-            server_world
-                .add_player(player_id, player)
-                .await
-                .expect("Failed to add player. Timeout");
-
-            info!(
-                "connection accepted ({}) {}/{}",
-                addr,
-                server_world
-                    .get_connection_count()
-                    .await
-                    .expect("Failed to get connection count. Timeout"),
-                SETTINGS.server.max_connections
-            );
+                add_player(world, db, Socket::Standard(socket), ip, addr, "connection").await;
+            });
         }
     });
 
     if let Some(websocket_listener) = websocket_listener {
-        let mut websocket_world = world.clone();
+        let websocket_world = world.clone();
         tokio::spawn(async move {
-            while websocket_world.is_alive {
+            loop {
                 let (socket, addr) = websocket_listener.accept().await.unwrap();
-                let websocket = match accept_async(socket).await {
-                    Ok(ws) => ws,
-                    Err(e) => {
-                        error!("Failed to accept websocket: {}", e);
-                        continue;
-                    }
-                };
-
-                let ip = addr.ip().to_string();
-                let now = Utc::now();
-
-                let player_count = websocket_world
-                    .get_connection_count()
-                    .await
-                    .expect("Failed to get connection count. Timeout");
-                if player_count >= SETTINGS.server.max_connections {
-                    warn!("{} has been disconnected because the server is full", addr);
-                    continue;
-                }
-
-                // Check reconnect rate limiting
-                match websocket_world.get_ip_last_connect(&ip).await {
-                    Ok(Some(last_connect)) => {
-                        let time_since_last_connect = now - last_connect;
-                        if SETTINGS.server.ip_reconnect_limit != 0
-                            && time_since_last_connect.num_seconds()
-                                < SETTINGS.server.ip_reconnect_limit.into()
-                        {
-                            warn!(
-                                "{} has been disconnected because it reconnected too quickly",
-                                addr
-                            );
-                            continue;
+                let world = websocket_world.clone();
+                let db = db.clone();
+                let tls = tls.clone();
+                tokio::spawn(async move {
+                    // Direct clients are limited before their handshakes, clients
+                    // behind a trusted proxy only have an IP once the request is read
+                    let counted_ip = if is_trusted_proxy(&addr.ip()) {
+                        None
+                    } else {
+                        let ip = addr.ip().to_string();
+                        if !add_connection(&world, &ip, addr).await {
+                            return;
                         }
-                    }
-                    Ok(None) => {
-                        // First connection from this IP, allow it
-                    }
-                    Err(e) => {
-                        warn!("Failed to check last connect time for {}: {}", ip, e);
-                        // Continue anyway - we have other rate limiting checks
-                    }
-                }
-
-                let num_of_connections = websocket_world
-                    .get_ip_connection_count(&ip)
-                    .await
-                    .expect("Failed to get IP connection count. Timeout");
-                if SETTINGS.server.max_connections_per_ip != 0
-                    && num_of_connections >= SETTINGS.server.max_connections_per_ip
-                {
-                    warn!(
-                        "{} has been disconnected because there are already {} connections from {}",
-                        addr, num_of_connections, ip
-                    );
-                    continue;
-                }
+                        Some(ip)
+                    };
+
+                    let (websocket, ip) = match accept_websocket(socket, addr, tls.as_ref()).await {
+                        Ok((ws, ip)) => (ws, ip.to_string()),
+                        Err(e) => {
+                            error!("Failed to accept websocket: {}", e);
+                            if let Some(ip) = counted_ip {
+                                world.remove_connection(&ip);
+                            }
+                            return;
+                        }
+                    };
 
-                websocket_world
-                    .add_connection(&ip)
-                    .await
-                    .expect("Failed to add connection. Timeout");
+                    if counted_ip.is_none() && !add_connection(&world, &ip, addr).await {
+                        return;
+                    }
 
-                let player_id = websocket_world
-                    .get_next_player_id()
-                    .await
-                    .expect("Failed to get next player id. Timeout");
-
-                let player = PlayerHandle::new(
-                    player_id,
-                    Socket::Web(websocket),
-                    ip,
-                    now,
-                    websocket_world.clone(),
-                    db.clone(),
-                );
-                websocket_world
-                    .add_player(player_id, player)
-                    .await
-                    .expect("Failed to add player. Timeout");
-
-                info!(
-                    "websocket connection accepted ({}) {}/{}",
-                    addr,
-                    websocket_world
-                        .get_connection_count()
-                        .await
-                        .expect("Failed to get connection count. Timeout"),
-                    SETTINGS.server.max_connections
-                );
+                    add_player(
+                        world,
+                        db,
+                        Socket::Web(websocket),
+                        ip,
+                        addr,
+                        "websocket connection",
+                    )
+                    .await;
+                });
             }
         });
     }
@@ -418,7 +379,8 @@ async fn main() -> anyhow::Result<()> {
         },
         close = close() => if let Err(err) = close {
             error!("Unable to listen for shutdown signal: {}", err);
-        }
+        },
+        _ = world.wait_for_shutdown() => {}
     }
 
     info!("Shutting down server...");
@@ -427,6 +389,103 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Checks the connection limits and counts the connection, returning false
+/// if the connection was refused
+async fn add_connection(world: &WorldHandle, ip: &str, addr: SocketAddr) -> bool {
+    match world.add_connection(ip).await {
+        Ok(Ok(())) => true,
+        Ok(Err(ConnectionRejection::ServerFull)) => {
+            warn!("{} has been disconnected because the server is full", ip);
+            false
+        }
+        Ok(Err(ConnectionRejection::ReconnectedTooQuickly)) => {
+            warn!(
+                "{} has been disconnected because it reconnected too quickly",
+                ip
+            );
+            false
+        }
+        Ok(Err(ConnectionRejection::TooManyConnections(connections))) => {
+            warn!(
+                "{} has been disconnected because there are already {} connections from {}",
+                addr, connections, ip
+            );
+            false
+        }
+        Err(e) => {
+            error!("{}", e);
+            false
+        }
+    }
+}
+
+async fn add_player(
+    mut world: WorldHandle,
+    db: DbHandle,
+    socket: Socket,
+    ip: String,
+    addr: SocketAddr,
+    kind: &str,
+) {
+    let player_id = world
+        .get_next_player_id()
+        .await
+        .expect("Failed to get next player id. Timeout");
+
+    let player = PlayerHandle::new(player_id, socket, ip, Utc::now(), world.clone(), db);
+    world
+        .add_player(player_id, player)
+        .await
+        .expect("Failed to add player. Timeout");
+
+    info!(
+        "{} accepted ({}) {}/{}",
+        kind,
+        addr,
+        world
+            .get_connection_count()
+            .await
+            .expect("Failed to get connection count. Timeout"),
+        SETTINGS.server.max_connections
+    );
+}
+
+/// Accepts a websocket (over TLS if configured), taking the client's IP from
+/// the Forwarded or X-Forwarded-For headers if it connected through a trusted proxy
+#[allow(clippy::result_large_err)]
+async fn accept_websocket(
+    socket: TcpStream,
+    addr: SocketAddr,
+    tls: Option<&Tls>,
+) -> anyhow::Result<(WebSocketStream<WebStream>, IpAddr)> {
+    let stream = match tls {
+        Some(tls) => WebStream::Tls(Box::new(
+            time::timeout(
+                Duration::from_secs(SETTINGS.server.hangup_delay as u64),
+                tls.acceptor().accept(socket),
+            )
+            .await??,
+        )),
+        None => WebStream::Plain(socket),
+    };
+
+    let trusted = is_trusted_proxy(&addr.ip());
+    let mut forwarded_ip = None;
+
+    let websocket = time::timeout(
+        Duration::from_secs(SETTINGS.server.hangup_delay as u64),
+        accept_hdr_async(stream, |request: &Request, response: Response| {
+            if trusted {
+                forwarded_ip = get_forwarded_ip(request.headers(), is_trusted_proxy);
+            }
+            Ok(response)
+        }),
+    )
+    .await??;
+
+    Ok((websocket, forwarded_ip.unwrap_or(addr.ip())))
+}
+
 #[cfg(windows)]
 async fn close() -> anyhow::Result<()> {
     let mut close_stream = signal::windows::ctrl_close()?;
@@ -441,6 +500,37 @@ async fn close() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// SIGUSR1 starts an announced shutdown countdown and SIGUSR2 cancels it
+#[cfg(unix)]
+async fn shutdown_signals(world: WorldHandle) {
+    use signal::unix::{SignalKind, signal};
+
+    let (mut start, mut cancel) = match (
+        signal(SignalKind::user_defined1()),
+        signal(SignalKind::user_defined2()),
+    ) {
+        (Ok(start), Ok(cancel)) => (start, cancel),
+        (Err(err), _) | (_, Err(err)) => {
+            error!("Unable to listen for shutdown countdown signals: {}", err);
+            return;
+        }
+    };
+
+    loop {
+        tokio::select! {
+            _ = start.recv() => world.start_shutdown(
+                "Server".to_string(),
+                SETTINGS.shutdown.signal_seconds,
+                String::new(),
+            ),
+            _ = cancel.recv() => world.cancel_shutdown(None, "Server".to_string()),
+        }
+    }
+}
+
+#[cfg(windows)]
+async fn shutdown_signals(_world: WorldHandle) {}
+
 fn load_exp_table() -> [i32; 254] {
     let mut exp_table = [0; 254];
 
@@ -0,0 +1,17 @@
+#[derive(Clone, Debug, Default)]
+pub struct BossFight {
+    pub seconds: i32,
+    /// Index into the boss's phases, None until the first threshold is reached
+    pub phase: Option<usize>,
+    pub phase_seconds: i32,
+    pub enraged: bool,
+    pub participants: Vec<BossFightParticipant>,
+    pub add_indexes: Vec<i32>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BossFightParticipant {
+    pub player_id: i32,
+    pub character_id: i32,
+    pub damage_dealt: i32,
+}
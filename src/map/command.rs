@@ -12,9 +12,12 @@ use tokio::sync::oneshot;
 
 use crate::{
     character::{Character, SpellTarget},
-    player::PartyRequest,
+    player::{PartyRequest, PlayerHandle},
+    world::Party,
 };
 
+use super::{ItemTotals, QuestUpdate};
+
 #[derive(Debug)]
 pub enum Command {
     AcceptGuildCreationRequest {
@@ -83,6 +86,12 @@ pub enum Command {
         npc_index: i32,
         item_id: i32,
     },
+    CreditPartyQuestItem {
+        player_id: i32,
+        party: Party,
+        item_id: i32,
+        amount: i32,
+    },
     CreateBoardPost {
         player_id: i32,
         board_id: i32,
@@ -330,6 +339,9 @@ pub enum Command {
         player_id: i32,
         npc_index: i32,
     },
+    RemapQuestStates {
+        respond_to: oneshot::Sender<Vec<String>>,
+    },
     RemoveTradeItem {
         player_id: i32,
         partner_id: i32,
@@ -408,7 +420,7 @@ pub enum Command {
         npc_index: i32,
     },
     SaveAsync {
-        respond_to: oneshot::Sender<()>,
+        respond_to: oneshot::Sender<ItemTotals>,
     },
     SayIDo {
         player_id: i32,
@@ -474,6 +486,7 @@ pub enum Command {
         item_id: i32,
     },
     TimedArena,
+    TimedBossFights,
     TimedDoorClose,
     TimedDrain,
     TimedQuake,
@@ -483,9 +496,11 @@ pub enum Command {
     TimedEvacuate,
     TimedDropProtection,
     TimedGhost,
+    TimedKarma,
     TimedAutoPickup,
     TimedCleanup,
     TimedUsage,
+    TimedWeather,
     ToggleHidden {
         player_id: i32,
     },
@@ -499,6 +514,12 @@ pub enum Command {
         rank: i32,
         rank_str: String,
     },
+    UpdateQuest {
+        player_id: i32,
+        quest_id: i32,
+        update: QuestUpdate,
+        admin: PlayerHandle,
+    },
     UpgradeLocker {
         player_id: i32,
         npc_index: i32,
@@ -559,6 +580,22 @@ pub enum Command {
         player_id: i32,
         item_id: i32,
     },
+    ListSpawns {
+        player_id: i32,
+    },
+    ReloadSpawns {
+        player_id: i32,
+    },
+    SetEvents {
+        events: Vec<String>,
+    },
+    SetClock {
+        hour: u32,
+        minute: u32,
+    },
+    SetWeather {
+        weather: Option<String>,
+    },
     Load {
         respond_to: oneshot::Sender<()>,
     },
@@ -0,0 +1,9 @@
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Default)]
+pub struct ItemTotals {
+    /// Total amount of each item on the ground and in chests
+    pub items: HashMap<i32, i64>,
+    /// Item ids that chests on the map can spawn
+    pub chest_spawns: HashSet<i32>,
+}
@@ -6,7 +6,7 @@ use eolib::protocol::{
 };
 use tokio::sync::mpsc::UnboundedReceiver;
 
-use crate::{SETTINGS, character::Character, world::WorldHandle};
+use crate::{SETTINGS, character::Character, clock::Clock, spawns::Spawn, world::WorldHandle};
 
 use super::{Chest, Command, Door, Item, Npc, Wedding};
 
@@ -22,6 +22,12 @@ pub struct Map {
     item_index_counter: i32,
     npcs: Vec<Npc>,
     npcs_initialized: bool,
+    spawns: Vec<Spawn>,
+    events: Vec<String>,
+    hour: u32,
+    minute: u32,
+    weather: Option<String>,
+    weather_ticks: i32,
     characters: HashMap<i32, Character>,
     db: crate::db::DbHandle,
     quake_ticks: i32,
@@ -58,9 +64,11 @@ mod inn;
 mod jukebox;
 mod locker;
 mod marriage;
+mod pet;
 mod quest;
 mod shop;
 mod skill_master;
+mod spawns;
 mod trade;
 mod utils;
 
@@ -101,6 +109,8 @@ impl Map {
         }
 
         let chests = utils::create_chests(id, &file);
+        let spawns = utils::create_spawns(id, &file);
+        let clock = Clock::new();
 
         Self {
             id,
@@ -114,6 +124,12 @@ impl Map {
             item_index_counter: 0,
             npcs: Vec::new(),
             npcs_initialized: false,
+            spawns,
+            events: Vec::new(),
+            hour: clock.get_hour(),
+            minute: clock.get_minute(),
+            weather: None,
+            weather_ticks: 0,
             characters: HashMap::new(),
             db,
             arena_ticks: 0,
@@ -202,6 +218,13 @@ impl Map {
                 item_id,
             } => self.craft_item(player_id, npc_index, item_id),
 
+            Command::CreditPartyQuestItem {
+                player_id,
+                party,
+                item_id,
+                amount,
+            } => self.credit_party_quest_item(player_id, &party, item_id, amount),
+
             Command::CreateBoardPost {
                 player_id,
                 board_id,
@@ -496,6 +519,10 @@ impl Map {
                 npc_index,
             } => self.remove_citizenship(player_id, npc_index),
 
+            Command::RemapQuestStates { respond_to } => {
+                let _ = respond_to.send(self.remap_quest_states());
+            }
+
             Command::RemoveTradeItem {
                 player_id,
                 partner_id,
@@ -576,7 +603,7 @@ impl Map {
 
             Command::SaveAsync { respond_to } => {
                 self.save().await;
-                let _ = respond_to.send(());
+                let _ = respond_to.send(self.get_item_totals());
             }
 
             Command::SayIDo { player_id } => self.say_i_do(player_id),
@@ -669,6 +696,8 @@ impl Map {
 
             Command::TimedArena => self.timed_arena(),
 
+            Command::TimedBossFights => self.timed_boss_fights(),
+
             Command::TimedDoorClose => self.timed_door_close(),
 
             Command::TimedDrain => self.timed_drain(),
@@ -687,15 +716,19 @@ impl Map {
 
             Command::TimedGhost => self.timed_ghost(),
 
+            Command::TimedKarma => self.timed_karma(),
+
             Command::TimedAutoPickup => self.timed_auto_pickup(),
 
             Command::TimedCleanup => self.timed_cleanup(),
 
             Command::TimedUsage => self.timed_usage(),
 
+            Command::TimedWeather => self.timed_weather(),
+
             Command::ToggleHidden { player_id } => self.toggle_hidden(player_id),
 
-            Command::ActNpcs => self.act_npcs(),
+            Command::ActNpcs => self.act_npcs().await,
 
             Command::Unequip {
                 player_id,
@@ -709,6 +742,13 @@ impl Map {
                 rank_str,
             } => self.update_guild_rank(player_id, rank, rank_str),
 
+            Command::UpdateQuest {
+                player_id,
+                quest_id,
+                update,
+                admin,
+            } => self.update_quest(player_id, quest_id, update, admin),
+
             Command::UpgradeLocker {
                 player_id,
                 npc_index,
@@ -767,6 +807,11 @@ impl Map {
             Command::RemoveAutoPickupItem { player_id, item_id } => {
                 self.remove_auto_pickup_item(player_id, item_id)
             }
+            Command::ListSpawns { player_id } => self.list_spawns(player_id),
+            Command::ReloadSpawns { player_id } => self.reload_spawns(player_id),
+            Command::SetEvents { events } => self.events = events,
+            Command::SetClock { hour, minute } => self.set_clock(hour, minute),
+            Command::SetWeather { weather } => self.set_weather(weather),
             Command::Load { respond_to } => {
                 self.load().await;
                 let _ = respond_to.send(());
@@ -5,7 +5,9 @@ use eolib::protocol::{
     r#pub::NpcType,
 };
 
-use crate::NPC_DB;
+use serde_json::json;
+
+use crate::{NPC_DB, player::Action};
 
 use super::super::Map;
 
@@ -49,6 +51,15 @@ impl Map {
         character.add_item(1, amount);
 
         if let Some(player) = character.player.as_ref() {
+            player.record_action(
+                Action::BankGoldWithdrawn,
+                json!({
+                    "amount": amount,
+                    "gold_bank": character.gold_bank,
+                })
+                .to_string(),
+            );
+
             player.send(
                 PacketAction::Reply,
                 PacketFamily::Bank,
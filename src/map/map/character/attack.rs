@@ -118,7 +118,7 @@ impl Map {
             if let Some(npc) = self
                 .npcs
                 .iter()
-                .find(|npc| npc.alive && npc.coords == coords)
+                .find(|npc| npc.alive && npc.owner.is_none() && npc.coords == coords)
             {
                 return Some(AttackTarget::Npc(npc.index));
             }
@@ -402,6 +402,10 @@ impl Map {
 
             player.update_party_hp(target_character.get_hp_percentage());
         }
+
+        if target_character.hp == 0 {
+            self.pk_kill(player_id, target_player_id);
+        }
     }
 }
 
@@ -14,7 +14,7 @@ use eolib::protocol::{
 use rand::RngExt;
 
 use crate::utils::in_client_range;
-use crate::{NPC_DB, SPELL_DB, character::SpellTarget};
+use crate::{NPC_DB, PETS, SPELL_DB, character::SpellTarget};
 
 use super::super::Map;
 
@@ -34,6 +34,11 @@ impl Map {
             None => return,
         };
 
+        if PETS.get_by_spell(spell_id).is_some() {
+            self.cast_summon_pet(player_id, spell_id, spell_data);
+            return;
+        }
+
         match spell_data.r#type {
             SkillType::Heal => {
                 self.cast_heal_spell(player_id, spell_id, spell_data, target)
@@ -47,6 +52,58 @@ impl Map {
         }
     }
 
+    fn cast_summon_pet(&mut self, player_id: i32, spell_id: i32, spell: &EsfRecord) {
+        let pet = match PETS.get_by_spell(spell_id) {
+            Some(pet) => pet,
+            None => return,
+        };
+
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        if character.tp < spell.tp_cost {
+            return;
+        }
+
+        character.tp -= spell.tp_cost;
+        character.used_spell(spell_id);
+
+        let hp_percentage = character.get_hp_percentage();
+
+        if let Some(player) = character.player.as_ref() {
+            player.send(
+                PacketAction::TargetSelf,
+                PacketFamily::Spell,
+                &SpellTargetSelfServerPacket {
+                    player_id,
+                    spell_id,
+                    spell_heal_hp: 0,
+                    hp_percentage,
+                    hp: Some(character.hp),
+                    tp: Some(character.tp),
+                },
+            );
+        }
+
+        self.send_packet_near_player(
+            player_id,
+            PacketAction::TargetSelf,
+            PacketFamily::Spell,
+            &SpellTargetSelfServerPacket {
+                player_id,
+                spell_id,
+                spell_heal_hp: 0,
+                hp_percentage,
+                hp: None,
+                tp: None,
+            },
+        );
+
+        self.summon_pet(player_id, pet.npc_id);
+    }
+
     async fn cast_heal_spell(
         &mut self,
         player_id: i32,
@@ -81,6 +138,7 @@ impl Map {
         }
 
         character.tp -= spell.tp_cost;
+        character.used_spell(spell_id);
         let original_hp = character.hp;
         character.hp = cmp::min(character.hp + spell.hp_heal, character.max_hp);
 
@@ -146,6 +204,7 @@ impl Map {
         };
 
         character.tp -= spell.tp_cost;
+        character.used_spell(spell_id);
 
         let mut healed_players: Vec<GroupHealTargetPlayer> =
             Vec::with_capacity(party_player_ids.len());
@@ -239,6 +298,7 @@ impl Map {
         }
 
         character.tp -= spell.tp_cost;
+        character.used_spell(spell_id);
 
         let target = match self.characters.get_mut(&target_player_id) {
             Some(character) => character,
@@ -344,7 +404,11 @@ impl Map {
 
         let direction = character.direction;
 
-        let npc = match self.npcs.iter_mut().find(|npc| npc.index == npc_index) {
+        let npc = match self
+            .npcs
+            .iter_mut()
+            .find(|npc| npc.index == npc_index && npc.owner.is_none())
+        {
             Some(npc) => npc,
             None => return,
         };
@@ -359,6 +423,7 @@ impl Map {
         }
 
         character.tp -= spell_data.tp_cost;
+        character.used_spell(spell_id);
 
         let party_player_ids = match self
             .world
@@ -471,6 +536,7 @@ impl Map {
             };
 
             character.tp -= spell_data.tp_cost;
+            character.used_spell(spell_id);
 
             if let Some(player) = character.player.as_ref() {
                 player.send(
@@ -522,5 +588,9 @@ impl Map {
 
             player.update_party_hp(target_character.get_hp_percentage());
         }
+
+        if target_character.hp == 0 {
+            self.pk_kill(player_id, target_player_id);
+        }
     }
 }
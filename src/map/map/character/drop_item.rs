@@ -10,7 +10,9 @@ use eolib::protocol::{
     r#pub::ItemSpecial,
 };
 
-use crate::{ITEM_DB, SETTINGS, utils::get_distance};
+use serde_json::json;
+
+use crate::{ITEM_DB, SETTINGS, player::Action, utils::get_distance};
 
 use super::super::Map;
 
@@ -98,6 +100,18 @@ impl Map {
         };
 
         if let Some(player) = character.player.as_ref() {
+            player.record_action(
+                Action::ItemDropped,
+                json!({
+                    "item_id": item.id,
+                    "amount": amount_to_drop,
+                    "map": character.map_id,
+                    "x": coords.x,
+                    "y": coords.y,
+                })
+                .to_string(),
+            );
+
             player.send(
                 PacketAction::Drop,
                 PacketFamily::Item,
@@ -7,7 +7,7 @@ use tokio::sync::oneshot;
 use crate::{
     NPC_DB,
     character::Character,
-    deep::{BossPingServerPacket, FAMILY_BOSS},
+    deep::{BossPingServerPacket, FAMILY_BOSS, FAMILY_CLOCK},
     utils::in_client_range,
 };
 
@@ -41,11 +41,23 @@ impl Map {
 
         let mut character = *new_character;
 
+        character.hour = self.hour;
         character.entered_map();
 
+        // Catch up on quest reloads that happened while the character was off a map
+        for message in character.remap_quests() {
+            warn!("{}", message);
+        }
+
         if character.is_deep
             && let Some(player) = &character.player
         {
+            player.send(
+                PacketAction::Ping,
+                PacketFamily::Unrecognized(FAMILY_CLOCK),
+                &self.get_clock_ping(),
+            );
+
             for npc in self.npcs.iter().filter(|npc| {
                 let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
                     Some(npc) => npc,
@@ -68,8 +80,10 @@ impl Map {
             }
         }
 
-        self.characters
-            .insert(character.player_id.unwrap(), character);
+        let player_id = character.player_id.unwrap();
+        self.characters.insert(player_id, character);
+
+        self.spawn_pet(player_id);
 
         let _ = respond_to.send(());
     }
@@ -3,7 +3,9 @@ use eolib::protocol::net::{
     server::{ItemAddServerPacket, ItemGetServerPacket, ItemRemoveServerPacket},
 };
 
-use crate::{SETTINGS, utils::get_distance};
+use serde_json::json;
+
+use crate::{SETTINGS, player::Action, utils::get_distance};
 
 use super::super::Map;
 
@@ -25,6 +27,15 @@ impl Map {
             return;
         }
 
+        // Player the item belonged to, if they're still on the map
+        let owner_name = match owner {
+            owner if owner != player_id => self
+                .characters
+                .get(&owner)
+                .map(|character| character.name.to_owned()),
+            _ => None,
+        };
+
         let character = match self.characters.get_mut(&player_id) {
             Some(character) => character,
             None => return,
@@ -43,6 +54,19 @@ impl Map {
         character.add_item(item_id, amount_picked_up);
 
         if let Some(player) = character.player.as_ref() {
+            player.record_action(
+                Action::ItemPickedUp,
+                json!({
+                    "item_id": item_id,
+                    "amount": amount_picked_up,
+                    "owner": owner_name,
+                    "map": character.map_id,
+                    "x": item_coords.x,
+                    "y": item_coords.y,
+                })
+                .to_string(),
+            );
+
             player.send(
                 PacketAction::Get,
                 PacketFamily::Item,
@@ -89,5 +113,7 @@ impl Map {
                 },
             );
         }
+
+        self.share_quest_item(player_id, item_id, amount_picked_up);
     }
 }
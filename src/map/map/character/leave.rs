@@ -25,6 +25,8 @@ impl Map {
             None => return,
         };
 
+        self.despawn_pet(player_id);
+
         if let Some(config) = ARENAS.arenas.iter().find(|a| a.map == self.id)
             && self.arena_players.iter().any(|p| p.player_id == player_id)
             && !config
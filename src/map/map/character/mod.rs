@@ -17,6 +17,7 @@ mod lose_item;
 mod open_captcha;
 mod open_door;
 mod party_request;
+mod pk_kill;
 mod remove_karma;
 mod request_book;
 mod request_npcs;
@@ -34,4 +35,5 @@ mod start_spell_chant;
 mod unequip;
 mod use_item;
 mod use_title_item;
+mod validate_walk;
 mod walk;
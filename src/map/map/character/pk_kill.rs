@@ -0,0 +1,50 @@
+use evalexpr::{DefaultNumericTypes, HashMapContext, context_map, eval_float_with_context};
+
+use crate::{FORMULAS, SETTINGS};
+
+use super::super::Map;
+
+impl Map {
+    pub fn pk_kill(&mut self, killer_player_id: i32, victim_player_id: i32) {
+        let (victim_karma, victim_level, victim_flagged) =
+            match self.characters.get(&victim_player_id) {
+                Some(character) => (character.karma, character.level, character.pk_ticks > 0),
+                None => return,
+            };
+
+        let killer = match self.characters.get_mut(&killer_player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        killer.killed_player();
+        killer.pk_ticks = SETTINGS.karma.pk_flag_seconds;
+
+        let context: HashMapContext<DefaultNumericTypes> = match context_map! {
+            "killer_karma" => float killer.karma,
+            "killer_level" => float killer.level,
+            "victim_karma" => float victim_karma,
+            "victim_level" => float victim_level,
+            "victim_flagged" => victim_flagged,
+            "wanted_karma" => float SETTINGS.guards.wanted_karma,
+        } {
+            Ok(context) => context,
+            Err(e) => {
+                error!("Failed to generate formula context: {}", e);
+                return;
+            }
+        };
+
+        let karma_loss = match eval_float_with_context(&FORMULAS.pk_karma_loss, &context) {
+            Ok(karma_loss) => karma_loss.floor() as i32,
+            Err(e) => {
+                error!("Failed to calculate pk karma loss: {}", e);
+                return;
+            }
+        };
+
+        if karma_loss > 0 {
+            self.remove_karma(killer_player_id, karma_loss);
+        }
+    }
+}
@@ -3,8 +3,8 @@ use eolib::protocol::net::{PacketAction, PacketFamily, server::TalkPlayerServerP
 use super::super::Map;
 
 impl Map {
-    pub fn send_chat_message(&self, player_id: i32, message: String) {
-        let character = match self.characters.get(&player_id) {
+    pub fn send_chat_message(&mut self, player_id: i32, message: String) {
+        let character = match self.characters.get_mut(&player_id) {
             Some(character) => character,
             None => return,
         };
@@ -13,6 +13,8 @@ impl Map {
             return;
         }
 
+        character.record_chat(&message);
+
         self.send_packet_near_player(
             player_id,
             PacketAction::Player,
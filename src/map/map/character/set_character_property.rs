@@ -119,6 +119,10 @@ impl Map {
             }
         };
 
+        if let Some(character) = self.characters.get_mut(&player_id) {
+            character.check_quest_rules();
+        }
+
         if visible {
             let character = match self.characters.get(&player_id) {
                 Some(character) => character,
@@ -11,6 +11,7 @@ impl Map {
 
         character.class = class_id;
         character.calculate_stats();
+        character.check_quest_rules();
 
         if let Some(player) = character.player.as_ref() {
             player.send(
@@ -22,8 +22,8 @@ use eolib::{
 };
 
 use crate::{
-    INN_DB, ITEM_DB, SETTINGS, SPELL_DB, character::EquipmentSlot, deep::AVATAR_CHANGE_TYPE_SKIN,
-    utils::in_client_range,
+    INN_DB, ITEM_DB, PETS, SETTINGS, SPELL_DB, character::EquipmentSlot,
+    deep::AVATAR_CHANGE_TYPE_SKIN, utils::in_client_range,
 };
 
 use super::super::Map;
@@ -48,6 +48,11 @@ impl Map {
             return;
         }
 
+        if let Some(pet) = PETS.get_by_item(item_id) {
+            self.summon_pet(player_id, pet.npc_id);
+            return;
+        }
+
         let item = match ITEM_DB.items.get(item_id as usize - 1) {
             Some(item) => item,
             None => {
@@ -378,6 +383,8 @@ impl Map {
             character.remove_item(item_id, 1);
         }
 
+        character.used_item(item_id);
+
         if let Some(player) = character.player.as_ref() {
             packet.used_item = Item {
                 id: item_id,
@@ -0,0 +1,72 @@
+use chrono::Utc;
+use eolib::protocol::Coords;
+
+use crate::{SETTINGS, utils::get_distance};
+
+use super::super::Map;
+
+impl Map {
+    /// Checks a step against the walk speed and the client's reported position,
+    /// escalating repeated violations. Returns false if the step should be rejected
+    pub fn validate_walk(&mut self, player_id: i32, coords: Coords, client_coords: Coords) -> bool {
+        let policy = &SETTINGS.movement;
+        if !policy.enabled {
+            return true;
+        }
+
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return false,
+        };
+
+        if i32::from(character.admin_level) >= 1 {
+            return true;
+        }
+
+        let now = Utc::now();
+        let reason = if !character.movement.step(now, policy.step_ms, policy.burst) {
+            "walking too fast".to_string()
+        } else if get_distance(&coords, &client_coords) > policy.max_desync {
+            format!(
+                "client at {},{} but server at {},{}",
+                client_coords.x, client_coords.y, coords.x, coords.y
+            )
+        } else {
+            return true;
+        };
+
+        let violations = character
+            .movement
+            .add_violation(now, policy.violation_decay);
+
+        if reached(policy.log, violations) {
+            warn!(
+                "Movement violation by {} on map {} ({} recent): {}",
+                character.name, character.map_id, violations, reason
+            );
+        }
+
+        if policy.alert_admins > 0 && violations == policy.alert_admins {
+            self.world.notify_admins(
+                "Movement".to_string(),
+                format!(
+                    "{} has {} movement violations: {}",
+                    character.name, violations, reason
+                ),
+            );
+        }
+
+        if reached(policy.kick, violations) {
+            if let Some(player) = character.player.as_ref() {
+                player.close(format!("Movement violations: {}", reason));
+            }
+            return false;
+        }
+
+        !reached(policy.warp_back, violations)
+    }
+}
+
+fn reached(threshold: i32, violations: i32) -> bool {
+    threshold > 0 && violations >= threshold
+}
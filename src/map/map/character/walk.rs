@@ -41,10 +41,17 @@ impl Map {
 
             Some((previous_coords, coords, player, hidden))
         } {
+            if !self.validate_walk(player_id, coords, client_coords)
+                || self.weather_blocks_step(player_id)
+            {
+                self.request_refresh(player_id);
+                return;
+            }
+
             if let Some(character) = self.characters.get_mut(&player_id) {
                 character.coords = coords;
                 character.direction = direction;
-                character.entered_coord();
+                character.entered_coord(previous_coords);
                 character.warp_suck_ticks = SETTINGS.world.warp_suck_rate;
                 character.ghost_ticks = SETTINGS.world.ghost_rate;
             }
@@ -7,7 +7,9 @@ use eolib::{
     },
 };
 
-use crate::{map::Chest, utils::get_distance};
+use serde_json::json;
+
+use crate::{map::Chest, player::Action, utils::get_distance};
 
 use super::super::Map;
 
@@ -83,6 +85,18 @@ impl Map {
         character.add_item(item.item_id, item.amount);
 
         if let Some(player) = character.player.as_ref() {
+            player.record_action(
+                Action::ChestItemTaken,
+                json!({
+                    "item_id": item.item_id,
+                    "amount": item.amount,
+                    "map": character.map_id,
+                    "x": chest.coords.x,
+                    "y": chest.coords.y,
+                })
+                .to_string(),
+            );
+
             player.send(
                 PacketAction::Get,
                 PacketFamily::Chest,
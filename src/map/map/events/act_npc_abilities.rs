@@ -0,0 +1,382 @@
+use std::cmp;
+
+use eolib::protocol::{
+    Coords, Direction,
+    net::{
+        PacketAction, PacketFamily,
+        server::{AvatarAdminServerPacket, CastReplyServerPacket, RecoverPlayerServerPacket},
+    },
+    r#pub::{EsfRecord, NpcType, SkillTargetType, SkillType},
+};
+use rand::RngExt;
+
+use crate::{
+    NPC_ABILITIES, NPC_DB, SETTINGS, SPELL_DB, npc_abilities::NpcAbility, utils::get_distance,
+};
+
+use super::{super::Map, act_npcs::get_damage_amount};
+
+/// Spell packets name a player as the caster. Player ids start at 300 so
+/// the stock client animates the spell without anyone casting it
+pub(super) const NO_CASTER: i32 = 0;
+
+impl Map {
+    /// Returns true if the npc used one of its abilities instead of acting normally
+    pub(super) fn act_npc_ability(&mut self, index: i32, npc_id: i32) -> bool {
+        let ability_set = match NPC_ABILITIES.get(npc_id) {
+            Some(ability_set) => ability_set,
+            None => return false,
+        };
+
+        let mut rng = rand::rng();
+
+        for (ability_index, ability) in ability_set.abilities.iter().enumerate() {
+            let npc = match self.npcs.iter().find(|npc| npc.index == index) {
+                Some(npc) => npc,
+                None => return false,
+            };
+
+            if npc.ability_cooldowns.contains_key(&ability_index)
+                || rng.random_range(1..=100) > ability.chance
+            {
+                continue;
+            }
+
+            let used = if ability.spell_id == 0 {
+                self.npc_ranged_attack(index, npc_id, ability)
+            } else {
+                self.npc_cast_spell(index, npc_id, ability)
+            };
+
+            if used {
+                if let Some(npc) = self.npcs.iter_mut().find(|npc| npc.index == index) {
+                    npc.act_ticks = 0;
+                    if ability.cooldown > 0 {
+                        npc.ability_cooldowns.insert(
+                            ability_index,
+                            ability.cooldown * 1000 / SETTINGS.world.tick_rate,
+                        );
+                    }
+                }
+
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn npc_get_ability_target(
+        &self,
+        index: i32,
+        npc_id: i32,
+        ability: &NpcAbility,
+        line_of_sight: bool,
+    ) -> Option<i32> {
+        let npc = self.npcs.iter().find(|npc| npc.index == index)?;
+        if npc.get_hp_percentage() > ability.hp_threshold {
+            return None;
+        }
+
+        let player_id = self.npc_get_chase_target_player_id(index, npc_id)?;
+        let character = self.characters.get(&player_id)?;
+
+        if get_distance(&npc.coords, &character.coords) > ability.range
+            || (line_of_sight && !self.has_line_of_sight(&npc.coords, &character.coords))
+        {
+            return None;
+        }
+
+        Some(player_id)
+    }
+
+    /// Shown like a spell so the client draws the projectile's graphic on the
+    /// target instead of a melee swing from across the map
+    fn npc_ranged_attack(&mut self, index: i32, npc_id: i32, ability: &NpcAbility) -> bool {
+        let target_player_id = match self.npc_get_ability_target(index, npc_id, ability, true) {
+            Some(player_id) => player_id,
+            None => return false,
+        };
+
+        let damage = {
+            let character = match self.characters.get(&target_player_id) {
+                Some(character) => character,
+                None => return false,
+            };
+
+            let npc = match self.npcs.iter_mut().find(|npc| npc.index == index) {
+                Some(npc) => npc,
+                None => return false,
+            };
+
+            // Like a player's bow, shots only fly straight along a row or column
+            if npc.coords.x != character.coords.x && npc.coords.y != character.coords.y {
+                return false;
+            }
+
+            let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+                Some(npc_data) => npc_data,
+                None => return false,
+            };
+
+            npc.direction = get_direction_towards(&npc.coords, &character.coords);
+            get_damage_amount(npc, npc_data, character)
+        };
+
+        match self.characters.get_mut(&target_player_id) {
+            Some(character) => character.hp = cmp::max(character.hp - damage, 0),
+            None => return false,
+        }
+
+        self.npc_show_spell_hit(index, target_player_id, ability.projectile_id, damage);
+
+        true
+    }
+
+    fn npc_cast_spell(&mut self, index: i32, npc_id: i32, ability: &NpcAbility) -> bool {
+        let spell = match SPELL_DB.skills.get(ability.spell_id as usize - 1) {
+            Some(spell) => spell,
+            None => return false,
+        };
+
+        match self.npcs.iter().find(|npc| npc.index == index) {
+            Some(npc) if npc.tp >= spell.tp_cost => {}
+            _ => return false,
+        }
+
+        let cast = match spell.r#type {
+            SkillType::Heal => self.npc_cast_heal(index, ability, spell),
+            SkillType::Attack => self.npc_cast_attack(index, npc_id, ability, spell),
+            _ => false,
+        };
+
+        if cast && let Some(npc) = self.npcs.iter_mut().find(|npc| npc.index == index) {
+            npc.tp -= spell.tp_cost;
+        }
+
+        cast
+    }
+
+    fn npc_cast_heal(&mut self, index: i32, ability: &NpcAbility, spell: &EsfRecord) -> bool {
+        let (coords, direction) = match self.npcs.iter().find(|npc| npc.index == index) {
+            Some(npc) => (npc.coords, npc.direction),
+            None => return false,
+        };
+
+        let mut targets = self
+            .npcs
+            .iter()
+            .filter(|npc| {
+                npc.alive
+                    && npc.hp < npc.max_hp
+                    && npc.get_hp_percentage() <= ability.hp_threshold
+                    && (npc.index == index
+                        || (spell.target_type != SkillTargetType::SELF
+                            && get_distance(&coords, &npc.coords) <= ability.range
+                            && NPC_DB
+                                .npcs
+                                .get(npc.id as usize - 1)
+                                .is_some_and(|npc_data| {
+                                    matches!(
+                                        npc_data.r#type,
+                                        NpcType::Passive | NpcType::Aggressive
+                                    )
+                                })))
+            })
+            .map(|npc| (npc.index, npc.get_hp_percentage()))
+            .collect::<Vec<_>>();
+
+        if spell.target_type != SkillTargetType::Group {
+            targets = targets
+                .into_iter()
+                .min_by_key(|(_, hp_percentage)| *hp_percentage)
+                .into_iter()
+                .collect();
+        }
+
+        if targets.is_empty() {
+            return false;
+        }
+
+        for (target_index, _) in targets {
+            let npc = match self.npcs.iter_mut().find(|npc| npc.index == target_index) {
+                Some(npc) => npc,
+                None => continue,
+            };
+
+            npc.hp = cmp::min(npc.hp + spell.hp_heal, npc.max_hp);
+
+            let packet = CastReplyServerPacket {
+                spell_id: ability.spell_id,
+                caster_id: NO_CASTER,
+                caster_direction: direction,
+                npc_index: target_index,
+                damage: 0,
+                hp_percentage: npc.get_hp_percentage(),
+                caster_tp: None,
+                kill_steal_protection: None,
+            };
+
+            let coords = npc.coords;
+            self.send_packet_near(&coords, PacketAction::Reply, PacketFamily::Cast, packet);
+        }
+
+        true
+    }
+
+    fn npc_cast_attack(
+        &mut self,
+        index: i32,
+        npc_id: i32,
+        ability: &NpcAbility,
+        spell: &EsfRecord,
+    ) -> bool {
+        let group = spell.target_type == SkillTargetType::Group;
+
+        // Group spells hit everyone around the caster so don't need a clear shot
+        let target_player_id = match self.npc_get_ability_target(index, npc_id, ability, !group) {
+            Some(player_id) => player_id,
+            None => return false,
+        };
+
+        let target_coords = match self.characters.get(&target_player_id) {
+            Some(character) => character.coords,
+            None => return false,
+        };
+
+        let coords = match self.npcs.iter_mut().find(|npc| npc.index == index) {
+            Some(npc) => {
+                npc.direction = get_direction_towards(&npc.coords, &target_coords);
+                npc.coords
+            }
+            None => return false,
+        };
+
+        let victim_ids = if group {
+            self.characters
+                .iter()
+                .filter(|(_, character)| {
+                    !character.hidden
+                        && !character.captcha_open
+                        && get_distance(&coords, &character.coords) <= ability.range
+                })
+                .map(|(player_id, _)| *player_id)
+                .collect()
+        } else {
+            vec![target_player_id]
+        };
+
+        for victim_id in victim_ids {
+            self.npc_spell_hit_player(index, npc_id, victim_id, ability.spell_id, spell);
+        }
+
+        true
+    }
+
+    fn npc_spell_hit_player(
+        &mut self,
+        index: i32,
+        npc_id: i32,
+        victim_id: i32,
+        spell_id: i32,
+        spell: &EsfRecord,
+    ) {
+        let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+            Some(npc_data) => npc_data,
+            None => return,
+        };
+
+        let damage_multiplier = match self.npcs.iter().find(|npc| npc.index == index) {
+            Some(npc) => npc.get_damage_multiplier(),
+            None => return,
+        };
+
+        let amount = {
+            let min_damage = npc_data.min_damage + spell.min_damage;
+            let max_damage = cmp::max(npc_data.max_damage + spell.max_damage, min_damage);
+            let mut rng = rand::rng();
+            (rng.random_range(min_damage..=max_damage) as f32 * damage_multiplier).floor() as i32
+        };
+
+        let victim = match self.characters.get_mut(&victim_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        let critical = victim.hp == victim.max_hp;
+        let damage = victim.damage(amount, npc_data.accuracy, critical);
+
+        self.npc_show_spell_hit(index, victim_id, spell_id, damage);
+    }
+
+    /// Sends a hit the victim has already taken to everyone nearby as a spell
+    fn npc_show_spell_hit(&mut self, index: i32, victim_id: i32, spell_id: i32, damage: i32) {
+        let direction = match self.npcs.iter().find(|npc| npc.index == index) {
+            Some(npc) => npc.direction,
+            None => return,
+        };
+
+        let victim = match self.characters.get(&victim_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        self.send_packet_near(
+            &victim.coords,
+            PacketAction::Admin,
+            PacketFamily::Avatar,
+            AvatarAdminServerPacket {
+                caster_id: NO_CASTER,
+                victim_id,
+                caster_direction: direction,
+                damage,
+                hp_percentage: victim.get_hp_percentage(),
+                victim_died: victim.hp == 0,
+                spell_id,
+            },
+        );
+
+        let killed = victim.hp == 0;
+
+        if let Some(player) = victim.player.as_ref() {
+            player.send(
+                PacketAction::Player,
+                PacketFamily::Recover,
+                &RecoverPlayerServerPacket {
+                    hp: victim.hp,
+                    tp: victim.tp,
+                },
+            );
+
+            if damage > 0 {
+                player.update_party_hp(victim.get_hp_percentage());
+            }
+
+            if killed {
+                player.die();
+            }
+        }
+
+        if killed && let Some(npc) = self.npcs.iter_mut().find(|npc| npc.index == index) {
+            npc.opponents
+                .retain(|opponent| opponent.player_id != victim_id);
+        }
+    }
+}
+
+fn get_direction_towards(from: &Coords, to: &Coords) -> Direction {
+    let x_delta = to.x - from.x;
+    let y_delta = to.y - from.y;
+
+    if x_delta.abs() > y_delta.abs() {
+        if x_delta > 0 {
+            Direction::Right
+        } else {
+            Direction::Left
+        }
+    } else if y_delta > 0 {
+        Direction::Down
+    } else {
+        Direction::Up
+    }
+}
@@ -0,0 +1,25 @@
+use eolib::protocol::Coords;
+
+use super::super::Map;
+
+impl Map {
+    /// Waypoint the npc is walking to, moving on to the next one once it's reached
+    pub(super) fn get_npc_patrol_target(&mut self, index: i32) -> Option<Coords> {
+        let npc = self.npcs.iter_mut().find(|npc| npc.index == index)?;
+        let patrol = &self.spawns.get(npc.spawn_index?)?.patrol;
+
+        if patrol.is_empty() {
+            return None;
+        }
+
+        npc.patrol_index %= patrol.len();
+
+        let mut target = patrol[npc.patrol_index];
+        if npc.coords == target {
+            npc.patrol_index = (npc.patrol_index + 1) % patrol.len();
+            target = patrol[npc.patrol_index];
+        }
+
+        Some(target)
+    }
+}
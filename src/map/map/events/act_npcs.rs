@@ -22,7 +22,7 @@ use crate::{
     utils::{get_distance, get_next_coords, in_range},
 };
 
-use super::super::Map;
+use super::{super::Map, act_pets::PetKill};
 
 impl Map {
     fn act_npc_talk(&mut self, index: i32, npc_id: i32) -> Option<NpcUpdateChat> {
@@ -66,6 +66,14 @@ impl Map {
             }
         };
 
+        self.act_npc_move_towards(index, target_coords)
+    }
+
+    pub(super) fn act_npc_move_towards(
+        &mut self,
+        index: i32,
+        target_coords: Coords,
+    ) -> Option<NpcUpdatePosition> {
         let npc_coords = self.npcs.iter().find_map(|npc| {
             if npc.index == index {
                 Some(npc.coords)
@@ -157,7 +165,7 @@ impl Map {
     }
 
     // TODO: Party stuff
-    fn npc_get_chase_target_player_id(&self, index: i32, npc_id: i32) -> Option<i32> {
+    pub(super) fn npc_get_chase_target_player_id(&self, index: i32, npc_id: i32) -> Option<i32> {
         let npc_data = NPC_DB.npcs.get(npc_id as usize - 1)?;
 
         let npc = self.npcs.iter().find(|npc| npc.index == index)?;
@@ -178,14 +186,13 @@ impl Map {
             opponents_in_range
                 .max_by(|a, b| a.damage_dealt.cmp(&b.damage_dealt))
                 .map(|opponent| opponent.player_id)
-        } else if npc_data.r#type == NpcType::Aggressive && !self.characters.is_empty() {
+        } else if self.npc_hunts(npc_id, npc_data.r#type) && !self.characters.is_empty() {
             // find closest player
             self.characters
                 .iter()
                 .filter(|(_, character)| {
                     let distance = get_distance(&npc.coords, &character.coords);
-                    !character.hidden
-                        && !character.captcha_open
+                    self.npc_hunts_player(npc_id, npc_data.r#type, character)
                         && distance <= SETTINGS.npcs.chase_distance
                 })
                 .min_by(|(_, a), (_, b)| {
@@ -204,7 +211,7 @@ impl Map {
 
         let adjacent_tiles = self.get_adjacent_tiles(&npc.coords);
 
-        let adjacent_player_ids = self
+        let adjacent_players = self
             .characters
             .iter()
             .filter(|(_, character)| {
@@ -212,13 +219,16 @@ impl Map {
                     coords == &character.coords && !character.hidden && !character.captcha_open
                 })
             })
-            .map(|(player_id, _)| *player_id)
             .collect::<Vec<_>>();
 
         let adjacent_opponent = npc
             .opponents
             .iter()
-            .filter(|opponent| adjacent_player_ids.contains(&opponent.player_id))
+            .filter(|opponent| {
+                adjacent_players
+                    .iter()
+                    .any(|(player_id, _)| **player_id == opponent.player_id)
+            })
             .max_by_key(|opponent| opponent.damage_dealt);
 
         if let Some(opponent) = adjacent_opponent {
@@ -227,13 +237,15 @@ impl Map {
             let npc_data = NPC_DB.npcs.get(npc.id as usize - 1)?;
 
             // TODO: also attack adjacent players if blocking path to opponent(s)
-            // Choose a random player if npc is aggressive
-            if npc_data.r#type == NpcType::Aggressive {
-                let mut rng = rand::rng();
-                adjacent_player_ids.choose(&mut rng).copied()
-            } else {
-                None
-            }
+            // Choose a random player the npc hunts
+            let hunted_player_ids = adjacent_players
+                .iter()
+                .filter(|(_, character)| self.npc_hunts_player(npc.id, npc_data.r#type, character))
+                .map(|(player_id, _)| **player_id)
+                .collect::<Vec<_>>();
+
+            let mut rng = rand::rng();
+            hunted_player_ids.choose(&mut rng).copied()
         }
     }
 
@@ -305,10 +317,19 @@ impl Map {
 
         let npc_data = NPC_DB.npcs.get(npc_id as usize - 1)?;
 
-        if npc_data.r#type == NpcType::Aggressive || has_opponent {
+        // Passive npcs only give chase once someone they hunt is in range so they
+        // keep their idle pace otherwise
+        let hunting = npc_data.r#type == NpcType::Aggressive
+            || has_opponent
+            || self.npc_get_chase_target_player_id(index, npc_id).is_some();
+
+        if hunting {
             self.act_npc_move_chase(index, npc_id, npc_data.r#type)
         } else if act_ticks >= idle_rate {
-            self.act_npc_move_idle(index)
+            match self.get_npc_patrol_target(index) {
+                Some(target_coords) => self.act_npc_move_towards(index, target_coords),
+                None => self.act_npc_move_idle(index),
+            }
         } else {
             None
         }
@@ -339,6 +360,16 @@ impl Map {
             (get_damage_amount(npc, npc_data, character), direction)
         };
 
+        self.npc_hit_player(index, target_player_id, direction, damage)
+    }
+
+    pub(super) fn npc_hit_player(
+        &mut self,
+        index: i32,
+        target_player_id: i32,
+        direction: Direction,
+        damage: i32,
+    ) -> Option<NpcUpdateAttack> {
         let (killed_state, hp_percentage) = {
             let character = self.characters.get_mut(&target_player_id)?;
 
@@ -403,23 +434,19 @@ impl Map {
 
                         npc.act_ticks += SETTINGS.npcs.act_rate;
                         npc.talk_ticks += SETTINGS.npcs.act_rate;
+
+                        for ticks in npc.ability_cooldowns.values_mut() {
+                            *ticks -= SETTINGS.npcs.act_rate;
+                        }
+                        npc.ability_cooldowns.retain(|_, ticks| *ticks > 0);
+
                         (npc.id, npc.spawn_type, npc.act_ticks)
                     }
                 }
                 None => return (None, None, None),
             };
 
-        let act_rate = match spawn_type {
-            0 => SETTINGS.npcs.speed_0,
-            1 => SETTINGS.npcs.speed_1,
-            2 => SETTINGS.npcs.speed_2,
-            3 => SETTINGS.npcs.speed_3,
-            4 => SETTINGS.npcs.speed_4,
-            5 => SETTINGS.npcs.speed_5,
-            6 => SETTINGS.npcs.speed_6,
-            7 => 0,
-            _ => unreachable!("Invalid act rate {} for NPC {}", spawn_type, npc_id),
-        };
+        let act_rate = get_act_rate(spawn_type, npc_id);
 
         let talk_update = self.act_npc_talk(index, npc_id);
 
@@ -427,8 +454,13 @@ impl Map {
             (None, talk_update, None)
         } else {
             self.drop_opponents(index);
-            let attack_update = self.act_npc_attack(index, npc_id);
-            let pos_update = if attack_update.is_some() {
+            let used_ability = self.act_npc_ability(index, npc_id);
+            let attack_update = if used_ability {
+                None
+            } else {
+                self.act_npc_attack(index, npc_id)
+            };
+            let pos_update = if used_ability || attack_update.is_some() {
                 None
             } else {
                 self.act_npc_move(index, npc_id, act_rate, act_ticks)
@@ -447,14 +479,14 @@ impl Map {
             .retain(|o| o.bored_ticks < SETTINGS.npcs.bored_timer);
     }
 
-    pub fn act_npcs(&mut self) {
+    pub async fn act_npcs(&mut self) {
         if self.npcs.is_empty() || SETTINGS.npcs.freeze_on_empty_map && self.characters.is_empty() {
             return;
         }
 
         if !self.npcs_initialized {
             self.npcs_initialized = true;
-            for (spawn_index, spawn) in self.file.npcs.iter().enumerate() {
+            for (spawn_index, spawn) in self.spawns.iter().enumerate() {
                 let npcs = {
                     self.npcs
                         .iter()
@@ -480,8 +512,25 @@ impl Map {
         let mut position_updates: Vec<NpcUpdatePosition> = Vec::with_capacity(self.npcs.len());
         let mut talk_updates: Vec<NpcUpdateChat> = Vec::with_capacity(self.npcs.len());
 
+        let mut pet_kills: Vec<PetKill> = Vec::new();
+
         let indexes = self.npcs.iter().map(|npc| npc.index).collect::<Vec<i32>>();
         for index in indexes {
+            if self
+                .npcs
+                .iter()
+                .any(|npc| npc.index == index && npc.owner.is_some())
+            {
+                let (move_update, pet_kill) = self.act_pet(index);
+                if let Some(move_update) = move_update {
+                    position_updates.push(move_update);
+                }
+                if let Some(pet_kill) = pet_kill {
+                    pet_kills.push(pet_kill);
+                }
+                continue;
+            }
+
             let (move_update, chat_updatee, attack_update) = self.act_npc(index);
             if let Some(attack_update) = attack_update {
                 attack_updates.push(attack_update);
@@ -560,14 +609,39 @@ impl Map {
                 }
             }
         }
+
+        for pet_kill in pet_kills {
+            self.attack_npc_killed_reply(
+                pet_kill.owner_player_id,
+                pet_kill.npc_index,
+                pet_kill.damage,
+                None,
+            )
+            .await;
+        }
+    }
+}
+
+pub(super) fn get_act_rate(spawn_type: i32, npc_id: i32) -> i32 {
+    match spawn_type {
+        0 => SETTINGS.npcs.speed_0,
+        1 => SETTINGS.npcs.speed_1,
+        2 => SETTINGS.npcs.speed_2,
+        3 => SETTINGS.npcs.speed_3,
+        4 => SETTINGS.npcs.speed_4,
+        5 => SETTINGS.npcs.speed_5,
+        6 => SETTINGS.npcs.speed_6,
+        7 => 0,
+        _ => unreachable!("Invalid act rate {} for NPC {}", spawn_type, npc_id),
     }
 }
 
-fn get_damage_amount(npc: &Npc, npc_data: &EnfRecord, character: &Character) -> i32 {
+pub(super) fn get_damage_amount(npc: &Npc, npc_data: &EnfRecord, character: &Character) -> i32 {
     let mut rng = rand::rng();
     let rand = rng.random_range(0.0..=1.0);
 
     let amount = rng.random_range(npc_data.min_damage..=npc_data.max_damage);
+    let amount = (amount as f32 * npc.get_damage_multiplier()).floor() as i32;
 
     let npc_facing_player_back_or_side =
         (i32::from(character.direction) - i32::from(npc.direction)).abs() != 2;
@@ -0,0 +1,251 @@
+use eolib::protocol::{
+    Coords, Direction,
+    net::{
+        PacketAction, PacketFamily,
+        server::{NpcReplyServerPacket, NpcUpdatePosition},
+    },
+};
+use rand::RngExt;
+
+use crate::{NPC_DB, PETS, SETTINGS, utils::get_distance};
+
+use super::{super::Map, act_npc_abilities::NO_CASTER, act_npcs::get_act_rate};
+
+#[derive(Debug, PartialEq)]
+enum PetAction {
+    /// Left too far behind, jumps back next to its owner
+    CatchUp,
+    Attack(i32),
+    MoveTowards(Coords),
+    Idle,
+}
+
+pub(super) struct PetKill {
+    pub owner_player_id: i32,
+    pub npc_index: i32,
+    pub damage: i32,
+}
+
+impl Map {
+    pub(super) fn act_pet(&mut self, index: i32) -> (Option<NpcUpdatePosition>, Option<PetKill>) {
+        let (npc_id, coords, spawn_type, act_ticks, owner_player_id) =
+            match self.npcs.iter_mut().find(|npc| npc.index == index) {
+                Some(npc) if npc.alive => {
+                    npc.act_ticks += SETTINGS.npcs.act_rate;
+                    (
+                        npc.id,
+                        npc.coords,
+                        npc.spawn_type,
+                        npc.act_ticks,
+                        npc.owner.unwrap_or_default(),
+                    )
+                }
+                _ => return (None, None),
+            };
+
+        let act_rate = get_act_rate(spawn_type, npc_id);
+        if act_rate == 0 || act_ticks < act_rate {
+            return (None, None);
+        }
+
+        let owner_coords = match self.characters.get(&owner_player_id) {
+            Some(character) => character.coords,
+            None => return (None, None),
+        };
+
+        let target = self
+            .npcs
+            .iter()
+            .filter(|npc| {
+                npc.alive
+                    && npc.owner.is_none()
+                    && npc
+                        .opponents
+                        .iter()
+                        .any(|opponent| opponent.player_id == owner_player_id)
+            })
+            .min_by_key(|npc| get_distance(&coords, &npc.coords))
+            .map(|npc| (npc.index, npc.coords));
+
+        match get_pet_action(coords, owner_coords, target, SETTINGS.npcs.chase_distance) {
+            PetAction::CatchUp => (self.pet_catch_up(index, owner_coords), None),
+            PetAction::Attack(target_index) => (None, self.pet_attack_npc(index, target_index)),
+            PetAction::MoveTowards(target_coords) => {
+                (self.act_npc_move_towards(index, target_coords), None)
+            }
+            PetAction::Idle => (None, None),
+        }
+    }
+
+    /// Moves a pet that was left behind onto a free tile next to its owner
+    fn pet_catch_up(&mut self, index: i32, owner_coords: Coords) -> Option<NpcUpdatePosition> {
+        let coords = self
+            .get_adjacent_tiles(&owner_coords)
+            .into_iter()
+            .find(|coords| {
+                *coords != owner_coords
+                    && self.is_tile_walkable_npc(coords)
+                    && !self.is_tile_occupied(coords)
+            })?;
+
+        let npc = self.npcs.iter_mut().find(|npc| npc.index == index)?;
+        npc.coords = coords;
+        npc.act_ticks = 0;
+
+        Some(NpcUpdatePosition {
+            npc_index: index,
+            coords: npc.coords,
+            direction: npc.direction,
+        })
+    }
+
+    fn pet_attack_npc(&mut self, index: i32, target_index: i32) -> Option<PetKill> {
+        let (npc_id, coords, owner_player_id) = {
+            let npc = self.npcs.iter().find(|npc| npc.index == index)?;
+            (npc.id, npc.coords, npc.owner?)
+        };
+
+        let pet_data = PETS.get(npc_id)?;
+        let npc_data = NPC_DB.npcs.get(npc_id as usize - 1)?;
+
+        let level = {
+            let character = self.characters.get(&owner_player_id)?;
+            let pet = character.pets.iter().find(|pet| pet.npc_id == npc_id)?;
+            pet_data.get_level(pet.experience)
+        };
+
+        let mut rng = rand::rng();
+        let amount = rng.random_range(npc_data.min_damage..=npc_data.max_damage)
+            + pet_data.get_bonus_damage(level);
+
+        let (target_coords, damage_dealt, hp_percentage, killed) = {
+            let target = self.npcs.iter_mut().find(|npc| npc.index == target_index)?;
+            let damage_dealt = target.damage(owner_player_id, amount, npc_data.accuracy, false);
+            (
+                target.coords,
+                damage_dealt,
+                target.get_hp_percentage(),
+                !target.alive,
+            )
+        };
+
+        let direction = get_facing_direction(coords, target_coords);
+
+        if let Some(npc) = self.npcs.iter_mut().find(|npc| npc.index == index) {
+            npc.direction = direction;
+            npc.act_ticks = 0;
+        }
+
+        if killed {
+            return Some(PetKill {
+                owner_player_id,
+                npc_index: target_index,
+                damage: damage_dealt,
+            });
+        }
+
+        self.update_boss_fight(owner_player_id, target_index, damage_dealt);
+
+        self.send_packet_near(
+            &target_coords,
+            PacketAction::Reply,
+            PacketFamily::Npc,
+            NpcReplyServerPacket {
+                player_id: NO_CASTER,
+                player_direction: direction,
+                npc_index: target_index,
+                damage: damage_dealt,
+                hp_percentage,
+                kill_steal_protection: None,
+            },
+        );
+
+        None
+    }
+}
+
+/// Picks what a pet does this act: fight the nearest npc its owner is fighting, otherwise
+/// stay by its owner's side
+fn get_pet_action(
+    coords: Coords,
+    owner_coords: Coords,
+    target: Option<(i32, Coords)>,
+    chase_distance: i32,
+) -> PetAction {
+    if get_distance(&coords, &owner_coords) > chase_distance {
+        return PetAction::CatchUp;
+    }
+
+    match target {
+        Some((target_index, target_coords)) if get_distance(&coords, &target_coords) == 1 => {
+            PetAction::Attack(target_index)
+        }
+        Some((_, target_coords)) if get_distance(&coords, &target_coords) <= chase_distance => {
+            PetAction::MoveTowards(target_coords)
+        }
+        _ if get_distance(&coords, &owner_coords) > 1 => PetAction::MoveTowards(owner_coords),
+        _ => PetAction::Idle,
+    }
+}
+
+fn get_facing_direction(coords: Coords, target_coords: Coords) -> Direction {
+    match (coords.x - target_coords.x, coords.y - target_coords.y) {
+        (0, 1) => Direction::Up,
+        (1, 0) => Direction::Left,
+        (-1, 0) => Direction::Right,
+        _ => Direction::Down,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eolib::protocol::{Coords, Direction};
+
+    use super::{PetAction, get_facing_direction, get_pet_action};
+
+    #[test]
+    fn pet_follows_owner_and_catches_up_when_left_behind() {
+        let owner = Coords { x: 5, y: 5 };
+
+        assert_eq!(
+            get_pet_action(Coords { x: 5, y: 6 }, owner, None, 10),
+            PetAction::Idle
+        );
+        assert_eq!(
+            get_pet_action(Coords { x: 5, y: 8 }, owner, None, 10),
+            PetAction::MoveTowards(owner)
+        );
+        assert_eq!(
+            get_pet_action(Coords { x: 5, y: 20 }, owner, Some((3, owner)), 10),
+            PetAction::CatchUp
+        );
+    }
+
+    #[test]
+    fn pet_attacks_adjacent_targets_and_chases_nearby_ones() {
+        let owner = Coords { x: 5, y: 5 };
+        let pet = Coords { x: 5, y: 6 };
+
+        assert_eq!(
+            get_pet_action(pet, owner, Some((3, Coords { x: 6, y: 6 })), 10),
+            PetAction::Attack(3)
+        );
+        assert_eq!(
+            get_pet_action(pet, owner, Some((3, Coords { x: 9, y: 6 })), 10),
+            PetAction::MoveTowards(Coords { x: 9, y: 6 })
+        );
+        assert_eq!(
+            get_pet_action(pet, owner, Some((3, Coords { x: 30, y: 6 })), 10),
+            PetAction::Idle
+        );
+
+        assert_eq!(
+            get_facing_direction(pet, Coords { x: 5, y: 5 }),
+            Direction::Up
+        );
+        assert_eq!(
+            get_facing_direction(pet, Coords { x: 6, y: 6 }),
+            Direction::Right
+        );
+    }
+}
@@ -1,19 +1,27 @@
 mod abandon_arena;
+mod act_npc_abilities;
+mod act_npc_patrol;
 mod act_npcs;
+mod act_pets;
 mod recover_npcs;
 mod recover_players;
+mod set_clock;
+mod set_weather;
 mod spawn_items;
 mod spawn_npcs;
 mod timed_arena;
 mod timed_auto_pickup;
+mod timed_boss_fights;
 mod timed_cleanup;
 mod timed_door_close;
 mod timed_drain;
 mod timed_drop_protection;
 mod timed_evacuate;
 mod timed_ghost;
+mod timed_karma;
 mod timed_quake;
 mod timed_spikes;
 mod timed_usage;
 mod timed_warp_suck;
+mod timed_weather;
 mod timed_wedding;
@@ -1,3 +1,5 @@
+use std::cmp;
+
 use super::super::Map;
 
 impl Map {
@@ -9,6 +11,11 @@ impl Map {
                     npc.hp = npc.max_hp;
                 }
             }
+
+            let max_tp = npc.get_max_tp();
+            if npc.alive && npc.tp < max_tp {
+                npc.tp = cmp::min(npc.tp + (max_tp / 10) + 1, max_tp);
+            }
         }
     }
 }
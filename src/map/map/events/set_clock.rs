@@ -0,0 +1,68 @@
+use eolib::protocol::net::{PacketAction, PacketFamily, server::MusicPlayerServerPacket};
+
+use crate::{
+    SETTINGS,
+    clock::{TimeOfDay, get_time_of_day},
+    deep::{ClockPingServerPacket, FAMILY_CLOCK},
+};
+
+use super::super::Map;
+
+impl Map {
+    pub fn set_clock(&mut self, hour: u32, minute: u32) {
+        let previous_hour = self.hour;
+        self.hour = hour;
+        self.minute = minute;
+
+        if hour == previous_hour {
+            return;
+        }
+
+        // Quest rules like IsNight can be met just by the time passing
+        for character in self.characters.values_mut() {
+            character.hour = hour;
+            character.check_quest_rules();
+        }
+
+        let packet = self.get_clock_ping();
+        for player in self
+            .characters
+            .values()
+            .filter(|character| character.is_deep)
+            .filter_map(|character| character.player.as_ref())
+        {
+            player.send(
+                PacketAction::Ping,
+                PacketFamily::Unrecognized(FAMILY_CLOCK),
+                &packet,
+            );
+        }
+
+        let time_of_day = get_time_of_day(hour);
+        if time_of_day == get_time_of_day(previous_hour) {
+            return;
+        }
+
+        let sound_id = match time_of_day {
+            TimeOfDay::Day => SETTINGS.clock.day_sfx_id,
+            TimeOfDay::Night => SETTINGS.clock.night_sfx_id,
+        };
+
+        if sound_id > 0 {
+            self.send_packet_all(
+                PacketAction::Player,
+                PacketFamily::Music,
+                MusicPlayerServerPacket { sound_id },
+            );
+        }
+    }
+
+    pub fn get_clock_ping(&self) -> ClockPingServerPacket {
+        ClockPingServerPacket {
+            hour: self.hour as i32,
+            minute: self.minute as i32,
+            night: get_time_of_day(self.hour) == TimeOfDay::Night,
+            day_length: SETTINGS.clock.day_length,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use eolib::protocol::net::{
+    PacketAction, PacketFamily,
+    server::{MusicPlayerServerPacket, TalkServerServerPacket},
+};
+
+use crate::WEATHERS;
+
+use super::super::Map;
+
+impl Map {
+    pub fn set_weather(&mut self, weather: Option<String>) {
+        if weather == self.weather {
+            return;
+        }
+
+        self.weather = weather;
+        self.weather_ticks = 0;
+
+        let weather = match self.weather.as_ref().and_then(|name| WEATHERS.get(name)) {
+            Some(weather) => weather,
+            None => return,
+        };
+
+        if !weather.message.is_empty() {
+            self.send_packet_all(
+                PacketAction::Server,
+                PacketFamily::Talk,
+                TalkServerServerPacket {
+                    message: weather.message.to_owned(),
+                },
+            );
+        }
+
+        if weather.sfx_id > 0 {
+            self.send_packet_all(
+                PacketAction::Player,
+                PacketFamily::Music,
+                MusicPlayerServerPacket {
+                    sound_id: weather.sfx_id,
+                },
+            );
+        }
+    }
+}
@@ -11,57 +11,28 @@ impl Map {
     pub fn spawn_npcs(&mut self) {
         self.npcs.retain(|n| n.spawn_index.is_some() || n.alive);
 
-        if self.file.npcs.is_empty() {
+        if self.spawns.is_empty() {
             return;
         }
 
-        if self.npcs.is_empty() {
-            let mut npc_index: i32 = 0;
-
-            for (spawn_index, spawn) in self.file.npcs.iter().enumerate() {
-                let data_record = match NPC_DB.npcs.get(spawn.id as usize - 1) {
-                    Some(npc) => npc,
-                    None => {
-                        error!(
-                            "Failed to load NPC {} (Map: {}, Coords: {:?})",
-                            spawn.id, self.id, spawn.coords,
-                        );
-                        continue;
-                    }
-                };
-
-                for _ in 0..spawn.amount as i64 {
-                    self.npcs.push(
-                        NPCBuilder::new()
-                            .id(spawn.id)
-                            .index(npc_index)
-                            .coords(Coords::default())
-                            .direction(Direction::Down)
-                            .spawn_index(spawn_index)
-                            .spawn_type(spawn.spawn_type)
-                            .spawn_time(spawn.spawn_time)
-                            .alive(false)
-                            .spawn_ticks(if SETTINGS.npcs.instant_spawn {
-                                0
-                            } else {
-                                spawn.spawn_time
-                            })
-                            .hp(data_record.hp)
-                            .max_hp(data_record.hp)
-                            .boss(data_record.boss)
-                            .child(data_record.child)
-                            .build(),
-                    );
-                    npc_index += 1;
-                }
-            }
+        if !self.npcs.iter().any(|npc| npc.spawn_index.is_some()) {
+            self.create_spawn_npcs(0);
         }
 
         let mut rng = rand::rng();
         let indexes = self.npcs.iter().map(|npc| npc.index).collect::<Vec<i32>>();
 
         for index in indexes {
-            let (child, alive, spawn_ticks, spawn_coords, spawn_type, npc_type, spawn_time) = {
+            let (
+                child,
+                alive,
+                spawn_ticks,
+                spawn_coords,
+                spawn_radius,
+                spawn_type,
+                npc_type,
+                spawn_time,
+            ) = {
                 match self.npcs.iter_mut().find(|npc| npc.index == index) {
                     Some(npc) => {
                         let spawn_index = match npc.spawn_index {
@@ -69,9 +40,23 @@ impl Map {
                             None => continue,
                         };
 
+                        let spawn = &self.spawns[spawn_index];
+
+                        // Scheduled and event spawns wait for their window to open
+                        if !spawn.is_active(self.hour, &self.events) {
+                            if npc.alive {
+                                npc.alive = false;
+                                npc.opponents.clear();
+                                npc.boss_fight = None;
+                                npc.spawn_ticks = 0;
+                                let coords = npc.coords;
+                                self.send_npc_despawn(index, &coords);
+                            }
+                            continue;
+                        }
+
                         npc.spawn_ticks = cmp::max(npc.spawn_ticks - 1, 0);
 
-                        let spawn = &self.file.npcs[spawn_index];
                         let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
                             Some(npc_data) => npc_data,
                             None => continue,
@@ -81,6 +66,7 @@ impl Map {
                             npc.alive,
                             npc.spawn_ticks,
                             spawn.coords,
+                            spawn.get_spawn_radius().max(0),
                             spawn.spawn_type,
                             npc_data.r#type,
                             spawn.spawn_time,
@@ -110,11 +96,17 @@ impl Map {
             } else {
                 Coords {
                     x: cmp::max(
-                        cmp::min(spawn_coords.x + rng.random_range(-2..=2), self.file.width),
+                        cmp::min(
+                            spawn_coords.x + rng.random_range(-spawn_radius..=spawn_radius),
+                            self.file.width,
+                        ),
                         0,
                     ) as i32,
                     y: cmp::max(
-                        cmp::min(spawn_coords.y + rng.random_range(-2..=2), self.file.height),
+                        cmp::min(
+                            spawn_coords.y + rng.random_range(-spawn_radius..=spawn_radius),
+                            self.file.height,
+                        ),
                         0,
                     ) as i32,
                 }
@@ -126,14 +118,14 @@ impl Map {
             {
                 let x = cmp::max(
                     cmp::min(
-                        file_spawn_coords.x + rng.random_range(-2..=2),
+                        file_spawn_coords.x + rng.random_range(-spawn_radius..=spawn_radius),
                         self.file.width,
                     ),
                     0,
                 );
                 let y = cmp::max(
                     cmp::min(
-                        file_spawn_coords.y + rng.random_range(-2..=2),
+                        file_spawn_coords.y + rng.random_range(-spawn_radius..=spawn_radius),
                         self.file.height,
                     ),
                     0,
@@ -157,6 +149,10 @@ impl Map {
 
             npc.alive = true;
             npc.hp = npc.max_hp;
+            npc.tp = npc.get_max_tp();
+            npc.ability_cooldowns.clear();
+            npc.boss_fight = None;
+            npc.patrol_index = 0;
             npc.coords = spawn_coords;
             npc.direction = if spawn_type == 7 {
                 Direction::from(spawn_time & 0x03)
@@ -171,4 +167,55 @@ impl Map {
             };
         }
     }
+
+    /// Creates the npcs of every spawn from `from_spawn_index` onwards, waiting to spawn
+    pub fn create_spawn_npcs(&mut self, from_spawn_index: usize) {
+        for (spawn_index, spawn) in self.spawns.iter().enumerate().skip(from_spawn_index) {
+            let data_record = match NPC_DB.npcs.get(spawn.id as usize - 1) {
+                Some(npc) => npc,
+                None => {
+                    error!(
+                        "Failed to load NPC {} (Map: {}, Coords: {:?})",
+                        spawn.id, self.id, spawn.coords,
+                    );
+                    continue;
+                }
+            };
+
+            for _ in 0..spawn.amount as i64 {
+                let npc_index = match self.get_next_npc_index() {
+                    Some(index) => index,
+                    None => {
+                        error!(
+                            "Failed to create spawn npcs on map {}. Index exceeds 252",
+                            self.id
+                        );
+                        return;
+                    }
+                };
+
+                self.npcs.push(
+                    NPCBuilder::new()
+                        .id(spawn.id)
+                        .index(npc_index)
+                        .coords(Coords::default())
+                        .direction(Direction::Down)
+                        .spawn_index(spawn_index)
+                        .spawn_type(spawn.spawn_type)
+                        .spawn_time(spawn.spawn_time)
+                        .alive(false)
+                        .spawn_ticks(if SETTINGS.npcs.instant_spawn {
+                            0
+                        } else {
+                            spawn.spawn_time
+                        })
+                        .hp(data_record.hp)
+                        .max_hp(data_record.hp)
+                        .boss(data_record.boss)
+                        .child(data_record.child)
+                        .build(),
+                );
+            }
+        }
+    }
 }
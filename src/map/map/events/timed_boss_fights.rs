@@ -0,0 +1,181 @@
+use std::cmp;
+
+use eolib::protocol::{
+    Coords,
+    net::{
+        PacketAction, PacketFamily,
+        server::{EffectAdminServerPacket, RecoverPlayerServerPacket},
+    },
+};
+
+use crate::{BOSSES, bosses::BossPhase, utils::get_distance};
+
+use super::super::Map;
+
+impl Map {
+    pub fn timed_boss_fights(&mut self) {
+        let npc_indexes = self
+            .npcs
+            .iter()
+            .filter(|npc| npc.boss_fight.is_some())
+            .map(|npc| npc.index)
+            .collect::<Vec<_>>();
+
+        for npc_index in npc_indexes {
+            self.timed_boss_fight(npc_index);
+        }
+    }
+
+    fn timed_boss_fight(&mut self, npc_index: i32) {
+        let npc = match self.npcs.iter_mut().find(|npc| npc.index == npc_index) {
+            Some(npc) => npc,
+            None => return,
+        };
+
+        // Everyone fighting the boss died, left or lost interest
+        if !npc.alive || npc.opponents.is_empty() {
+            self.end_boss_fight(npc_index);
+            return;
+        }
+
+        let boss = match BOSSES.get(npc.id) {
+            Some(boss) => boss,
+            None => return,
+        };
+
+        let npc_coords = npc.coords;
+
+        let fight = match npc.boss_fight.as_mut() {
+            Some(fight) => fight,
+            None => return,
+        };
+
+        fight.seconds += 1;
+        fight.phase_seconds += 1;
+
+        let enraged =
+            boss.enrage_seconds > 0 && !fight.enraged && fight.seconds >= boss.enrage_seconds;
+
+        if enraged {
+            fight.enraged = true;
+        }
+
+        let phase_seconds = fight.phase_seconds;
+        let phase = fight.phase.and_then(|index| boss.phases.get(index));
+
+        if enraged && !boss.enrage_message.is_empty() {
+            self.npc_chat(npc_index, &boss.enrage_message);
+        }
+
+        let phase = match phase {
+            Some(phase) => phase,
+            None => return,
+        };
+
+        if phase.add_wave_seconds > 0 && phase_seconds % phase.add_wave_seconds == 0 {
+            self.spawn_boss_adds(npc_index, &phase.adds);
+        }
+
+        if phase.mechanic_seconds > 0 && phase_seconds % phase.mechanic_seconds == 0 {
+            self.boss_mechanic(npc_index, npc_coords, phase);
+        }
+    }
+
+    fn boss_mechanic(&mut self, npc_index: i32, npc_coords: Coords, phase: &BossPhase) {
+        if phase.quake > 0 {
+            self.quake(phase.quake);
+        }
+
+        if phase.effect_id == 0 {
+            return;
+        }
+
+        let radius = phase.effect_radius;
+        let tiles = (-radius..=radius)
+            .flat_map(|y| {
+                (-radius..=radius).map(move |x| Coords {
+                    x: npc_coords.x + x,
+                    y: npc_coords.y + y,
+                })
+            })
+            .filter(|coords| {
+                coords.x >= 0
+                    && coords.y >= 0
+                    && self.is_in_bounds(*coords)
+                    && get_distance(coords, &npc_coords) <= radius
+            })
+            .collect::<Vec<_>>();
+
+        self.effect_on_coords(&tiles, phase.effect_id);
+
+        if phase.effect_damage <= 0 {
+            return;
+        }
+
+        let victim_ids = self
+            .characters
+            .iter()
+            .filter(|(_, character)| {
+                !character.hidden
+                    && character.hp > 0
+                    && get_distance(&character.coords, &npc_coords) <= radius
+            })
+            .map(|(player_id, _)| *player_id)
+            .collect::<Vec<_>>();
+
+        for victim_id in victim_ids {
+            self.boss_mechanic_damage(npc_index, victim_id, phase.effect_damage);
+        }
+    }
+
+    fn boss_mechanic_damage(&mut self, npc_index: i32, victim_id: i32, damage: i32) {
+        let victim = match self.characters.get_mut(&victim_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        let damage = cmp::min(damage, victim.hp);
+        victim.hp -= damage;
+
+        let victim = match self.characters.get(&victim_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        let killed = victim.hp == 0;
+
+        self.send_packet_near(
+            &victim.coords,
+            PacketAction::Admin,
+            PacketFamily::Effect,
+            EffectAdminServerPacket {
+                player_id: victim_id,
+                hp_percentage: victim.get_hp_percentage(),
+                died: killed,
+                damage,
+            },
+        );
+
+        if let Some(player) = victim.player.as_ref() {
+            player.send(
+                PacketAction::Player,
+                PacketFamily::Recover,
+                &RecoverPlayerServerPacket {
+                    hp: victim.hp,
+                    tp: victim.tp,
+                },
+            );
+
+            player.update_party_hp(victim.get_hp_percentage());
+
+            if killed {
+                player.die();
+            }
+        }
+
+        if killed && let Some(npc) = self.npcs.iter_mut().find(|npc| npc.index == npc_index) {
+            npc.opponents
+                .retain(|opponent| opponent.player_id != victim_id);
+        }
+    }
+}
@@ -19,15 +19,16 @@ use super::super::Map;
 impl Map {
     pub fn timed_drain(&mut self) {
         if self.file.timed_effect == MapTimedEffect::HpDrain {
-            self.timed_drain_hp();
+            self.drain_hp(SETTINGS.world.drain_hp_damage);
         }
 
         if self.file.timed_effect == MapTimedEffect::TpDrain {
-            self.timed_drain_tp();
+            self.drain_tp(SETTINGS.world.drain_tp_damage);
         }
     }
 
-    fn timed_drain_hp(&mut self) {
+    /// Drains a fraction of every visible character's max HP, never below 1 HP
+    pub(super) fn drain_hp(&mut self, fraction: f32) {
         let player_ids: Vec<i32> = self.characters.keys().copied().collect();
         let mut damage_list: Vec<i32> = Vec::with_capacity(player_ids.len());
 
@@ -45,7 +46,7 @@ impl Map {
                 continue;
             }
 
-            let damage = (character.max_hp as f32 * SETTINGS.world.drain_hp_damage).floor() as i32;
+            let damage = (character.max_hp as f32 * fraction).floor() as i32;
             let damage = cmp::min(damage, character.hp - 1);
             let damage = cmp::max(damage, 0);
 
@@ -119,13 +120,13 @@ impl Map {
         }
     }
 
-    fn timed_drain_tp(&mut self) {
+    pub(super) fn drain_tp(&mut self, fraction: f32) {
         for character in self.characters.values_mut() {
             if character.tp == 0 || character.hidden {
                 continue;
             }
 
-            let damage = (character.max_tp as f32 * SETTINGS.world.drain_tp_damage).floor() as i32;
+            let damage = (character.max_tp as f32 * fraction).floor() as i32;
             let damage = cmp::min(damage, character.tp - 1);
             let damage = cmp::max(damage, 0);
 
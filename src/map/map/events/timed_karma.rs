@@ -0,0 +1,41 @@
+use std::cmp;
+
+use crate::SETTINGS;
+
+use super::super::Map;
+
+impl Map {
+    pub fn timed_karma(&mut self) {
+        let mut recovering_player_ids: Vec<i32> = Vec::new();
+
+        for (player_id, character) in self.characters.iter_mut() {
+            character.pk_ticks = cmp::max(0, character.pk_ticks - 1);
+
+            if SETTINGS.karma.drift_seconds == 0
+                || character.pk_ticks > 0
+                || character.karma >= SETTINGS.karma.neutral
+            {
+                character.karma_ticks = 0;
+                continue;
+            }
+
+            character.karma_ticks += 1;
+            if character.karma_ticks >= SETTINGS.karma.drift_seconds {
+                character.karma_ticks = 0;
+                recovering_player_ids.push(*player_id);
+            }
+        }
+
+        for player_id in recovering_player_ids {
+            let karma = match self.characters.get(&player_id) {
+                Some(character) => character.karma,
+                None => continue,
+            };
+
+            self.give_karma(
+                player_id,
+                cmp::min(SETTINGS.karma.drift_amount, SETTINGS.karma.neutral - karma),
+            );
+        }
+    }
+}
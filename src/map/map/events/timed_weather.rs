@@ -0,0 +1,63 @@
+use rand::RngExt;
+
+use crate::WEATHERS;
+
+use super::super::Map;
+
+impl Map {
+    pub fn timed_weather(&mut self) {
+        let weather = match self.weather.as_ref().and_then(|name| WEATHERS.get(name)) {
+            Some(weather) => weather,
+            None => return,
+        };
+
+        if weather.effect_seconds <= 0 {
+            return;
+        }
+
+        self.weather_ticks += 1;
+        if self.weather_ticks < weather.effect_seconds {
+            return;
+        }
+
+        self.weather_ticks = 0;
+
+        if weather.effect_id > 0 {
+            let player_ids = self
+                .characters
+                .iter()
+                .filter(|(_, character)| !character.hidden)
+                .map(|(player_id, _)| *player_id)
+                .collect::<Vec<_>>();
+
+            self.effect_on_players(&player_ids, weather.effect_id);
+        }
+
+        if weather.hp_drain > 0.0 {
+            self.drain_hp(weather.hp_drain);
+        }
+
+        if weather.tp_drain > 0.0 {
+            self.drain_tp(weather.tp_drain);
+        }
+    }
+
+    /// Rolls whether the current weather blocks a character's step
+    pub fn weather_blocks_step(&self, player_id: i32) -> bool {
+        let weather = match self.weather.as_ref().and_then(|name| WEATHERS.get(name)) {
+            Some(weather) => weather,
+            None => return false,
+        };
+
+        if weather.stumble_chance <= 0 {
+            return false;
+        }
+
+        match self.characters.get(&player_id) {
+            Some(character) if i32::from(character.admin_level) < 1 => {
+                rand::rng().random_range(0..100) < weather.stumble_chance
+            }
+            _ => false,
+        }
+    }
+}
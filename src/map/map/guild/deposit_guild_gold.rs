@@ -5,7 +5,9 @@ use eolib::protocol::{
     r#pub::NpcType,
 };
 
-use crate::{NPC_DB, SETTINGS, db::insert_params};
+use serde_json::json;
+
+use crate::{NPC_DB, SETTINGS, db::insert_params, player::Action};
 
 use super::super::Map;
 
@@ -38,6 +40,15 @@ impl Map {
         character.remove_item(1, amount);
 
         if let Some(player) = character.player.as_ref() {
+            player.record_action(
+                Action::GuildGoldDeposited,
+                json!({
+                    "guild": tag,
+                    "amount": amount,
+                })
+                .to_string(),
+            );
+
             player.send(
                 PacketAction::Buy,
                 PacketFamily::Guild,
@@ -9,7 +9,9 @@ use eolib::protocol::{
     },
 };
 
-use crate::SETTINGS;
+use serde_json::json;
+
+use crate::{SETTINGS, player::Action};
 
 use super::super::Map;
 
@@ -83,6 +85,15 @@ impl Map {
         character.add_bank_item(item.id, amount);
 
         if let Some(player) = character.player.as_ref() {
+            player.record_action(
+                Action::LockerItemDeposited,
+                json!({
+                    "item_id": item.id,
+                    "amount": amount,
+                })
+                .to_string(),
+            );
+
             player.send(
                 PacketAction::Reply,
                 PacketFamily::Locker,
@@ -6,6 +6,10 @@ use eolib::protocol::{
     net::{PacketAction, PacketFamily, ThreeItem, server::LockerGetServerPacket},
 };
 
+use serde_json::json;
+
+use crate::player::Action;
+
 use super::super::Map;
 
 impl Map {
@@ -60,6 +64,15 @@ impl Map {
         character.add_item(item_id, amount);
 
         if let Some(player) = character.player.as_ref() {
+            player.record_action(
+                Action::LockerItemWithdrawn,
+                json!({
+                    "item_id": item_id,
+                    "amount": amount,
+                })
+                .to_string(),
+            );
+
             player.send(
                 PacketAction::Get,
                 PacketFamily::Locker,
@@ -0,0 +1,13 @@
+use super::super::Map;
+
+impl Map {
+    pub fn despawn_pet(&mut self, player_id: i32) {
+        let (index, coords) = match self.npcs.iter().find(|npc| npc.owner == Some(player_id)) {
+            Some(npc) => (npc.index, npc.coords),
+            None => return,
+        };
+
+        self.npcs.retain(|npc| npc.index != index);
+        self.send_npc_despawn(index, &coords);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{LANG, NPC_DB, PETS};
+
+use super::super::Map;
+
+impl Map {
+    pub fn give_pet_experience(&mut self, player_id: i32, experience: i32) {
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        let pet = match character.pets.iter_mut().find(|pet| pet.summoned) {
+            Some(pet) => pet,
+            None => return,
+        };
+
+        let pet_data = match PETS.get(pet.npc_id) {
+            Some(pet_data) => pet_data,
+            None => return,
+        };
+
+        let gained = experience * pet_data.experience_share / 100;
+        if gained <= 0 {
+            return;
+        }
+
+        let level = pet_data.get_level(pet.experience);
+        let max_experience = (pet_data.max_level - 1).max(0) * pet_data.experience_per_level;
+        pet.experience = (pet.experience + gained).min(max_experience);
+
+        let new_level = pet_data.get_level(pet.experience);
+        if new_level <= level {
+            return;
+        }
+
+        let name = match NPC_DB.npcs.get(pet.npc_id as usize - 1) {
+            Some(npc_data) => npc_data.name.to_owned(),
+            None => return,
+        };
+
+        if let Some(player) = character.player.as_ref() {
+            player.send_server_message(&get_lang_string!(
+                &LANG.pet_level_up,
+                pet = name,
+                level = new_level
+            ));
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod despawn_pet;
+mod give_pet_experience;
+mod spawn_pet;
+mod summon_pet;
@@ -0,0 +1,62 @@
+use eolib::protocol::net::{PacketAction, PacketFamily, server::NpcAgreeServerPacket};
+
+use crate::{NPC_DB, PETS, map::Npc};
+
+use super::super::Map;
+
+impl Map {
+    pub fn spawn_pet(&mut self, player_id: i32) {
+        if self.npcs.iter().any(|npc| npc.owner == Some(player_id)) {
+            return;
+        }
+
+        let (npc_id, coords, direction) = match self.characters.get(&player_id) {
+            Some(character) => match character.pets.iter().find(|pet| pet.summoned) {
+                Some(pet) => (pet.npc_id, character.coords, character.direction),
+                None => return,
+            },
+            None => return,
+        };
+
+        let pet = match PETS.get(npc_id) {
+            Some(pet) => pet,
+            None => return,
+        };
+
+        let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+            Some(npc_data) => npc_data,
+            None => return,
+        };
+
+        let index = match self.get_next_npc_index() {
+            Some(index) => index,
+            None => {
+                warn!("Failed to spawn pet on map {}. Index exceeds 252", self.id);
+                return;
+            }
+        };
+
+        let mut npc = Npc {
+            index,
+            id: npc_id,
+            coords,
+            direction,
+            spawn_type: pet.speed,
+            spawn_index: None,
+            alive: true,
+            hp: npc_data.hp,
+            max_hp: npc_data.hp,
+            owner: Some(player_id),
+            ..Default::default()
+        };
+        npc.tp = npc.get_max_tp();
+
+        let packet = NpcAgreeServerPacket {
+            npcs: vec![npc.to_map_info()],
+        };
+
+        self.npcs.push(npc);
+
+        self.send_packet_near(&coords, PacketAction::Agree, PacketFamily::Npc, packet);
+    }
+}
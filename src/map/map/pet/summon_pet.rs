@@ -0,0 +1,44 @@
+use crate::character::CharacterPet;
+
+use super::super::Map;
+
+impl Map {
+    /// Summons the pet or dismisses it if it's already following the character
+    pub fn summon_pet(&mut self, player_id: i32, npc_id: i32) {
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        let dismiss = character
+            .pets
+            .iter()
+            .any(|pet| pet.npc_id == npc_id && pet.summoned);
+
+        for pet in character.pets.iter_mut() {
+            pet.summoned = false;
+        }
+
+        self.despawn_pet(player_id);
+
+        if dismiss {
+            return;
+        }
+
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        match character.pets.iter_mut().find(|pet| pet.npc_id == npc_id) {
+            Some(pet) => pet.summoned = true,
+            None => character.pets.push(CharacterPet {
+                npc_id,
+                experience: 0,
+                summoned: true,
+            }),
+        }
+
+        self.spawn_pet(player_id);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::world::Party;
+
+use super::super::Map;
+
+impl Map {
+    pub fn credit_party_quest_item(
+        &mut self,
+        player_id: i32,
+        party: &Party,
+        item_id: i32,
+        amount: i32,
+    ) {
+        for member_id in self.get_quest_party_member_ids(player_id, party) {
+            if let Some(character) = self.characters.get_mut(&member_id) {
+                character.add_shared_quest_item(item_id, amount);
+            }
+        }
+    }
+}
@@ -1,5 +1,9 @@
 mod award_experience;
+mod credit_party_quest_item;
+mod remap_quest_states;
 mod reply_to_quest_npc;
+mod share_quest_item;
 mod talk_to_quest_npc;
+mod update_quest;
 mod view_quest_history;
 mod view_quest_progress;
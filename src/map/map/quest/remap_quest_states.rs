@@ -0,0 +1,12 @@
+use super::super::Map;
+
+impl Map {
+    /// Re-resolves quest progress after a reload, returning messages for
+    /// progress that had to be reset or cleared
+    pub fn remap_quest_states(&mut self) -> Vec<String> {
+        self.characters
+            .values_mut()
+            .flat_map(|character| character.remap_quests())
+            .collect()
+    }
+}
@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, sync::Arc};
 
 use eolib::protocol::{
     net::{
@@ -48,7 +48,8 @@ impl Map {
 
         character.talked_to_npc(npc_data.behavior_id, quest_id, action_id);
 
-        let quests_for_npc = QUEST_DB
+        let quest_db = QUEST_DB.all();
+        let quests_for_npc = quest_db
             .iter()
             .filter(|(id, quest)| {
                 let progress = character.get_quest_progress(**id);
@@ -60,7 +61,7 @@ impl Map {
                             && action.args[0] == Arg::Int(npc_data.behavior_id)
                     })
             })
-            .collect::<Vec<(&i32, &Quest)>>();
+            .collect::<Vec<(&i32, &Arc<Quest>)>>();
 
         if quests_for_npc.is_empty() {
             return;
@@ -0,0 +1,46 @@
+use super::super::Map;
+
+impl Map {
+    /// Looks up the looter's party off the map actor, then credits party members
+    /// who still need the item for a party shared GotItems objective
+    pub fn share_quest_item(&self, player_id: i32, item_id: i32, amount: i32) {
+        // Avoid asking the world for the party on every pickup
+        if !self.characters.iter().any(|(id, character)| {
+            *id != player_id && character.get_shared_quest_item_need(item_id) > 0
+        }) {
+            return;
+        }
+
+        let player = match self
+            .characters
+            .get(&player_id)
+            .and_then(|character| character.player.as_ref())
+        {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let world = self.world.clone();
+
+        tokio::spawn(async move {
+            let party = match world.get_player_party(player_id).await {
+                Ok(Some(party)) => party,
+                Ok(None) => return,
+                Err(e) => {
+                    error!("Failed to get player party: {}", e);
+                    return;
+                }
+            };
+
+            let map = match player.get_map().await {
+                Ok(map) => map,
+                Err(e) => {
+                    error!("Failed to get map: {}", e);
+                    return;
+                }
+            };
+
+            map.credit_party_quest_item(player_id, party, item_id, amount);
+        });
+    }
+}
@@ -0,0 +1,85 @@
+use chrono::Utc;
+
+use crate::{
+    QUEST_DB, character::QuestProgress, map::QuestUpdate, player::PlayerHandle, utils::capitalize,
+};
+
+use super::super::Map;
+
+impl Map {
+    pub fn update_quest(
+        &mut self,
+        player_id: i32,
+        quest_id: i32,
+        update: QuestUpdate,
+        admin: PlayerHandle,
+    ) {
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        let quest = match QUEST_DB.get(&quest_id) {
+            Some(quest) => quest,
+            None => {
+                admin.send_server_message(&format!("Quest {} does not exist", quest_id));
+                return;
+            }
+        };
+
+        let name = capitalize(&character.name);
+        let message = match update {
+            QuestUpdate::SetState(state_name) => {
+                let state = match quest
+                    .states
+                    .iter()
+                    .position(|state| state.name.eq_ignore_ascii_case(&state_name))
+                {
+                    Some(state) => state,
+                    None => {
+                        admin.send_server_message(&format!(
+                            "{} has no state {}",
+                            quest.name, state_name
+                        ));
+                        return;
+                    }
+                };
+
+                character.set_quest_state(quest_id, state as i32);
+                format!(
+                    "{} is now on state {} of {}",
+                    name, quest.states[state].name, quest.name
+                )
+            }
+            QuestUpdate::Reset => {
+                if !character.quests.iter().any(|q| q.id == quest_id) {
+                    admin.send_server_message(&format!("{} has not started {}", name, quest.name));
+                    return;
+                }
+
+                character.quests.retain(|q| q.id != quest_id);
+                format!("{} has been reset for {}", quest.name, name)
+            }
+            QuestUpdate::Complete => {
+                if !character.quests.iter().any(|q| q.id == quest_id) {
+                    character.quests.push(QuestProgress {
+                        id: quest_id,
+                        ..Default::default()
+                    });
+                }
+
+                let progress = character
+                    .quests
+                    .iter_mut()
+                    .find(|q| q.id == quest_id)
+                    .unwrap();
+                progress.done_at = Some(Utc::now().naive_utc());
+                progress.completions += 1;
+                format!("{} has been completed for {}", quest.name, name)
+            }
+        };
+
+        // Persisted by the character's next save like any other quest progress
+        admin.send_server_message(&message);
+    }
+}
@@ -25,10 +25,12 @@ impl Map {
             .quests
             .iter()
             .filter_map(|q| {
+                let quest = QUEST_DB.get(&q.id)?;
                 if q.done_at.is_some() && q.state != 0 {
-                    QUEST_DB.get(&q.id).map(|quest| quest.name.to_owned())
+                    Some(quest.name.to_owned())
                 } else {
-                    None
+                    q.get_repeat_status(&quest)
+                        .map(|status| format!("{} ({})", quest.name, status))
                 }
             })
             .collect::<Vec<_>>();
@@ -41,6 +41,16 @@ impl Map {
                     None => return None,
                 };
 
+                if let Some(status) = q.get_repeat_status(&quest) {
+                    return Some(QuestProgressEntry {
+                        name: quest.name.to_owned(),
+                        description: status,
+                        icon: QuestRequirementIcon::Talk,
+                        progress: 0,
+                        target: 0,
+                    });
+                }
+
                 if let Some(rule) = state.rules.iter().find(|rule| rule.name == "GotItems") {
                     return Some(QuestProgressEntry {
                         name: quest.name.to_owned(),
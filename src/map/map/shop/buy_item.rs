@@ -5,7 +5,9 @@ use eolib::protocol::{
     r#pub::NpcType,
 };
 
-use crate::{NPC_DB, SETTINGS, SHOP_DB};
+use serde_json::json;
+
+use crate::{NPC_DB, SETTINGS, SHOP_DB, player::Action};
 
 use super::super::Map;
 
@@ -60,7 +62,7 @@ impl Map {
 
         let amount = cmp::min(amount, trade.max_amount);
 
-        let price = trade.buy_price * amount;
+        let price = character.get_shop_buy_price(trade.buy_price) * amount;
 
         if character.get_item_amount(1) < price {
             return;
@@ -70,6 +72,17 @@ impl Map {
         character.add_item(item.id, amount);
 
         if let Some(player) = character.player.as_ref() {
+            player.record_action(
+                Action::ShopItemBought,
+                json!({
+                    "npc_id": npc.id,
+                    "item_id": item.id,
+                    "amount": amount,
+                    "price": price,
+                })
+                .to_string(),
+            );
+
             player.send(
                 PacketAction::Buy,
                 PacketFamily::Shop,
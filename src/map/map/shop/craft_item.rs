@@ -3,7 +3,9 @@ use eolib::protocol::{
     r#pub::NpcType,
 };
 
-use crate::{NPC_DB, SHOP_DB};
+use serde_json::json;
+
+use crate::{NPC_DB, SHOP_DB, player::Action};
 
 use super::super::Map;
 
@@ -59,6 +61,24 @@ impl Map {
         character.add_item(item_id, 1);
 
         if let Some(player) = character.player.as_ref() {
+            player.record_action(
+                Action::ShopItemCrafted,
+                json!({
+                    "npc_id": npc.id,
+                    "item_id": item_id,
+                    "ingredients": craft
+                        .ingredients
+                        .iter()
+                        .filter(|ingredient| ingredient.item_id > 0)
+                        .map(|ingredient| json!({
+                            "id": ingredient.item_id,
+                            "amount": ingredient.amount,
+                        }))
+                        .collect::<Vec<_>>(),
+                })
+                .to_string(),
+            );
+
             player.send(
                 PacketAction::Create,
                 PacketFamily::Shop,
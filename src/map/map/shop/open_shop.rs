@@ -60,8 +60,9 @@ impl Map {
                     .iter()
                     .map(|trade| ShopTradeItem {
                         item_id: trade.item_id,
-                        buy_price: trade.buy_price,
-                        sell_price: trade.sell_price,
+                        buy_price: character.get_shop_buy_price(trade.buy_price),
+                        sell_price: character
+                            .get_shop_sell_price(trade.sell_price, trade.buy_price),
                         max_buy_amount: trade.max_amount,
                     })
                     .collect(),
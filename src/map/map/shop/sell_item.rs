@@ -8,7 +8,9 @@ use eolib::protocol::{
     r#pub::NpcType,
 };
 
-use crate::{NPC_DB, SETTINGS, SHOP_DB};
+use serde_json::json;
+
+use crate::{NPC_DB, SETTINGS, SHOP_DB, player::Action};
 
 use super::super::Map;
 
@@ -63,12 +65,26 @@ impl Map {
 
         let amount = cmp::min(amount, trade.max_amount);
 
-        let price = cmp::min(trade.sell_price * amount, SETTINGS.limits.max_item);
+        let price = cmp::min(
+            character.get_shop_sell_price(trade.sell_price, trade.buy_price) * amount,
+            SETTINGS.limits.max_item,
+        );
 
         character.remove_item(item.id, amount);
         character.add_item(1, price);
 
         if let Some(player) = character.player.as_ref() {
+            player.record_action(
+                Action::ShopItemSold,
+                json!({
+                    "npc_id": npc.id,
+                    "item_id": item.id,
+                    "amount": amount,
+                    "price": price,
+                })
+                .to_string(),
+            );
+
             player.send(
                 PacketAction::Sell,
                 PacketFamily::Shop,
@@ -45,5 +45,8 @@ impl Map {
                 &StatSkillRemoveServerPacket { spell_id: skill_id },
             );
         }
+
+        character.lost_spell(skill_id);
+        character.check_quest_rules();
     }
 }
@@ -103,5 +103,7 @@ impl Map {
                 },
             );
         }
+
+        character.check_quest_rules();
     }
 }
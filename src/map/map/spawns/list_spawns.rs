@@ -0,0 +1,65 @@
+use crate::NPC_DB;
+
+use super::super::Map;
+
+impl Map {
+    pub fn list_spawns(&mut self, player_id: i32) {
+        let file_spawns = self.file.npcs.len();
+
+        let mut lines = vec![format!(
+            "{} map file spawns, {} overlay spawns",
+            file_spawns,
+            self.spawns.len() - file_spawns
+        )];
+
+        for (spawn_index, spawn) in self.spawns.iter().enumerate().skip(file_spawns) {
+            let name = match NPC_DB.npcs.get(spawn.id as usize - 1) {
+                Some(npc_data) => npc_data.name.as_str(),
+                None => "Unknown npc",
+            };
+
+            let alive = self
+                .npcs
+                .iter()
+                .filter(|npc| npc.alive && npc.spawn_index == Some(spawn_index))
+                .count();
+
+            let mut line = format!(
+                "{} {}/{} at {},{}",
+                name, alive, spawn.amount, spawn.coords.x, spawn.coords.y
+            );
+
+            if !spawn.patrol.is_empty() {
+                line.push_str(&format!(", {} waypoints", spawn.patrol.len()));
+            }
+
+            if spawn.start_hour.is_some() || spawn.end_hour.is_some() {
+                line.push_str(&format!(
+                    ", {}h-{}h",
+                    spawn.start_hour.unwrap_or(0),
+                    spawn.end_hour.unwrap_or(24)
+                ));
+            }
+
+            if let Some(time_of_day) = spawn.time_of_day {
+                line.push_str(&format!(", {:?} only", time_of_day).to_lowercase());
+            }
+
+            if let Some(event) = spawn.event.as_ref() {
+                line.push_str(&format!(", event {}", event));
+            }
+
+            if !spawn.is_active(self.hour, &self.events) {
+                line.push_str(" (inactive)");
+            }
+
+            lines.push(line);
+        }
+
+        self.show_info_box(
+            player_id,
+            "Spawns:",
+            lines.iter().map(|line| line.as_str()).collect(),
+        );
+    }
+}
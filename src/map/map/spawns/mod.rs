@@ -0,0 +1,2 @@
+mod list_spawns;
+mod reload_spawns;
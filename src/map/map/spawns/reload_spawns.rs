@@ -0,0 +1,51 @@
+use crate::spawns::SpawnOverlay;
+
+use super::super::Map;
+
+impl Map {
+    pub fn reload_spawns(&mut self, player_id: i32) {
+        let player = match self.characters.get(&player_id) {
+            Some(character) => match character.player.as_ref() {
+                Some(player) => player.to_owned(),
+                None => return,
+            },
+            None => return,
+        };
+
+        let overlay = match SpawnOverlay::load(self.id) {
+            Ok(overlay) => overlay,
+            Err(e) => {
+                player.send_server_message(&format!("Failed to load spawn overlay: {}", e));
+                return;
+            }
+        };
+
+        let file_spawns = self.file.npcs.len();
+        let is_overlay_npc =
+            |spawn_index: Option<usize>| spawn_index.is_some_and(|i| i >= file_spawns);
+
+        let despawned = self
+            .npcs
+            .iter()
+            .filter(|npc| npc.alive && is_overlay_npc(npc.spawn_index))
+            .map(|npc| (npc.index, npc.coords))
+            .collect::<Vec<_>>();
+
+        self.npcs.retain(|npc| !is_overlay_npc(npc.spawn_index));
+
+        for (index, coords) in despawned {
+            self.send_npc_despawn(index, &coords);
+        }
+
+        let spawns = overlay.spawns.len();
+        self.spawns.truncate(file_spawns);
+        self.spawns.extend(overlay.spawns);
+
+        // Otherwise every spawn's npcs are created on the next spawn tick
+        if self.npcs.iter().any(|npc| npc.spawn_index.is_some()) {
+            self.create_spawn_npcs(file_spawns);
+        }
+
+        player.send_server_message(&format!("Reloaded {} overlay spawns", spawns));
+    }
+}
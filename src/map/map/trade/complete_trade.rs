@@ -8,7 +8,9 @@ use eolib::protocol::{
     },
 };
 
-use crate::SETTINGS;
+use serde_json::json;
+
+use crate::{SETTINGS, player::Action};
 
 use super::super::Map;
 
@@ -85,6 +87,17 @@ impl Map {
         };
 
         if let Some(player) = character.player.as_ref() {
+            player.record_action(
+                Action::TradeCompleted,
+                json!({
+                    "partner_id": partner_character.id,
+                    "partner": partner_character.name,
+                    "gave": trade_items,
+                    "received": partner_trade_items,
+                })
+                .to_string(),
+            );
+
             player.set_trading(false);
             player.set_trade_accepted(false);
 
@@ -107,6 +120,17 @@ impl Map {
         }
 
         if let Some(partner) = partner_character.player.as_ref() {
+            partner.record_action(
+                Action::TradeCompleted,
+                json!({
+                    "partner_id": character.id,
+                    "partner": character.name,
+                    "gave": partner_trade_items,
+                    "received": trade_items,
+                })
+                .to_string(),
+            );
+
             partner.set_trading(false);
             partner.set_trade_accepted(false);
 
@@ -58,6 +58,8 @@ impl Map {
             );
         }
 
+        self.update_boss_fight(player_id, npc_index, damage_dealt);
+
         let npc = match self.npcs.iter().find(|npc| npc.index == npc_index) {
             Some(npc) => npc,
             None => return,
@@ -176,9 +178,9 @@ impl Map {
         damage_dealt: i32,
         spell_id: Option<i32>,
     ) {
-        let (npc_id, npc_coords, is_boss) =
+        let (npc_id, npc_coords, is_boss, spawn_index) =
             match self.npcs.iter().find(|npc| npc.index == npc_index) {
-                Some(npc) => (npc.id, npc.coords, npc.boss),
+                Some(npc) => (npc.id, npc.coords, npc.boss, npc.spawn_index),
                 None => return,
             };
 
@@ -187,6 +189,17 @@ impl Map {
             None => return,
         };
 
+        if let Some(spawn_index) = spawn_index {
+            let spawn_ticks = self.get_respawn_ticks(spawn_index);
+            if let Some(npc) = self.npcs.iter_mut().find(|npc| npc.index == npc_index) {
+                npc.spawn_ticks = spawn_ticks;
+            }
+        }
+
+        // Camping only cuts the killer's own share, the rest of the party is unaffected
+        let reward = self.record_spawn_kill(killer_player_id, npc_index);
+        let killer_experience = npc_data.experience * reward / 100;
+
         let mut exp_gains: Vec<ExpGain> = Vec::new();
 
         let party = self
@@ -226,6 +239,12 @@ impl Map {
             };
 
             for member_id in members_on_map {
+                let experience = if *member_id == killer_player_id {
+                    experience * reward / 100
+                } else {
+                    experience
+                };
+
                 let (leveled_up, level, total_experience, experience_gained) =
                     self.give_experience(*member_id, experience);
                 exp_gains.push(ExpGain {
@@ -238,7 +257,7 @@ impl Map {
             }
         } else {
             let (leveled_up, level, total_experience, experience_gained) =
-                self.give_experience(killer_player_id, npc_data.experience);
+                self.give_experience(killer_player_id, killer_experience);
             exp_gains.push(ExpGain {
                 player_id: killer_player_id,
                 leveled_up,
@@ -248,7 +267,9 @@ impl Map {
             });
         }
 
-        let drop = get_drop(killer_player_id, npc_id, &npc_coords);
+        self.give_pet_experience(killer_player_id, killer_experience);
+
+        let drop = get_drop(killer_player_id, npc_id, &npc_coords, self.hour, reward);
 
         let (drop_index, drop_item_id, drop_amount) = match drop {
             Some(drop) => {
@@ -447,6 +468,8 @@ impl Map {
             }
         }
 
+        self.finish_boss_fight(killer_player_id, npc_index, damage_dealt);
+
         if is_boss {
             self.npcs
                 .iter_mut()
@@ -503,9 +526,15 @@ impl Map {
             }
         }
 
-        for gain in &exp_gains {
-            if let Some(character) = self.characters.get_mut(&gain.player_id) {
-                character.killed_npc(npc_id);
+        if let Some(character) = self.characters.get_mut(&killer_player_id) {
+            character.killed_npc(npc_id, false);
+        }
+
+        if let Some(party) = party.as_ref() {
+            for member_id in self.get_quest_party_member_ids(killer_player_id, party) {
+                if let Some(character) = self.characters.get_mut(&member_id) {
+                    character.killed_npc(npc_id, true);
+                }
             }
         }
     }
@@ -559,8 +588,16 @@ impl Map {
     }
 }
 
-fn get_drop(target_player_id: i32, npc_id: i32, npc_coords: &Coords) -> Option<Item> {
+/// `reward` is the percent of the normal drop rates the kill is worth
+fn get_drop(
+    target_player_id: i32,
+    npc_id: i32,
+    npc_coords: &Coords,
+    hour: u32,
+    reward: i32,
+) -> Option<Item> {
     let mut drops = GLOBAL_DROPS.drops.iter().collect::<Vec<_>>();
+    drops.extend(GLOBAL_DROPS.get_timed(npc_id, hour));
     if let Some(drop_npc) = DROP_DB.npcs.iter().find(|d| d.npc_id == npc_id) {
         drops.extend(drop_npc.drops.iter());
     }
@@ -571,7 +608,7 @@ fn get_drop(target_player_id: i32, npc_id: i32, npc_coords: &Coords) -> Option<I
 
         for drop in drops {
             let roll = rng.random_range(0..=64000);
-            if roll <= drop.rate {
+            if roll <= drop.rate * reward / 100 {
                 let amount = rng.random_range(drop.min_amount..=drop.max_amount);
                 if amount > 0 {
                     return Some(Item {
@@ -0,0 +1,15 @@
+use eolib::protocol::map::Emf;
+
+use crate::spawns::{Spawn, SpawnOverlay};
+
+/// Map file spawns followed by the ones from the map's spawn overlay
+pub fn create_spawns(map_id: i32, file: &Emf) -> Vec<Spawn> {
+    let mut spawns: Vec<Spawn> = file.npcs.iter().map(Spawn::from).collect();
+
+    match SpawnOverlay::load(map_id) {
+        Ok(overlay) => spawns.extend(overlay.spawns),
+        Err(e) => error!("Failed to load spawn overlay for map {}: {}", map_id, e),
+    }
+
+    spawns
+}
@@ -0,0 +1,46 @@
+use eolib::protocol::net::{PacketAction, PacketFamily, server::NpcJunkServerPacket};
+
+use crate::map::BossFight;
+
+use super::super::Map;
+
+impl Map {
+    /// Clears the boss's fight and despawns any adds it summoned that are still alive
+    pub fn end_boss_fight(&mut self, npc_index: i32) -> Option<BossFight> {
+        let fight = self
+            .npcs
+            .iter_mut()
+            .find(|npc| npc.index == npc_index)?
+            .boss_fight
+            .take()?;
+
+        let mut add_ids: Vec<i32> = Vec::new();
+
+        for npc in self.npcs.iter_mut().filter(|npc| {
+            npc.alive && npc.boss_index == Some(npc_index) && fight.add_indexes.contains(&npc.index)
+        }) {
+            npc.alive = false;
+            npc.hp = 0;
+            npc.opponents.clear();
+
+            if !add_ids.contains(&npc.id) {
+                add_ids.push(npc.id);
+            }
+        }
+
+        // Junk removes every npc with the id so it's skipped while others are still up
+        for npc_id in add_ids {
+            if self.npcs.iter().any(|npc| npc.alive && npc.id == npc_id) {
+                continue;
+            }
+
+            self.send_packet_all(
+                PacketAction::Junk,
+                PacketFamily::Npc,
+                NpcJunkServerPacket { npc_id },
+            );
+        }
+
+        Some(fight)
+    }
+}
@@ -0,0 +1,153 @@
+use eolib::protocol::net::{PacketAction, PacketFamily, server::ItemAddServerPacket};
+use rand::RngExt;
+
+use crate::{
+    BOSSES, LANG, NPC_DB, SETTINGS,
+    db::{DbHandle, insert_params},
+    map::{BossFight, BossFightParticipant},
+    utils::format_countdown,
+};
+
+use super::super::Map;
+
+impl Map {
+    pub fn finish_boss_fight(&mut self, killer_player_id: i32, npc_index: i32, damage_dealt: i32) {
+        let (npc_id, npc_coords) = match self.npcs.iter().find(|npc| npc.index == npc_index) {
+            Some(npc) => (npc.id, npc.coords),
+            None => return,
+        };
+
+        let boss = match BOSSES.get(npc_id) {
+            Some(boss) => boss,
+            None => return,
+        };
+
+        let (killer_character_id, killer_name) = match self.characters.get(&killer_player_id) {
+            Some(character) => (character.id, character.name.clone()),
+            None => return,
+        };
+
+        let mut fight = self.end_boss_fight(npc_index).unwrap_or_default();
+
+        match fight
+            .participants
+            .iter_mut()
+            .find(|participant| participant.player_id == killer_player_id)
+        {
+            Some(participant) => participant.damage_dealt += damage_dealt,
+            None => fight.participants.push(BossFightParticipant {
+                player_id: killer_player_id,
+                character_id: killer_character_id,
+                damage_dealt,
+            }),
+        }
+
+        // Each phase the fight reached rolls its own drop table
+        let phases_reached = fight.phase.map_or(0, |phase| phase + 1);
+        let mut rng = rand::rng();
+        for phase in boss.phases.iter().take(phases_reached) {
+            let mut drops = phase.drops.iter().collect::<Vec<_>>();
+            drops.sort_by_key(|drop| drop.rate);
+
+            let drop = drops.into_iter().find_map(|drop| {
+                if rng.random_range(0..=64000) > drop.rate {
+                    return None;
+                }
+
+                let amount = rng.random_range(drop.min_amount..=drop.max_amount);
+                if amount > 0 {
+                    Some((drop.item_id, amount))
+                } else {
+                    None
+                }
+            });
+
+            let (item_id, amount) = match drop {
+                Some(drop) => drop,
+                None => continue,
+            };
+
+            let item_index = match self.add_item(
+                item_id,
+                amount,
+                npc_coords,
+                killer_player_id,
+                SETTINGS.world.drop_protect_npc,
+            ) {
+                Ok(index) => index,
+                Err(e) => {
+                    error!("Failed to add boss drop to map: {}", e);
+                    continue;
+                }
+            };
+
+            self.send_packet_near(
+                &npc_coords,
+                PacketAction::Add,
+                PacketFamily::Item,
+                ItemAddServerPacket {
+                    item_id,
+                    item_index,
+                    item_amount: amount,
+                    coords: npc_coords,
+                },
+            );
+        }
+
+        if let Some(npc_data) = NPC_DB.npcs.get(npc_id as usize - 1) {
+            self.world.broadcast_server_message(get_lang_string!(
+                &LANG.boss_killed,
+                npc = npc_data.name,
+                name = killer_name,
+                time = format_countdown(fight.seconds)
+            ));
+        }
+
+        let db = self.db.clone();
+        let map_id = self.id;
+        tokio::spawn(async move {
+            if let Err(e) = record_boss_kill(&db, npc_id, map_id, killer_character_id, &fight).await
+            {
+                error!("Failed to record boss kill: {}", e);
+            }
+        });
+    }
+}
+
+async fn record_boss_kill(
+    db: &DbHandle,
+    npc_id: i32,
+    map_id: i32,
+    killer_character_id: i32,
+    fight: &BossFight,
+) -> anyhow::Result<()> {
+    db.execute(&insert_params(
+        include_str!("../../../sql/create_boss_kill.sql"),
+        &[
+            ("npc_id", &npc_id),
+            ("map", &map_id),
+            ("killer_character_id", &killer_character_id),
+            ("duration", &fight.seconds),
+        ],
+    ))
+    .await?;
+
+    let boss_kill_id = match db.get_last_insert_id().await {
+        Some(id) => id as i32,
+        None => return Err(anyhow::anyhow!("Failed to get boss kill id")),
+    };
+
+    for participant in &fight.participants {
+        db.execute(&insert_params(
+            include_str!("../../../sql/create_boss_kill_participant.sql"),
+            &[
+                ("boss_kill_id", &boss_kill_id),
+                ("character_id", &participant.character_id),
+                ("damage_dealt", &participant.damage_dealt),
+            ],
+        ))
+        .await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use crate::map::ItemTotals;
+
+use super::super::Map;
+
+impl Map {
+    pub fn get_item_totals(&self) -> ItemTotals {
+        let mut totals = ItemTotals::default();
+
+        for item in &self.items {
+            *totals.items.entry(item.id).or_default() += item.amount as i64;
+        }
+
+        for chest in &self.chests {
+            for item in &chest.items {
+                *totals.items.entry(item.item_id).or_default() += item.amount as i64;
+            }
+
+            totals
+                .chest_spawns
+                .extend(chest.spawns.iter().map(|spawn| spawn.item_id));
+        }
+
+        totals
+    }
+}
@@ -0,0 +1,16 @@
+use eolib::data::CHAR_MAX;
+
+use super::super::Map;
+
+impl Map {
+    pub fn get_next_npc_index(&self) -> Option<i32> {
+        let index = self
+            .npcs
+            .iter()
+            .map(|npc| npc.index)
+            .max()
+            .map_or(0, |index| index + 1);
+
+        if index >= CHAR_MAX { None } else { Some(index) }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{SETTINGS, utils::get_distance, world::Party};
+
+use super::super::Map;
+
+impl Map {
+    /// Party members on the map, other than the player, close enough to share quest progress
+    pub fn get_quest_party_member_ids(&self, player_id: i32, party: &Party) -> Vec<i32> {
+        let coords = match self.characters.get(&player_id) {
+            Some(character) => character.coords,
+            None => return Vec::new(),
+        };
+
+        party
+            .members
+            .iter()
+            .filter(|id| **id != player_id)
+            .filter(|id| match self.characters.get(id) {
+                Some(character) => {
+                    SETTINGS.quests.party_share_range == 0
+                        || get_distance(&coords, &character.coords)
+                            <= SETTINGS.quests.party_share_range
+                }
+                None => false,
+            })
+            .copied()
+            .collect()
+    }
+}
@@ -0,0 +1,36 @@
+use std::cmp;
+
+use rand::RngExt;
+
+use crate::SETTINGS;
+
+use super::super::Map;
+
+impl Map {
+    /// Respawn time of a spawn sped up by the map's population, with rare
+    /// spawns randomly shifted so they can't be timed
+    pub fn get_respawn_ticks(&self, spawn_index: usize) -> i32 {
+        let spawn = match self.spawns.get(spawn_index) {
+            Some(spawn) => spawn,
+            None => return 0,
+        };
+
+        let population = cmp::min(
+            self.characters.len() as i32,
+            SETTINGS.npcs.respawn_population_cap,
+        );
+
+        let speedup =
+            100 + SETTINGS.npcs.respawn_speedup_per_player.max(0) * (population - 1).max(0);
+        let ticks = spawn.spawn_time * 100 / speedup;
+
+        let variance = spawn.get_respawn_variance();
+        let ticks = if variance > 0 {
+            ticks + rand::rng().random_range(-variance..=variance)
+        } else {
+            ticks
+        };
+
+        cmp::max(ticks, 0)
+    }
+}
@@ -8,6 +8,9 @@ impl Map {
             Some(character) => {
                 let experience = experience * SETTINGS.world.exp_multiplier;
                 let leveled_up = character.add_experience(experience);
+                if leveled_up {
+                    character.check_quest_rules();
+                }
                 (
                     leveled_up,
                     character.level,
@@ -0,0 +1,14 @@
+use eolib::protocol::Coords;
+
+use crate::utils::get_tiles_between;
+
+use super::super::Map;
+
+impl Map {
+    /// True if nothing a player couldn't walk through stands between two tiles
+    pub fn has_line_of_sight(&self, from: &Coords, to: &Coords) -> bool {
+        get_tiles_between(from, to)
+            .iter()
+            .all(|coords| self.is_tile_walkable(coords))
+    }
+}
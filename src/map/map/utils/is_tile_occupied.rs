@@ -3,6 +3,7 @@ use eolib::protocol::Coords;
 use super::super::Map;
 
 impl Map {
+    /// Pets never block a tile so they can't wall in players or npcs
     pub fn is_tile_occupied(&self, coords: &Coords) -> bool {
         self.characters
             .values()
@@ -10,6 +11,6 @@ impl Map {
             || self
                 .npcs
                 .iter()
-                .any(|npc| npc.alive && npc.coords == *coords)
+                .any(|npc| npc.alive && npc.owner.is_none() && npc.coords == *coords)
     }
 }
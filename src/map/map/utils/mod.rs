@@ -1,32 +1,46 @@
 mod attack_npc_replies;
 mod create_chests;
+mod end_boss_fight;
+mod finish_boss_fight;
 mod spawn_item_at_feet;
 pub use create_chests::create_chests;
+mod create_spawns;
+pub use create_spawns::create_spawns;
 mod add_item;
 mod get_adjacent_tiles;
 mod get_character;
+mod get_item_totals;
 mod get_nearby_info;
+mod get_next_npc_index;
+mod get_quest_party_member_ids;
+mod get_respawn_ticks;
 mod get_rid_and_size;
 mod get_tile;
 mod get_warp;
 mod give_experience;
 mod give_item;
+mod has_line_of_sight;
 mod is_in_bounds;
 mod is_tile_occupied;
 mod is_tile_walkable;
 mod is_tile_walkable_npc;
 mod npc_chat;
+mod npc_hunts_player;
 mod player_chat;
 mod player_in_range_of_tile;
+mod record_spawn_kill;
 mod reload;
 mod remove_item;
 mod save;
+mod send_npc_despawn;
 mod send_packet_all;
 mod send_packet_near;
 mod send_packet_near_exclude_player;
 mod send_packet_near_player;
 mod serialize;
 mod show_info_box;
+mod spawn_boss_adds;
 mod spawn_npc;
 mod spike_damage;
 mod toggle_hidden;
+mod update_boss_fight;
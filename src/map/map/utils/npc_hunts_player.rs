@@ -0,0 +1,47 @@
+use eolib::protocol::{map::MapType, r#pub::NpcType};
+
+use crate::{SETTINGS, character::Character};
+
+use super::super::Map;
+
+impl Map {
+    /// Whether the npc goes after players on sight rather than waiting to be attacked
+    pub fn npc_hunts(&self, npc_id: i32, npc_type: NpcType) -> bool {
+        if npc_type == NpcType::Aggressive {
+            return true;
+        }
+
+        if SETTINGS.guards.npcs.contains(&npc_id) {
+            return self.is_town();
+        }
+
+        npc_type == NpcType::Passive && SETTINGS.karma.tiers.iter().any(|tier| tier.aggressive_npcs)
+    }
+
+    pub fn npc_hunts_player(&self, npc_id: i32, npc_type: NpcType, character: &Character) -> bool {
+        if character.hidden || character.captcha_open {
+            return false;
+        }
+
+        if npc_type == NpcType::Aggressive {
+            return true;
+        }
+
+        if SETTINGS.guards.npcs.contains(&npc_id) {
+            return self.is_town() && character.is_wanted();
+        }
+
+        npc_type == NpcType::Passive
+            && character
+                .get_karma_tier()
+                .is_some_and(|tier| tier.aggressive_npcs)
+    }
+
+    fn is_town(&self) -> bool {
+        if SETTINGS.guards.town_maps.is_empty() {
+            self.file.r#type != MapType::Pk
+        } else {
+            SETTINGS.guards.town_maps.contains(&self.id)
+        }
+    }
+}
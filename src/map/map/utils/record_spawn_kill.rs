@@ -0,0 +1,43 @@
+use chrono::Utc;
+
+use crate::{SETTINGS, character::get_camping_reward};
+
+use super::super::Map;
+
+impl Map {
+    /// Counts a kill of a spawn npc towards the killer's camping, returning
+    /// the percent of the normal EXP and drop rates the kill is worth to them
+    pub fn record_spawn_kill(&mut self, player_id: i32, npc_index: i32) -> i32 {
+        let policy = &SETTINGS.npcs;
+        if policy.camping_window <= 0 {
+            return 100;
+        }
+
+        if !self
+            .npcs
+            .iter()
+            .any(|npc| npc.index == npc_index && npc.spawn_index.is_some())
+        {
+            return 100;
+        }
+
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return 100,
+        };
+
+        let kills = character.camping.add_kill(
+            self.id,
+            npc_index,
+            Utc::now(),
+            policy.camping_window as i64,
+        );
+
+        get_camping_reward(
+            kills,
+            policy.camping_free_kills,
+            policy.camping_penalty,
+            policy.camping_min_reward,
+        )
+    }
+}
@@ -15,7 +15,7 @@ use eolib::{
 
 use crate::map::Door;
 
-use super::{super::Map, create_chests};
+use super::{super::Map, create_chests, create_spawns};
 
 impl Map {
     pub fn reload(&mut self, file: Box<Emf>, file_size: i32) {
@@ -51,6 +51,7 @@ impl Map {
         }
 
         self.chests = create_chests(self.id, &file);
+        self.spawns = create_spawns(self.id, &file);
         self.arena_ticks = 0;
         self.arena_players.clear();
         self.quake_ticks = 0;
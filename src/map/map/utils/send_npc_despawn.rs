@@ -0,0 +1,33 @@
+use eolib::protocol::{
+    Coords, Direction,
+    net::{
+        PacketAction, PacketFamily,
+        server::{NpcKilledData, NpcSpecServerPacket},
+    },
+};
+
+use super::super::Map;
+
+impl Map {
+    /// Removes an npc from nearby clients without anyone having killed it
+    pub fn send_npc_despawn(&self, npc_index: i32, coords: &Coords) {
+        self.send_packet_near(
+            coords,
+            PacketAction::Spec,
+            PacketFamily::Npc,
+            NpcSpecServerPacket {
+                npc_killed_data: NpcKilledData {
+                    killer_id: 0,
+                    killer_direction: Direction::Down,
+                    npc_index,
+                    drop_index: 0,
+                    drop_id: 0,
+                    drop_coords: *coords,
+                    drop_amount: 0,
+                    damage: 0,
+                },
+                experience: None,
+            },
+        );
+    }
+}
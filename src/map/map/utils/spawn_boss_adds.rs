@@ -0,0 +1,64 @@
+use eolib::protocol::Direction;
+
+use crate::{NPC_DB, bosses::BossAdd, map::Npc};
+
+use super::super::Map;
+
+impl Map {
+    pub fn spawn_boss_adds(&mut self, npc_index: i32, adds: &[BossAdd]) {
+        let coords = match self.npcs.iter().find(|npc| npc.index == npc_index) {
+            Some(npc) => npc.coords,
+            None => return,
+        };
+
+        let mut add_indexes = Vec::new();
+
+        for add in adds {
+            let npc_data = match NPC_DB.npcs.get(add.npc_id as usize - 1) {
+                Some(npc_data) => npc_data,
+                None => continue,
+            };
+
+            for _ in 0..add.amount {
+                let index = match self.get_next_npc_index() {
+                    Some(index) => index,
+                    None => {
+                        warn!(
+                            "Failed to spawn boss add on map {}. Index exceeds 252",
+                            self.id
+                        );
+                        break;
+                    }
+                };
+
+                let mut npc = Npc {
+                    index,
+                    id: add.npc_id,
+                    coords,
+                    direction: Direction::Down,
+                    spawn_type: add.speed,
+                    spawn_index: None,
+                    alive: true,
+                    hp: npc_data.hp,
+                    max_hp: npc_data.hp,
+                    boss: npc_data.boss,
+                    child: npc_data.child,
+                    boss_index: Some(npc_index),
+                    ..Default::default()
+                };
+                npc.tp = npc.get_max_tp();
+                self.npcs.push(npc);
+                add_indexes.push(index);
+            }
+        }
+
+        if let Some(fight) = self
+            .npcs
+            .iter_mut()
+            .find(|npc| npc.index == npc_index)
+            .and_then(|npc| npc.boss_fight.as_mut())
+        {
+            fight.add_indexes.extend(add_indexes);
+        }
+    }
+}
@@ -24,7 +24,7 @@ impl Map {
                 }
                 break;
             }
-            self.npcs.push(Npc {
+            let mut npc = Npc {
                 index: max_index + i,
                 id: npc_id,
                 coords: character.coords,
@@ -37,7 +37,9 @@ impl Map {
                 boss: npc_data.boss,
                 child: npc_data.child,
                 ..Default::default()
-            });
+            };
+            npc.tp = npc.get_max_tp();
+            self.npcs.push(npc);
         }
     }
 }
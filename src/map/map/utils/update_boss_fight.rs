@@ -0,0 +1,60 @@
+use crate::{
+    BOSSES,
+    map::{BossFight, BossFightParticipant},
+};
+
+use super::super::Map;
+
+impl Map {
+    pub fn update_boss_fight(&mut self, player_id: i32, npc_index: i32, damage_dealt: i32) {
+        let character_id = match self.characters.get(&player_id) {
+            Some(character) => character.id,
+            None => return,
+        };
+
+        let npc = match self.npcs.iter_mut().find(|npc| npc.index == npc_index) {
+            Some(npc) => npc,
+            None => return,
+        };
+
+        let boss = match BOSSES.get(npc.id) {
+            Some(boss) => boss,
+            None => return,
+        };
+
+        let hp_percentage = npc.get_hp_percentage();
+        let fight = npc.boss_fight.get_or_insert_with(BossFight::default);
+
+        match fight
+            .participants
+            .iter_mut()
+            .find(|participant| participant.player_id == player_id)
+        {
+            Some(participant) => participant.damage_dealt += damage_dealt,
+            None => fight.participants.push(BossFightParticipant {
+                player_id,
+                character_id,
+                damage_dealt,
+            }),
+        }
+
+        let phase_index = boss.get_phase(hp_percentage);
+        if phase_index <= fight.phase {
+            return;
+        }
+
+        fight.phase = phase_index;
+        fight.phase_seconds = 0;
+
+        let phase = match phase_index.and_then(|index| boss.phases.get(index)) {
+            Some(phase) => phase,
+            None => return,
+        };
+
+        if !phase.message.is_empty() {
+            self.npc_chat(npc_index, &phase.message);
+        }
+
+        self.spawn_boss_adds(npc_index, &phase.adds);
+    }
+}
@@ -17,11 +17,11 @@ use tokio::time::timeout;
 
 use crate::{
     character::{Character, SpellTarget},
-    player::PartyRequest,
-    world::WorldHandle,
+    player::{PartyRequest, PlayerHandle},
+    world::{Party, WorldHandle},
 };
 
-use super::{Command, Map};
+use super::{Command, ItemTotals, Map, QuestUpdate};
 
 #[derive(Debug, Clone)]
 pub struct MapHandle {
@@ -142,6 +142,15 @@ impl MapHandle {
         });
     }
 
+    pub fn credit_party_quest_item(&self, player_id: i32, party: Party, item_id: i32, amount: i32) {
+        let _ = self.tx.send(Command::CreditPartyQuestItem {
+            player_id,
+            party,
+            item_id,
+            amount,
+        });
+    }
+
     pub fn create_board_post(&self, player_id: i32, board_id: i32, subject: String, body: String) {
         let _ = self.tx.send(Command::CreateBoardPost {
             player_id,
@@ -581,6 +590,15 @@ impl MapHandle {
         });
     }
 
+    pub async fn remap_quest_states(&self) -> Result<Vec<String>, String> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::RemapQuestStates { respond_to: tx });
+        timeout(Duration::from_secs(1), rx)
+            .await
+            .map_err(|_| "Failed to remap quest states. Timeout".to_string())?
+            .map_err(|_| "Failed to remap quest states. Channel closed".to_string())
+    }
+
     pub fn remove_trade_item(&self, player_id: i32, partner_id: i32, item_id: i32) {
         let _ = self.tx.send(Command::RemoveTradeItem {
             player_id,
@@ -758,7 +776,9 @@ impl MapHandle {
         let _ = self.tx.send(Command::StartEvacuate);
     }
 
-    pub async fn save_async(&self) -> Result<(), String> {
+    /// Saves the map and its characters, returning the items left on the
+    /// ground and in chests at the moment of the save
+    pub async fn save_async(&self) -> Result<ItemTotals, String> {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::SaveAsync { respond_to: tx });
         timeout(Duration::from_secs(5), rx)
@@ -868,6 +888,10 @@ impl MapHandle {
         let _ = self.tx.send(Command::TimedDrain);
     }
 
+    pub fn timed_boss_fights(&self) {
+        let _ = self.tx.send(Command::TimedBossFights);
+    }
+
     pub fn timed_quake(&self) {
         let _ = self.tx.send(Command::TimedQuake);
     }
@@ -896,6 +920,10 @@ impl MapHandle {
         let _ = self.tx.send(Command::TimedGhost);
     }
 
+    pub fn timed_karma(&self) {
+        let _ = self.tx.send(Command::TimedKarma);
+    }
+
     pub fn timed_auto_pickup(&self) {
         let _ = self.tx.send(Command::TimedAutoPickup);
     }
@@ -908,6 +936,10 @@ impl MapHandle {
         let _ = self.tx.send(Command::TimedUsage);
     }
 
+    pub fn timed_weather(&self) {
+        let _ = self.tx.send(Command::TimedWeather);
+    }
+
     pub fn toggle_hidden(&self, player_id: i32) {
         let _ = self.tx.send(Command::ToggleHidden { player_id });
     }
@@ -932,6 +964,21 @@ impl MapHandle {
         });
     }
 
+    pub fn update_quest(
+        &self,
+        player_id: i32,
+        quest_id: i32,
+        update: QuestUpdate,
+        admin: PlayerHandle,
+    ) {
+        let _ = self.tx.send(Command::UpdateQuest {
+            player_id,
+            quest_id,
+            update,
+            admin,
+        });
+    }
+
     pub fn upgrade_locker(&self, player_id: i32, npc_index: i32) {
         let _ = self.tx.send(Command::UpgradeLocker {
             player_id,
@@ -1014,6 +1061,26 @@ impl MapHandle {
             .send(Command::RemoveAutoPickupItem { player_id, item_id });
     }
 
+    pub fn list_spawns(&self, player_id: i32) {
+        let _ = self.tx.send(Command::ListSpawns { player_id });
+    }
+
+    pub fn reload_spawns(&self, player_id: i32) {
+        let _ = self.tx.send(Command::ReloadSpawns { player_id });
+    }
+
+    pub fn set_events(&self, events: Vec<String>) {
+        let _ = self.tx.send(Command::SetEvents { events });
+    }
+
+    pub fn set_clock(&self, hour: u32, minute: u32) {
+        let _ = self.tx.send(Command::SetClock { hour, minute });
+    }
+
+    pub fn set_weather(&self, weather: Option<String>) {
+        let _ = self.tx.send(Command::SetWeather { weather });
+    }
+
     pub async fn load(&self) -> Result<(), String> {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::Load { respond_to: tx });
@@ -1,3 +1,5 @@
+mod boss_fight;
+pub use boss_fight::{BossFight, BossFightParticipant};
 mod chest;
 pub use chest::Chest;
 mod command;
@@ -6,11 +8,15 @@ mod door;
 pub use door::Door;
 mod item;
 pub use item::Item;
+mod item_totals;
+pub use item_totals::ItemTotals;
 #[allow(clippy::module_inception)]
 mod map;
 pub use map::Map;
 mod npc;
 pub use npc::{NPCBuilder, Npc};
+mod quest_update;
+pub use quest_update::QuestUpdate;
 mod map_handle;
 pub use map_handle::MapHandle;
 mod wedding;
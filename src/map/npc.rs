@@ -1,10 +1,12 @@
-use std::cmp;
+use std::{cmp, collections::HashMap};
 
 use eolib::protocol::{Coords, Direction, net::server::NpcMapInfo};
 use evalexpr::{DefaultNumericTypes, HashMapContext, context_map, eval_float_with_context};
 use rand::RngExt;
 
-use crate::{FORMULAS, NPC_DB};
+use crate::{BOSSES, FORMULAS, NPC_ABILITIES, NPC_DB};
+
+use super::BossFight;
 
 #[derive(Clone, Debug, Default)]
 pub struct Npc {
@@ -25,6 +27,16 @@ pub struct Npc {
     pub opponents: Vec<NpcOpponent>,
     pub boss: bool,
     pub child: bool,
+    pub tp: i32,
+    /// Ticks left before each of the npc's abilities can be used again
+    pub ability_cooldowns: HashMap<usize, i32>,
+    pub boss_fight: Option<BossFight>,
+    /// Index of the boss that summoned this npc as an add
+    pub boss_index: Option<i32>,
+    /// Player id of the character this npc is a pet of
+    pub owner: Option<i32>,
+    /// Next waypoint of the spawn's patrol route
+    pub patrol_index: usize,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -35,6 +47,21 @@ pub struct NpcOpponent {
 }
 
 impl Npc {
+    pub fn get_max_tp(&self) -> i32 {
+        NPC_ABILITIES
+            .get(self.id)
+            .map_or(0, |ability_set| ability_set.tp)
+    }
+
+    pub fn get_damage_multiplier(&self) -> f32 {
+        match self.boss_fight.as_ref() {
+            Some(fight) if fight.enraged => BOSSES
+                .get(self.id)
+                .map_or(1.0, |boss| boss.enrage_damage_multiplier),
+            _ => 1.0,
+        }
+    }
+
     pub fn get_hp_percentage(&self) -> i32 {
         let percent = (self.hp as f32 / self.max_hp as f32) * 100.0;
         percent.floor() as i32
@@ -233,6 +260,12 @@ impl NPCBuilder {
             opponents: Vec::new(),
             boss: self.boss,
             child: self.child,
+            tp: 0,
+            ability_cooldowns: HashMap::new(),
+            boss_fight: None,
+            boss_index: None,
+            owner: None,
+            patrol_index: 0,
         }
     }
 }
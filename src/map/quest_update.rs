@@ -0,0 +1,6 @@
+#[derive(Debug)]
+pub enum QuestUpdate {
+    SetState(String),
+    Reset,
+    Complete,
+}
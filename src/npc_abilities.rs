@@ -0,0 +1,74 @@
+use config::{Config, ConfigError, File};
+
+#[derive(Debug, Deserialize)]
+pub struct NpcAbility {
+    /// ESF spell to cast, or 0 for a ranged weapon attack
+    #[serde(default)]
+    pub spell_id: i32,
+    /// ESF spell whose graphic is shown on the target of a ranged attack
+    #[serde(default)]
+    pub projectile_id: i32,
+    pub range: i32,
+    /// Seconds before the ability can be used again
+    #[serde(default)]
+    pub cooldown: i32,
+    /// Chance (out of 100) to use the ability each time it's ready
+    #[serde(default = "default_chance")]
+    pub chance: i32,
+    /// Heals only target NPCs at or below this HP percentage and attacks are
+    /// only used while the caster is
+    #[serde(default = "default_hp_threshold")]
+    pub hp_threshold: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NpcAbilitySet {
+    pub npc_id: i32,
+    #[serde(default)]
+    pub tp: i32,
+    pub abilities: Vec<NpcAbility>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NpcAbilities {
+    pub npcs: Vec<NpcAbilitySet>,
+}
+
+impl NpcAbilities {
+    pub fn new() -> Result<Self, ConfigError> {
+        let s = Config::builder()
+            .add_source(File::with_name("config/NpcAbilities.ron").required(false))
+            .add_source(File::with_name("config/NpcAbilities.local.ron").required(false))
+            .set_default("npcs", Vec::<String>::new())?
+            .build()?;
+
+        let mut s: NpcAbilities = s.try_deserialize()?;
+
+        for npc in s.npcs.iter_mut() {
+            npc.abilities.retain(|ability| {
+                let valid = ability.spell_id >= 0 && ability.projectile_id >= 0;
+                if !valid {
+                    warn!(
+                        "Skipping ability of NPC {} with invalid spell id {}",
+                        npc.npc_id, ability.spell_id
+                    );
+                }
+                valid
+            });
+        }
+
+        Ok(s)
+    }
+
+    pub fn get(&self, npc_id: i32) -> Option<&NpcAbilitySet> {
+        self.npcs.iter().find(|npc| npc.npc_id == npc_id)
+    }
+}
+
+fn default_chance() -> i32 {
+    100
+}
+
+fn default_hp_threshold() -> i32 {
+    100
+}
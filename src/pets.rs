@@ -0,0 +1,130 @@
+use config::{Config, ConfigError, File};
+
+#[derive(Debug, Deserialize)]
+pub struct Pet {
+    pub npc_id: i32,
+    /// Item that summons and dismisses the pet without being used up, 0 for none
+    #[serde(default)]
+    pub item_id: i32,
+    /// Spell that summons and dismisses the pet, 0 for none
+    #[serde(default)]
+    pub spell_id: i32,
+    /// Movement speed, the same as a map spawn's spawn type (0-6)
+    #[serde(default = "default_speed")]
+    pub speed: i32,
+    #[serde(default = "default_max_level")]
+    pub max_level: i32,
+    #[serde(default = "default_experience_per_level")]
+    pub experience_per_level: i32,
+    /// Percentage of the experience from its owner's kills the pet gains
+    #[serde(default = "default_experience_share")]
+    pub experience_share: i32,
+    /// Extra damage dealt for each level past the first
+    #[serde(default = "default_damage_per_level")]
+    pub damage_per_level: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Pets {
+    pub pets: Vec<Pet>,
+}
+
+impl Pets {
+    pub fn new() -> Result<Self, ConfigError> {
+        let s = Config::builder()
+            .add_source(File::with_name("config/Pets.ron").required(false))
+            .add_source(File::with_name("config/Pets.local.ron").required(false))
+            .set_default("pets", Vec::<String>::new())?
+            .build()?;
+
+        let mut s: Pets = s.try_deserialize()?;
+
+        for pet in s.pets.iter_mut() {
+            pet.speed = pet.speed.clamp(0, 6);
+        }
+
+        Ok(s)
+    }
+
+    pub fn get(&self, npc_id: i32) -> Option<&Pet> {
+        self.pets.iter().find(|pet| pet.npc_id == npc_id)
+    }
+
+    pub fn get_by_item(&self, item_id: i32) -> Option<&Pet> {
+        self.pets.iter().find(|pet| pet.item_id == item_id)
+    }
+
+    pub fn get_by_spell(&self, spell_id: i32) -> Option<&Pet> {
+        self.pets.iter().find(|pet| pet.spell_id == spell_id)
+    }
+}
+
+impl Pet {
+    pub fn get_level(&self, experience: i32) -> i32 {
+        if self.experience_per_level <= 0 {
+            return 1;
+        }
+
+        (1 + experience / self.experience_per_level).clamp(1, self.max_level.max(1))
+    }
+
+    pub fn get_bonus_damage(&self, level: i32) -> i32 {
+        self.damage_per_level * (level - 1)
+    }
+}
+
+fn default_speed() -> i32 {
+    1
+}
+
+fn default_max_level() -> i32 {
+    10
+}
+
+fn default_experience_per_level() -> i32 {
+    1000
+}
+
+fn default_experience_share() -> i32 {
+    50
+}
+
+fn default_damage_per_level() -> i32 {
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pet;
+
+    fn pet() -> Pet {
+        Pet {
+            npc_id: 1,
+            item_id: 0,
+            spell_id: 0,
+            speed: 1,
+            max_level: 5,
+            experience_per_level: 100,
+            experience_share: 50,
+            damage_per_level: 2,
+        }
+    }
+
+    #[test]
+    fn pet_level_is_capped_at_max_level() {
+        let pet = pet();
+
+        assert_eq!(pet.get_level(0), 1);
+        assert_eq!(pet.get_level(99), 1);
+        assert_eq!(pet.get_level(250), 3);
+        assert_eq!(pet.get_level(10_000), 5);
+        assert_eq!(pet.get_bonus_damage(1), 0);
+        assert_eq!(pet.get_bonus_damage(3), 4);
+
+        let pet = Pet {
+            experience_per_level: 0,
+            ..pet
+        };
+        assert_eq!(pet.get_level(10_000), 1);
+    }
+}
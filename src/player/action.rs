@@ -2,4 +2,17 @@
 pub enum Action {
     AccountCreated = 1,
     AccountLoggedIn = 2,
+    TradeCompleted = 3,
+    ShopItemBought = 4,
+    ShopItemSold = 5,
+    ShopItemCrafted = 6,
+    BankGoldDeposited = 7,
+    BankGoldWithdrawn = 8,
+    LockerItemDeposited = 9,
+    LockerItemWithdrawn = 10,
+    ItemDropped = 11,
+    ItemPickedUp = 12,
+    ChestItemTaken = 13,
+    GuildGoldDeposited = 14,
+    CharacterDeleted = 15,
 }
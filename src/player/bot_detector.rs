@@ -0,0 +1,175 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Duration, Utc};
+use eolib::protocol::Direction;
+
+use crate::settings::BotDetection;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BotAction {
+    Walk(Direction),
+    Attack(Direction),
+    Pickup,
+}
+
+/// Builds up suspicion from a player's recent actions
+#[derive(Debug, Default)]
+pub struct BotDetector {
+    actions: VecDeque<BotAction>,
+    times: VecDeque<DateTime<Utc>>,
+    session_start: Option<DateTime<Utc>>,
+    last_action: Option<DateTime<Utc>>,
+    suspicion: i32,
+    reasons: Vec<&'static str>,
+    detection: Option<String>,
+}
+
+impl BotDetector {
+    pub fn record(&mut self, action: BotAction, now: DateTime<Utc>, settings: &BotDetection) {
+        if !settings.enabled {
+            return;
+        }
+
+        // A break ends the session and anything suspicious about it
+        match self.last_action {
+            Some(last_action)
+                if now - last_action < Duration::minutes(settings.session_break_minutes) => {}
+            _ => {
+                self.session_start = Some(now);
+                self.suspicion = 0;
+                self.reasons.clear();
+            }
+        }
+
+        self.last_action = Some(now);
+
+        let sample_size = settings.sample_size.max(4);
+
+        self.actions.push_back(action);
+        if self.actions.len() > sample_size {
+            self.actions.pop_front();
+        }
+
+        self.times.push_back(now);
+        if self.times.len() > sample_size + 1 {
+            self.times.pop_front();
+        }
+
+        if settings.max_timing_deviation > 0.0
+            && self.times.len() > sample_size
+            && is_periodic(&self.times, settings.max_timing_deviation)
+        {
+            self.flag("perfectly periodic timing", settings.periodic_timing);
+            self.times.clear();
+        }
+
+        if self.actions.len() == sample_size
+            && is_repetitive(&self.actions, settings.max_pattern_length)
+        {
+            self.flag("repetitive actions", settings.repetitive_pattern);
+            self.actions.clear();
+        }
+
+        if let Some(session_start) = self.session_start
+            && now - session_start >= Duration::minutes(settings.session_minutes)
+        {
+            self.flag("long farming session", settings.long_session);
+            self.session_start = Some(now);
+        }
+
+        if self.suspicion >= settings.threshold.max(1) {
+            self.detection = Some(self.reasons.join(", "));
+            self.suspicion = 0;
+            self.reasons.clear();
+        }
+    }
+
+    /// Reasons the player was flagged as a possible bot since the last call
+    pub fn take_detection(&mut self) -> Option<String> {
+        self.detection.take()
+    }
+
+    fn flag(&mut self, reason: &'static str, weight: i32) {
+        self.suspicion += weight;
+        if !self.reasons.contains(&reason) {
+            self.reasons.push(reason);
+        }
+    }
+}
+
+fn is_periodic(times: &VecDeque<DateTime<Utc>>, max_deviation: f64) -> bool {
+    let intervals = times
+        .iter()
+        .zip(times.iter().skip(1))
+        .map(|(a, b)| (*b - *a).num_milliseconds() as f64)
+        .collect::<Vec<_>>();
+
+    let mean = intervals.iter().sum::<f64>() / intervals.len() as f64;
+    let variance = intervals
+        .iter()
+        .map(|interval| (interval - mean).powi(2))
+        .sum::<f64>()
+        / intervals.len() as f64;
+
+    variance.sqrt() <= max_deviation
+}
+
+/// True if the actions are one short cycle repeated over and over. A single
+/// repeated action (holding down a key) is left to the other heuristics
+fn is_repetitive(actions: &VecDeque<BotAction>, max_length: usize) -> bool {
+    if actions.iter().all(|action| *action == actions[0]) {
+        return false;
+    }
+
+    (2..=max_length.min(actions.len() / 2))
+        .any(|length| (length..actions.len()).all(|i| actions[i] == actions[i - length]))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use chrono::{Duration, Utc};
+    use eolib::protocol::Direction;
+
+    use super::{BotAction, is_periodic, is_repetitive};
+
+    #[test]
+    fn detects_repeated_cycles() {
+        let cycle = [
+            BotAction::Attack(Direction::Left),
+            BotAction::Attack(Direction::Left),
+            BotAction::Pickup,
+            BotAction::Walk(Direction::Right),
+            BotAction::Walk(Direction::Left),
+        ];
+
+        let actions = cycle
+            .iter()
+            .cycle()
+            .take(40)
+            .copied()
+            .collect::<VecDeque<_>>();
+        assert!(is_repetitive(&actions, 8));
+        assert!(!is_repetitive(&actions, 4));
+
+        let held = vec![BotAction::Attack(Direction::Down); 40]
+            .into_iter()
+            .collect();
+        assert!(!is_repetitive(&held, 8));
+    }
+
+    #[test]
+    fn detects_periodic_timing() {
+        let start = Utc::now();
+        let steady = (0..20)
+            .map(|i| start + Duration::milliseconds(i * 500))
+            .collect::<VecDeque<_>>();
+        assert!(is_periodic(&steady, 2.0));
+
+        let human = (0..20)
+            .map(|i| start + Duration::milliseconds(i * 500 + (i * i * 37) % 120))
+            .collect::<VecDeque<_>>();
+        assert!(!is_periodic(&human, 2.0));
+    }
+}
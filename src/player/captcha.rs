@@ -1,6 +1,10 @@
+use chrono::{DateTime, Utc};
+
 #[derive(Debug, Default)]
 pub struct Captcha {
     pub challenge: String,
     pub reward: i32,
     pub attempts: i32,
+    /// Set for captchas opened by bot detection, which fail when time runs out
+    pub expires_at: Option<DateTime<Utc>>,
 }
@@ -11,7 +11,7 @@ use tokio::sync::oneshot;
 
 use crate::{character::Character, errors::InvalidStateError, map::MapHandle};
 
-use super::{ClientState, PartyRequest};
+use super::{Action, ClientState, PartyRequest};
 
 #[derive(Debug)]
 pub enum Command {
@@ -34,6 +34,9 @@ pub enum Command {
     GetMap {
         respond_to: oneshot::Sender<Result<MapHandle, InvalidStateError>>,
     },
+    GetIp {
+        respond_to: oneshot::Sender<String>,
+    },
     GetPlayerId {
         respond_to: oneshot::Sender<i32>,
     },
@@ -60,6 +63,10 @@ pub enum Command {
         animation: Option<WarpEffect>,
     },
     SendGuildReply(GuildReply),
+    RecordAction {
+        action: Action,
+        details: String,
+    },
     SendServerMessage(String),
     Send(PacketAction, PacketFamily, Bytes),
     SendAwait(PacketAction, PacketFamily, Bytes, oneshot::Sender<()>),
@@ -101,5 +101,7 @@ impl Player {
                 error!("Unhandled packet {:?}_{:?}", action, family);
             }
         }
+
+        self.check_bot_detection().await;
     }
 }
@@ -1,3 +1,5 @@
+mod bot_detector;
+pub use bot_detector::{BotAction, BotDetector};
 mod captcha;
 pub use captcha::Captcha;
 mod client_state;
@@ -15,7 +17,9 @@ pub use player::Player;
 mod player_handle;
 pub use player_handle::PlayerHandle;
 mod warp_session;
+mod web_stream;
 pub use warp_session::WarpSession;
+pub use web_stream::WebStream;
 mod party_request;
 pub use party_request::PartyRequest;
 mod action;
@@ -13,12 +13,12 @@ use tokio_tungstenite::{WebSocketStream, tungstenite::Message};
 
 use crate::PACKET_RATE_LIMITS;
 
-use super::PacketLog;
+use super::{PacketLog, WebStream};
 
 #[allow(clippy::large_enum_variant)]
 pub enum Socket {
     Standard(TcpStream),
-    Web(WebSocketStream<TcpStream>),
+    Web(WebSocketStream<WebStream>),
 }
 
 pub struct PacketBus {
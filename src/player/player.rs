@@ -8,7 +8,8 @@ use tokio::sync::mpsc::UnboundedReceiver;
 use crate::{character::Character, errors::InvalidStateError, map::MapHandle, world::WorldHandle};
 
 use super::{
-    Captcha, ClientState, Command, PartyRequest, Socket, WarpSession, packet_bus::PacketBus,
+    BotDetector, Captcha, ClientState, Command, PartyRequest, Socket, WarpSession,
+    packet_bus::PacketBus,
 };
 
 pub struct Player {
@@ -44,12 +45,14 @@ pub struct Player {
     version: Version,
     email_pin: Option<String>,
     captcha: Option<Captcha>,
+    bot_detector: BotDetector,
     timestamp: i32,
     spell_id: Option<i32>,
 }
 
 mod account;
 mod arena_die;
+mod bot_check;
 mod cancel_trade;
 mod close;
 mod die;
@@ -116,6 +119,7 @@ impl Player {
             version: Version::default(),
             email_pin: None,
             captcha: None,
+            bot_detector: BotDetector::default(),
             timestamp: 0,
             spell_id: None,
         }
@@ -153,6 +157,9 @@ impl Player {
                         .send(Err(InvalidStateError::new(ClientState::InGame, self.state)));
                 }
             }
+            Command::GetIp { respond_to } => {
+                let _ = respond_to.send(self.ip.to_owned());
+            }
             Command::GetPlayerId { respond_to } => {
                 let _ = respond_to.send(self.id);
             }
@@ -188,6 +195,9 @@ impl Player {
                     )
                     .await;
             }
+            Command::RecordAction { action, details } => {
+                self.record_action_with_details(action, &details)
+            }
             Command::SendServerMessage(message) => self.send_server_message(&message).await,
             Command::Send(action, family, data) => {
                 let _ = self.bus.send_buf(action, family, data).await;
@@ -0,0 +1,66 @@
+use chrono::{Duration, Utc};
+
+use crate::{SETTINGS, settings::CaptchaFailAction, utils::is_deep};
+
+use super::super::Player;
+
+impl Player {
+    /// Reports players flagged by bot detection and opens a captcha for them
+    pub async fn check_bot_detection(&mut self) {
+        let reasons = match self.bot_detector.take_detection() {
+            Some(reasons) => reasons,
+            None => return,
+        };
+
+        let name = match &self.character_name {
+            Some(name) => name.to_owned(),
+            None => return,
+        };
+
+        if self.captcha.is_some() {
+            return;
+        }
+
+        warn!("{} flagged as a possible bot: {}", name, reasons);
+        self.world.notify_admins(
+            "Bot".to_string(),
+            format!("{} flagged as a possible bot: {}", name, reasons),
+        );
+
+        if !is_deep(&self.version) {
+            return;
+        }
+
+        self.show_captcha(SETTINGS.bot_detection.captcha_reward)
+            .await;
+
+        if let Some(captcha) = self.captcha.as_mut() {
+            captcha.expires_at =
+                Some(Utc::now() + Duration::minutes(SETTINGS.bot_detection.captcha_minutes));
+        }
+    }
+
+    pub async fn fail_bot_check(&mut self) {
+        self.captcha = None;
+
+        let name = match &self.character_name {
+            Some(name) => name.to_owned(),
+            None => return,
+        };
+
+        warn!("{} failed a bot check", name);
+        self.world
+            .notify_admins("Bot".to_string(), format!("{} failed a bot check", name));
+
+        match SETTINGS.bot_detection.fail_action {
+            CaptchaFailAction::Jail => {
+                if let Some(map) = &self.map {
+                    map.close_captcha(self.id, 0);
+                }
+
+                self.world.jail_player(name, "Server".to_string());
+            }
+            CaptchaFailAction::Kick => self.close("Failed bot check".to_string()).await,
+        }
+    }
+}
@@ -1,44 +1,50 @@
-use std::time::Duration;
-
-use chrono::{NaiveDateTime, Utc};
-
-use crate::db::insert_params;
+use crate::{IP_BANS, db::insert_params, utils::get_ban_minutes_remaining};
 
 use super::Player;
 
 impl Player {
-    pub async fn get_ban_duration(&mut self) -> Option<i32> {
-        let row = match self
+    /// Checks ip/range and hdid ban rules, returning the longest remaining
+    /// ban in minutes (0 = permanent)
+    pub async fn get_ban_duration(&mut self, hdid: &str) -> Option<i32> {
+        let hdid = match hdid.trim().parse::<u32>() {
+            Ok(hdid) => hdid.to_string(),
+            Err(_) => hdid.trim().to_string(),
+        };
+
+        let mut longest = IP_BANS.get_ban_duration(&self.ip);
+        if longest == Some(0) || hdid.is_empty() {
+            return longest;
+        }
+
+        let rows = match self
             .db
-            .query_one(&insert_params(
-                include_str!("../../sql/get_ban_duration.sql"),
-                &[("ip", &self.ip)],
+            .query(&insert_params(
+                include_str!("../../sql/get_hdid_bans.sql"),
+                &[("hdid", &hdid)],
             ))
             .await
         {
-            Ok(Some(row)) => row,
-            _ => return None,
-        };
-
-        let duration = match row.get_int(0) {
-            Some(d) => d,
-            None => return None,
+            Ok(rows) => rows,
+            Err(e) => {
+                error!("Failed to get bans: {}", e);
+                return longest;
+            }
         };
 
-        // 0 = permanent
-        if duration == 0 {
-            return Some(0);
-        }
-
-        let now = Utc::now().naive_utc();
-        let created_at: NaiveDateTime = row.get_date(1).unwrap();
-        let expires_at = created_at + Duration::from_mins(duration as u64);
-        let diff = expires_at - now;
-        let remaining = diff.num_minutes();
-        if remaining <= 0 {
-            return None;
+        for row in rows {
+            let (Some(duration), Some(created_at)) = (row.get_int(0), row.get_date(1)) else {
+                continue;
+            };
+
+            match get_ban_minutes_remaining(duration, created_at) {
+                Some(0) => return Some(0),
+                Some(remaining) => {
+                    longest = Some(longest.map_or(remaining, |l| l.max(remaining)));
+                }
+                None => {}
+            }
         }
 
-        Some(diff.num_minutes() as i32)
+        longest
     }
 }
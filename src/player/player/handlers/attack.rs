@@ -3,7 +3,9 @@ use eolib::{
     protocol::net::{PacketAction, client::AttackUseClientPacket},
 };
 
-use crate::utils::timestamp_diff;
+use chrono::Utc;
+
+use crate::{SETTINGS, player::BotAction, utils::timestamp_diff};
 
 use super::super::Player;
 
@@ -23,6 +25,11 @@ impl Player {
             }
 
             self.timestamp = packet.timestamp;
+            self.bot_detector.record(
+                BotAction::Attack(packet.direction),
+                Utc::now(),
+                &SETTINGS.bot_detection,
+            );
 
             map.attack(self.id, packet.direction);
         }
@@ -21,11 +21,12 @@ impl Player {
                 }
             };
 
-            let (attempts, captcha, reward) = match &self.captcha {
+            let (attempts, captcha, reward, bot_check) = match &self.captcha {
                 Some(captcha) => (
                     captcha.attempts + 1,
                     captcha.challenge.to_owned(),
                     captcha.reward,
+                    captcha.expires_at.is_some(),
                 ),
                 None => return,
             };
@@ -38,6 +39,10 @@ impl Player {
                 if let Some(captcha) = &mut self.captcha {
                     captcha.attempts += 1;
                 }
+
+                if bot_check && attempts == 5 {
+                    self.fail_bot_check().await;
+                }
                 return;
             }
 
@@ -19,13 +19,15 @@ use eolib::{
     },
 };
 
+use serde_json::json;
+
 use crate::{
     SETTINGS,
     character::Character,
     db::{DbHandle, insert_params},
     errors::WrongSessionIdError,
     player::{
-        ClientState,
+        Action, ClientState,
         player::account::{get_character_list, get_num_of_characters},
     },
     utils::validate_character_name,
@@ -208,6 +210,20 @@ impl Player {
             return;
         }
 
+        self.record_action_with_details(
+            Action::CharacterDeleted,
+            &json!({
+                "character_id": character.id,
+                "name": character.name,
+                "level": character.level,
+                "gold": character.get_item_amount(1),
+                "gold_bank": character.gold_bank,
+                "items": character.items,
+                "bank": character.bank,
+            })
+            .to_string(),
+        );
+
         let characters = match get_character_list(&self.db, self.account_id).await {
             Ok(characters) => characters,
             Err(e) => {
@@ -4,7 +4,7 @@ use eolib::protocol::net::{PacketAction, PacketFamily};
 use eolib::protocol::r#pub::EifRecord;
 
 use crate::commands::{ArgType, Command};
-use crate::{character::Character, player::PlayerHandle, world::WorldHandle};
+use crate::{character::Character, map::QuestUpdate, player::PlayerHandle, world::WorldHandle};
 
 use crate::{COMMANDS, ITEM_DB, NPC_DB};
 
@@ -72,6 +72,107 @@ async fn warp_me_to(args: &[String], character: &Character, world: &WorldHandle)
     }
 }
 
+fn maintenance(args: &[String], character: &Character, player: &PlayerHandle, world: &WorldHandle) {
+    let enabled = match args.first().map(|state| state.as_str()) {
+        Some("on") => Some(true),
+        Some("off") => Some(false),
+        None => None,
+        Some(state) => {
+            send_error_message(player, format!("Invalid state: {}", state));
+            return;
+        }
+    };
+
+    world.set_maintenance(
+        character.name.to_owned(),
+        enabled,
+        args.get(1).is_some_and(|kick| kick == "kick"),
+    );
+}
+
+fn shutdown(args: &[String], character: &Character, player: &PlayerHandle, world: &WorldHandle) {
+    if args[0] == "cancel" {
+        world.cancel_shutdown(character.player_id, character.name.to_owned());
+        return;
+    }
+
+    let seconds = match args[0]
+        .parse::<u64>()
+        .or_else(|_| duration_str::parse(&args[0]).map(|duration| duration.as_secs()))
+    {
+        Ok(seconds) if seconds > 0 => seconds.min(i32::MAX as u64) as i32,
+        _ => {
+            send_error_message(player, format!("Invalid duration: {}", args[0]));
+            return;
+        }
+    };
+
+    world.start_shutdown(
+        character.name.to_owned(),
+        seconds,
+        args.get(1).cloned().unwrap_or_default(),
+    );
+}
+
+fn quest(args: &[String], character: &Character, player: &PlayerHandle, world: &WorldHandle) {
+    let player_id = character.player_id.unwrap();
+    let name = args[0].to_owned();
+
+    let quest_id = match args.get(1) {
+        Some(quest_id) => quest_id.parse::<i32>().unwrap(),
+        None => return world.list_player_quests(player_id, name),
+    };
+
+    let update = match args.get(2).map(|action| action.to_lowercase()).as_deref() {
+        None | Some("view") => return world.view_player_quest(player_id, name, quest_id),
+        Some("set") => match args.get(3) {
+            Some(state) => QuestUpdate::SetState(state.to_owned()),
+            None => {
+                send_error_message(player, "Usage: $quest player 1 set State".to_string());
+                return;
+            }
+        },
+        Some("reset") => QuestUpdate::Reset,
+        Some("complete") => QuestUpdate::Complete,
+        Some(action) => {
+            send_error_message(
+                player,
+                format!(
+                    "Unknown quest action {}, use view, set, reset or complete",
+                    action
+                ),
+            );
+            return;
+        }
+    };
+
+    world.update_player_quest(player_id, name, quest_id, update);
+}
+
+async fn spawns(
+    args: &[String],
+    character: &Character,
+    player: &PlayerHandle,
+    world: &WorldHandle,
+) {
+    let map = match world.get_map(character.map_id).await {
+        Ok(map) => map,
+        Err(e) => {
+            error!("Failed to get map: {}", e);
+            return;
+        }
+    };
+
+    match args.first().map(|action| action.to_lowercase()).as_deref() {
+        None | Some("list") => map.list_spawns(character.player_id.unwrap()),
+        Some("reload") => map.reload_spawns(character.player_id.unwrap()),
+        Some(action) => send_error_message(
+            player,
+            format!("Unknown spawns action {}, use list or reload", action),
+        ),
+    }
+}
+
 async fn evacuate(character: &Character, world: &WorldHandle) {
     let map = match world.get_map(character.map_id).await {
         Ok(map) => map,
@@ -84,6 +185,39 @@ async fn evacuate(character: &Character, world: &WorldHandle) {
     map.start_evacuate();
 }
 
+fn tickets(
+    command: &str,
+    args: &[String],
+    character: &Character,
+    player: &PlayerHandle,
+    world: &WorldHandle,
+) {
+    let report_id = match args.first().map(|id| id.parse::<i32>()) {
+        Some(Ok(report_id)) => Some(report_id),
+        Some(Err(_)) => {
+            send_error_message(player, format!("Invalid ticket: {}", args[0]));
+            return;
+        }
+        None => None,
+    };
+
+    match (command, report_id) {
+        ("tickets", report_id) => world.list_reports(character.player_id.unwrap(), report_id),
+        ("claim", Some(report_id)) => world.claim_report(
+            character.player_id.unwrap(),
+            character.name.to_owned(),
+            report_id,
+        ),
+        ("resolve", Some(report_id)) => world.resolve_report(
+            character.player_id.unwrap(),
+            character.name.to_owned(),
+            report_id,
+            args.get(1).cloned().unwrap_or_default(),
+        ),
+        _ => {}
+    }
+}
+
 async fn get_item_id_and_amount(player: &PlayerHandle, args: &[String]) -> Option<(i32, i32)> {
     let amount = if args.len() > 1 {
         args.last()
@@ -352,6 +486,26 @@ pub async fn handle_command(
                 }
             }
 
+            if matches!(command.name.as_str(), "ban" | "sban" | "banip") && args.len() > 1 {
+                // duration is optional so anything that doesn't parse as one starts the reason
+                let (duration, reason) = if duration_str::parse(&args[1]).is_ok() {
+                    (args[1].clone(), args[2..].join(" "))
+                } else {
+                    ("".to_string(), args[1..].join(" "))
+                };
+                args = vec![args[0].clone(), duration, reason];
+            }
+
+            if command.name.as_str() == "shutdown" && args.len() > 2 {
+                let reason = args[1..].join(" ");
+                args = vec![args[0].clone(), reason];
+            }
+
+            if command.name.as_str() == "resolve" && args.len() > 2 {
+                let resolution = args[1..].join(" ");
+                args = vec![args[0].clone(), resolution];
+            }
+
             if command.name.as_str() == "set" {
                 if args.len() > 3 {
                     let value = args[2..].join(" ");
@@ -364,6 +518,12 @@ pub async fn handle_command(
             if i32::from(character.admin_level) >= i32::from(command.admin_level)
                 && validate_args(&args, command, &player)
             {
+                world.record_admin_action(
+                    Box::new(character.to_owned()),
+                    command.name.to_owned(),
+                    args.clone(),
+                );
+
                 match command.name.as_str() {
                     "hide" => hide(character).await,
                     "spawnitem" => spawn_item(&args, character).await,
@@ -383,24 +543,31 @@ pub async fn handle_command(
                     }
                     "ban" => world.ban_player(
                         args[0].to_owned(),
-                        if args.len() > 1 {
-                            args[1].to_owned()
-                        } else {
-                            "".to_string()
-                        },
+                        args.get(1).cloned().unwrap_or_default(),
+                        args.get(2).cloned().unwrap_or_default(),
                         character.name.to_owned(),
                         false,
                     ),
                     "sban" => world.ban_player(
                         args[0].to_owned(),
-                        if args.len() > 1 {
-                            args[1].to_owned()
-                        } else {
-                            "".to_string()
-                        },
+                        args.get(1).cloned().unwrap_or_default(),
+                        args.get(2).cloned().unwrap_or_default(),
                         character.name.to_owned(),
                         true,
                     ),
+                    "banip" => world.ban_ip(
+                        character.player_id.unwrap(),
+                        character.name.to_owned(),
+                        args[0].to_owned(),
+                        args.get(1).cloned().unwrap_or_default(),
+                        args.get(2).cloned().unwrap_or_default(),
+                    ),
+                    "unban" => world.unban_player(character.player_id.unwrap(), args[0].to_owned()),
+                    "bans" => world.list_bans(character.player_id.unwrap(), args.first().cloned()),
+                    "audit" => {
+                        world.list_audit_log(character.player_id.unwrap(), args.first().cloned())
+                    }
+                    "quest" => quest(&args, character, &player, &world),
                     "quake" => world.quake(if args.is_empty() {
                         1
                     } else {
@@ -418,6 +585,22 @@ pub async fn handle_command(
                     }
                     "global" => world.toggle_global(character.name.to_owned()),
                     "remap" => world.reload_map(character.map_id),
+                    "reloadquests" => world.reload_quests(character.player_id.unwrap()),
+                    "shutdown" => shutdown(&args, character, &player, &world),
+                    "maintenance" => maintenance(&args, character, &player, &world),
+                    "spawns" => spawns(&args, character, &player, &world).await,
+                    "event" => {
+                        world.toggle_event(character.player_id.unwrap(), args.first().cloned())
+                    }
+                    "time" => world.set_time(
+                        character.player_id.unwrap(),
+                        args.first().map(|hour| hour.parse::<u32>().unwrap()),
+                    ),
+                    "weather" => world.set_weather(
+                        character.player_id.unwrap(),
+                        character.map_id,
+                        args.first().cloned(),
+                    ),
                     "captcha" => {
                         world.show_captcha(args[0].to_owned(), args[1].parse::<i32>().unwrap())
                     }
@@ -426,6 +609,9 @@ pub async fn handle_command(
                         args[1].to_owned(),
                         args[2].to_owned(),
                     ),
+                    "tickets" | "claim" | "resolve" => {
+                        tickets(&command.name, &args, character, &player, &world)
+                    }
                     _ => {
                         send_error_message(
                             &player,
@@ -31,7 +31,7 @@ impl Player {
             }
         };
 
-        if let Some(duration) = self.get_ban_duration().await {
+        if let Some(duration) = self.get_ban_duration(&request.hdid).await {
             let _ = self
                 .bus
                 .send(
@@ -8,7 +8,9 @@ use eolib::{
     },
 };
 
-use crate::{SETTINGS, deep::ItemReportClientPacket};
+use chrono::Utc;
+
+use crate::{SETTINGS, deep::ItemReportClientPacket, player::BotAction};
 
 use super::super::Player;
 
@@ -42,6 +44,8 @@ impl Player {
             };
 
             map.get_item(self.id, get.item_index);
+            self.bot_detector
+                .record(BotAction::Pickup, Utc::now(), &SETTINGS.bot_detection);
         }
     }
 
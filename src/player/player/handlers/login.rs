@@ -9,7 +9,7 @@ use eolib::{
             LoginReplyServerPacketReplyCodeDataBanned, LoginReplyServerPacketReplyCodeDataBusy,
             LoginReplyServerPacketReplyCodeDataLoggedIn, LoginReplyServerPacketReplyCodeDataOk,
             LoginReplyServerPacketReplyCodeDataWrongUser,
-            LoginReplyServerPacketReplyCodeDataWrongUserPassword,
+            LoginReplyServerPacketReplyCodeDataWrongUserPassword, MessageAcceptServerPacket,
         },
     },
 };
@@ -31,7 +31,7 @@ use crate::{
             get_character_list, validate_password,
         },
     },
-    utils::{is_deep, mask_email, send_email},
+    utils::{has_maintenance_access, is_deep, mask_email, send_email},
 };
 
 use super::super::Player;
@@ -64,22 +64,11 @@ impl Player {
             .await
             .expect("Failed to get player count. Timeout");
         if player_count >= SETTINGS.server.max_players {
-            let _ = self
-                .bus
-                .send(
-                    PacketAction::Reply,
-                    PacketFamily::Login,
-                    LoginReplyServerPacket {
-                        reply_code: LoginReply::Busy,
-                        reply_code_data: Some(LoginReplyServerPacketReplyCodeData::Busy(
-                            LoginReplyServerPacketReplyCodeDataBusy::new(),
-                        )),
-                    },
-                )
-                .await;
-
-            self.close("Server busy".to_string()).await;
+            self.send_busy("Server busy").await;
+            return;
+        }
 
+        if self.is_login_blocked().await {
             return;
         }
 
@@ -196,6 +185,11 @@ impl Player {
             return;
         }
 
+        if self.is_maintenance_blocked(account_id).await {
+            self.world.remove_pending_login(account_id);
+            return;
+        }
+
         if logged_in {
             self.world.remove_pending_login(account_id);
             if self.login_attempts >= SETTINGS.server.max_login_attempts {
@@ -499,6 +493,10 @@ impl Player {
             return;
         }
 
+        if self.is_login_blocked().await {
+            return;
+        }
+
         let row = match self
             .db
             .query_one(&insert_params(
@@ -539,6 +537,10 @@ impl Player {
                 }
 
                 let account_id = row.get_int(0).unwrap();
+                if self.is_maintenance_blocked(account_id).await {
+                    return;
+                }
+
                 let logged_in = self
                     .world
                     .is_logged_in(account_id)
@@ -585,6 +587,77 @@ impl Player {
         }
     }
 
+    /// Turns logins away once a shutdown countdown is nearly over
+    pub(super) async fn is_login_blocked(&mut self) -> bool {
+        let blocked = self
+            .world
+            .is_login_blocked()
+            .await
+            .expect("Failed to check if logins are blocked. Timeout");
+
+        if blocked {
+            self.send_busy("Server shutting down").await;
+        }
+
+        blocked
+    }
+
+    /// Turns away accounts without staff access while in maintenance mode
+    async fn is_maintenance_blocked(&mut self, account_id: i32) -> bool {
+        let maintenance = self
+            .world
+            .is_maintenance()
+            .await
+            .expect("Failed to check maintenance mode. Timeout");
+
+        if !maintenance {
+            return false;
+        }
+
+        match has_maintenance_access(&self.db, account_id).await {
+            Ok(true) => return false,
+            Ok(false) => {}
+            Err(e) => error!("Failed to check maintenance access: {}", e),
+        }
+
+        let _ = self
+            .bus
+            .send(
+                PacketAction::Accept,
+                PacketFamily::Message,
+                MessageAcceptServerPacket {
+                    messages: [
+                        "Maintenance".to_string(),
+                        SETTINGS.maintenance.message.to_owned(),
+                        String::new(),
+                        String::new(),
+                    ],
+                },
+            )
+            .await;
+
+        self.send_busy("Server in maintenance").await;
+        true
+    }
+
+    async fn send_busy(&mut self, reason: &str) {
+        let _ = self
+            .bus
+            .send(
+                PacketAction::Reply,
+                PacketFamily::Login,
+                LoginReplyServerPacket {
+                    reply_code: LoginReply::Busy,
+                    reply_code_data: Some(LoginReplyServerPacketReplyCodeData::Busy(
+                        LoginReplyServerPacketReplyCodeDataBusy::new(),
+                    )),
+                },
+            )
+            .await;
+
+        self.close(reason.to_string()).await;
+    }
+
     pub async fn handle_login(&mut self, action: PacketAction, reader: EoReader) {
         match action {
             PacketAction::Request => self.login_request(reader).await,
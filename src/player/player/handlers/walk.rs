@@ -3,7 +3,9 @@ use eolib::{
     protocol::net::client::WalkPlayerClientPacket,
 };
 
-use crate::utils::timestamp_diff;
+use chrono::Utc;
+
+use crate::{SETTINGS, player::BotAction, utils::timestamp_diff};
 
 use super::super::Player;
 
@@ -27,6 +29,11 @@ impl Player {
             }
 
             self.timestamp = packet.walk_action.timestamp;
+            self.bot_detector.record(
+                BotAction::Walk(packet.walk_action.direction),
+                Utc::now(),
+                &SETTINGS.bot_detection,
+            );
 
             map.walk(
                 self.id,
@@ -54,6 +54,10 @@ impl Player {
             }
         };
 
+        if self.is_login_blocked().await {
+            return;
+        }
+
         self.enter_game(msg.session_id).await;
     }
 
@@ -66,6 +70,10 @@ impl Player {
             }
         };
 
+        if self.is_login_blocked().await {
+            return;
+        }
+
         self.select_character(request.character_id).await;
     }
 
@@ -17,7 +17,7 @@ impl Player {
         };
 
         match action.as_str() {
-            "SetMap" => {
+            "SetMap" | "SetCoord" => {
                 let map_id = match args.first() {
                     Some(Arg::Int(map_id)) => *map_id,
                     _ => return,
@@ -70,6 +70,21 @@ impl Player {
 
                 map.set_class(self.id, class_id);
             }
+            "SetRace" => {
+                if let Some(Arg::Int(skin)) = args.first() {
+                    map.set_character_property(self.id, "skin".to_owned(), skin.to_string());
+                }
+            }
+            "SetTitle" | "SetHome" | "SetFiance" | "SetPartner" => {
+                let value = match args.first() {
+                    Some(Arg::Str(value)) => value.to_owned(),
+                    Some(Arg::Int(value)) => value.to_string(),
+                    None => return,
+                };
+
+                let property = action.trim_start_matches("Set").to_lowercase();
+                map.set_character_property(self.id, property, value);
+            }
             "PlayMusic" => {
                 if let Some(Arg::Int(sound_id)) = args.first() {
                     let _ = self
@@ -139,6 +154,11 @@ impl Player {
                     }
                 }
             }
+            "QuakeWorld" => {
+                if let Some(Arg::Int(magnitude)) = args.first() {
+                    self.world.quake(*magnitude);
+                }
+            }
             "EffectOnPlayer" => {
                 let effect_id = match args.first() {
                     Some(Arg::Int(effect_id)) => *effect_id,
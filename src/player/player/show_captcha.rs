@@ -28,6 +28,7 @@ impl Player {
             challenge: captcha.to_owned(),
             reward: experience,
             attempts: 0,
+            expires_at: None,
         });
 
         if let Some(map) = &self.map {
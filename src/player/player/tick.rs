@@ -24,6 +24,12 @@ impl Player {
             }
         }
 
+        if let Some(expires_at) = self.captcha.as_ref().and_then(|captcha| captcha.expires_at)
+            && Utc::now() >= expires_at
+        {
+            self.fail_bot_check().await;
+        }
+
         self.bus.log.clean_old_entries();
     }
 }
@@ -21,7 +21,10 @@ impl Player {
                 .collect()
         };
 
-        captcha.attempts = 0;
+        // Bot checks can't dodge failing by asking for a new challenge
+        if captcha.expires_at.is_none() {
+            captcha.attempts = 0;
+        }
 
         let _ = self
             .bus
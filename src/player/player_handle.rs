@@ -17,7 +17,7 @@ use tokio::time::timeout;
 
 use crate::{character::Character, map::MapHandle, world::WorldHandle};
 
-use super::{ClientState, Command, PartyRequest, Socket, player::Player};
+use super::{Action, ClientState, Command, PartyRequest, Socket, player::Player};
 
 #[derive(Debug, Clone)]
 pub struct PlayerHandle {
@@ -113,6 +113,16 @@ impl PlayerHandle {
         }
     }
 
+    pub async fn get_ip(&self) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetIp { respond_to: tx });
+        match timeout(Duration::from_secs(1), rx).await {
+            Ok(Ok(ip)) => Ok(ip),
+            Ok(Err(_)) => Err("Failed to get ip. Channel closed".into()),
+            Err(_) => Err("Failed to get ip. Timeout".into()),
+        }
+    }
+
     pub async fn get_player_id(&self) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::GetPlayerId { respond_to: tx });
@@ -182,6 +192,10 @@ impl PlayerHandle {
         let _ = self.tx.send(Command::SendGuildReply(guild_reply));
     }
 
+    pub fn record_action(&self, action: Action, details: String) {
+        let _ = self.tx.send(Command::RecordAction { action, details });
+    }
+
     pub fn send_server_message(&self, message: &str) {
         let _ = self.tx.send(Command::SendServerMessage(message.to_owned()));
     }
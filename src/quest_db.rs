@@ -0,0 +1,306 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use eoplus::Quest;
+use tokio::sync::{RwLock as AsyncRwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::utils::capitalize;
+
+/// How stored progress should change for each quest that was changed in a reload
+pub type QuestStateRemaps = HashMap<i32, QuestRemap>;
+
+#[derive(Debug)]
+pub enum QuestRemap {
+    /// Old state names and their new index
+    States(Vec<(String, Option<i32>)>),
+    /// The quest was deleted so its progress should be cleared
+    Removed,
+}
+
+pub enum StateRemap {
+    Unchanged,
+    Moved(i32),
+    /// The state (by its old name) no longer exists
+    Lost(String),
+    /// The quest no longer exists
+    Removed,
+}
+
+/// Loaded quests, swappable at runtime so quests can be reloaded without a restart
+pub struct QuestDb {
+    quests: RwLock<Arc<HashMap<i32, Arc<Quest>>>>,
+    /// Remaps of every reload so far, the generation is its length
+    history: RwLock<Vec<Arc<QuestStateRemaps>>>,
+    /// Held for reading while quest progress is loaded or saved and for
+    /// writing while a reload remaps stored progress
+    progress: AsyncRwLock<()>,
+}
+
+impl QuestDb {
+    pub fn new(quests: HashMap<i32, Quest>) -> Self {
+        Self {
+            quests: RwLock::new(Arc::new(Self::wrap(quests))),
+            history: RwLock::new(Vec::new()),
+            progress: AsyncRwLock::new(()),
+        }
+    }
+
+    pub fn get(&self, quest_id: &i32) -> Option<Arc<Quest>> {
+        self.all().get(quest_id).cloned()
+    }
+
+    pub fn contains_key(&self, quest_id: &i32) -> bool {
+        self.all().contains_key(quest_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.all().len()
+    }
+
+    /// Snapshot of every quest, unaffected by later reloads
+    pub fn all(&self) -> Arc<HashMap<i32, Arc<Quest>>> {
+        self.quests.read().unwrap().clone()
+    }
+
+    /// How many reloads have changed quest states
+    pub fn generation(&self) -> usize {
+        self.history.read().unwrap().len()
+    }
+
+    /// Remaps of every reload after `generation` in order, along with the
+    /// current generation
+    pub fn get_remaps_since(&self, generation: usize) -> (Vec<Arc<QuestStateRemaps>>, usize) {
+        let history = self.history.read().unwrap();
+        (
+            history.iter().skip(generation).cloned().collect(),
+            history.len(),
+        )
+    }
+
+    /// Blocks reloads while quest progress is loaded from or saved to the database
+    pub async fn lock_progress(&self) -> RwLockReadGuard<'_, ()> {
+        self.progress.read().await
+    }
+
+    /// Blocks quest progress loads and saves while stored progress is remapped
+    pub async fn lock_reload(&self) -> RwLockWriteGuard<'_, ()> {
+        self.progress.write().await
+    }
+
+    /// Swaps in new quests, returning how stored state indexes should be
+    /// re-resolved by name for quests whose states were changed and which
+    /// quests were deleted
+    pub fn reload(&self, quests: HashMap<i32, Quest>) -> Arc<QuestStateRemaps> {
+        let quests = Self::wrap(quests);
+        let mut remaps = QuestStateRemaps::new();
+
+        let mut current = self.quests.write().unwrap();
+        for (quest_id, old_quest) in current.iter() {
+            let new_quest = match quests.get(quest_id) {
+                Some(quest) => quest,
+                None => {
+                    remaps.insert(*quest_id, QuestRemap::Removed);
+                    continue;
+                }
+            };
+
+            let states = old_quest
+                .states
+                .iter()
+                .map(|state| {
+                    (
+                        state.name.to_owned(),
+                        new_quest
+                            .states
+                            .iter()
+                            .position(|new_state| new_state.name.eq_ignore_ascii_case(&state.name))
+                            .map(|index| index as i32),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            let unchanged = old_quest.states.len() == new_quest.states.len()
+                && states
+                    .iter()
+                    .enumerate()
+                    .all(|(index, (_, new_index))| *new_index == Some(index as i32));
+
+            if !unchanged {
+                remaps.insert(*quest_id, QuestRemap::States(states));
+            }
+        }
+
+        *current = Arc::new(quests);
+
+        let remaps = Arc::new(remaps);
+        if !remaps.is_empty() {
+            self.history.write().unwrap().push(remaps.clone());
+        }
+
+        remaps
+    }
+
+    fn wrap(quests: HashMap<i32, Quest>) -> HashMap<i32, Arc<Quest>> {
+        quests
+            .into_iter()
+            .map(|(id, quest)| (id, Arc::new(quest)))
+            .collect()
+    }
+}
+
+pub fn remap_quest_state(remaps: &QuestStateRemaps, quest_id: i32, state: i32) -> StateRemap {
+    let states = match remaps.get(&quest_id) {
+        Some(QuestRemap::States(states)) => states,
+        Some(QuestRemap::Removed) => return StateRemap::Removed,
+        None => return StateRemap::Unchanged,
+    };
+
+    match states.get(state as usize) {
+        Some((_, Some(new_state))) if *new_state == state => StateRemap::Unchanged,
+        Some((_, Some(new_state))) => StateRemap::Moved(*new_state),
+        Some((name, None)) => StateRemap::Lost(name.to_owned()),
+        None => StateRemap::Lost(format!("#{}", state)),
+    }
+}
+
+pub fn get_removed_quest_message(character_name: &str, quest_id: i32) -> String {
+    format!(
+        "{} lost their progress in quest {} because it no longer exists",
+        capitalize(character_name),
+        quest_id
+    )
+}
+
+pub fn get_lost_state_message(character_name: &str, quest_id: i32, state: &str) -> String {
+    format!(
+        "{} was moved to the start of quest {} because state {} no longer exists",
+        capitalize(character_name),
+        quest_id,
+        state
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use eoplus::State;
+
+    use super::*;
+
+    fn quest(states: &[&str]) -> Quest {
+        Quest {
+            states: states
+                .iter()
+                .map(|name| State {
+                    name: name.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn remaps() -> QuestStateRemaps {
+        QuestStateRemaps::from([
+            (
+                1,
+                QuestRemap::States(vec![
+                    ("Begin".to_string(), Some(0)),
+                    ("Hunt".to_string(), Some(2)),
+                    ("Reward".to_string(), None),
+                ]),
+            ),
+            (2, QuestRemap::Removed),
+        ])
+    }
+
+    #[test]
+    fn remap_quest_state_unchanged() {
+        let remaps = remaps();
+        assert!(matches!(
+            remap_quest_state(&remaps, 1, 0),
+            StateRemap::Unchanged
+        ));
+        assert!(matches!(
+            remap_quest_state(&remaps, 3, 4),
+            StateRemap::Unchanged
+        ));
+    }
+
+    #[test]
+    fn remap_quest_state_moved() {
+        assert!(matches!(
+            remap_quest_state(&remaps(), 1, 1),
+            StateRemap::Moved(2)
+        ));
+    }
+
+    #[test]
+    fn remap_quest_state_lost() {
+        let remaps = remaps();
+        assert!(matches!(
+            remap_quest_state(&remaps, 1, 2),
+            StateRemap::Lost(name) if name == "Reward"
+        ));
+        assert!(matches!(
+            remap_quest_state(&remaps, 1, 5),
+            StateRemap::Lost(name) if name == "#5"
+        ));
+    }
+
+    #[test]
+    fn remap_quest_state_removed() {
+        assert!(matches!(
+            remap_quest_state(&remaps(), 2, 0),
+            StateRemap::Removed
+        ));
+    }
+
+    #[test]
+    fn reload_remaps_changed_quests() {
+        let db = QuestDb::new(HashMap::from([
+            (1, quest(&["Begin", "Hunt", "Reward"])),
+            (2, quest(&["Begin", "End"])),
+            (3, quest(&["Begin", "End"])),
+        ]));
+
+        let remaps = db.reload(HashMap::from([
+            (1, quest(&["Begin", "Talk", "hunt"])),
+            (3, quest(&["Begin", "End"])),
+        ]));
+
+        match remaps.get(&1) {
+            Some(QuestRemap::States(states)) => assert_eq!(
+                states,
+                &vec![
+                    ("Begin".to_string(), Some(0)),
+                    ("Hunt".to_string(), Some(2)),
+                    ("Reward".to_string(), None),
+                ]
+            ),
+            remap => panic!("Expected quest 1 states to be remapped, got {:?}", remap),
+        }
+        assert!(matches!(remaps.get(&2), Some(QuestRemap::Removed)));
+        assert!(remaps.get(&3).is_none());
+        assert!(!db.contains_key(&2));
+        assert_eq!(db.generation(), 1);
+    }
+
+    #[test]
+    fn reload_tracks_generations() {
+        let db = QuestDb::new(HashMap::from([(1, quest(&["Begin", "End"]))]));
+
+        let remaps = db.reload(HashMap::from([(1, quest(&["Begin", "End"]))]));
+        assert!(remaps.is_empty());
+        assert_eq!(db.generation(), 0);
+
+        db.reload(HashMap::from([(1, quest(&["End", "Begin"]))]));
+        db.reload(HashMap::new());
+
+        let (since, generation) = db.get_remaps_since(1);
+        assert_eq!(generation, 2);
+        assert_eq!(since.len(), 1);
+        assert!(matches!(since[0].get(&1), Some(QuestRemap::Removed)));
+    }
+}
@@ -5,6 +5,10 @@ pub struct Server {
     pub host: String,
     pub port: String,
     pub websocket_port: String,
+    pub tls_cert: String,
+    pub tls_key: String,
+    pub trusted_proxies: Vec<String>,
+    pub proxy_protocol: bool,
     pub max_connections: i32,
     pub max_players: i32,
     pub max_connections_per_ip: i32,
@@ -91,6 +95,43 @@ pub struct World {
     pub usage_rate: i32,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct Movement {
+    pub enabled: bool,
+    pub step_ms: i64,
+    pub burst: i32,
+    pub max_desync: i32,
+    pub violation_decay: i64,
+    pub warp_back: i32,
+    pub log: i32,
+    pub alert_admins: i32,
+    pub kick: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BotDetection {
+    pub enabled: bool,
+    pub threshold: i32,
+    pub periodic_timing: i32,
+    pub repetitive_pattern: i32,
+    pub long_session: i32,
+    pub sample_size: usize,
+    pub max_timing_deviation: f64,
+    pub max_pattern_length: usize,
+    pub session_minutes: i64,
+    pub session_break_minutes: i64,
+    pub captcha_reward: i32,
+    pub captcha_minutes: i64,
+    pub fail_action: CaptchaFailAction,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum CaptchaFailAction {
+    Jail,
+    Kick,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Bard {
     pub instrument_items: Vec<i32>,
@@ -112,6 +153,15 @@ pub struct NPCs {
     pub speed_5: i32,
     pub speed_6: i32,
     pub talk_rate: i32,
+    pub respawn_speedup_per_player: i32,
+    pub respawn_population_cap: i32,
+    pub rare_npcs: Vec<i32>,
+    pub rare_respawn_variance: i32,
+    pub rare_spawn_radius: i32,
+    pub camping_window: i32,
+    pub camping_free_kills: i32,
+    pub camping_penalty: i32,
+    pub camping_min_reward: i32,
 }
 
 #[derive(Debug, Deserialize)]
@@ -170,6 +220,59 @@ pub struct Board {
     pub admin_max_posts: i32,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct Reports {
+    pub chat_history: usize,
+    pub list_limit: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Bans {
+    pub list_limit: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Audit {
+    pub list_limit: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Economy {
+    pub enabled: bool,
+    pub spike_percent: i64,
+    pub min_item_increase: i64,
+    pub min_gold_increase: i64,
+    pub sourced_items: Vec<i32>,
+    pub max_alerts: usize,
+    pub summary_items: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Quests {
+    pub weekly_reset_day: String,
+    pub weekly_reset_hour: u32,
+    pub repeat: Vec<QuestRepeat>,
+    pub party_share: bool,
+    pub party_share_range: i32,
+    pub party_shared_quests: Vec<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuestRepeat {
+    pub quest: i32,
+    #[serde(flatten)]
+    pub period: RepeatPeriod,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "period", rename_all = "lowercase")]
+pub enum RepeatPeriod {
+    Daily,
+    Weekly,
+    Hours { hours: i64 },
+    Lifetime { limit: i32 },
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Chest {
     pub slots: i32,
@@ -250,6 +353,58 @@ pub struct Evacuate {
     pub timer_step: i32,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct Shutdown {
+    pub sfx_id: i32,
+    pub announce_at: Vec<i32>,
+    pub block_logins_seconds: i32,
+    pub signal_seconds: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Maintenance {
+    pub enabled: bool,
+    pub allowlist: Vec<String>,
+    pub message: String,
+    pub kick_players: bool,
+    pub kick_delay_seconds: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Karma {
+    pub neutral: i32,
+    pub drift_seconds: i32,
+    pub drift_amount: i32,
+    pub pk_flag_seconds: i32,
+    pub tiers: Vec<KarmaTier>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KarmaTier {
+    pub min_karma: i32,
+    pub max_karma: i32,
+    pub shop_buy_multiplier: f32,
+    pub shop_sell_multiplier: f32,
+    pub aggressive_npcs: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Guards {
+    pub npcs: Vec<i32>,
+    pub town_maps: Vec<i32>,
+    pub wanted_karma: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Clock {
+    pub day_length: i32,
+    pub start_hour: u32,
+    pub day_start_hour: u32,
+    pub night_start_hour: u32,
+    pub day_sfx_id: i32,
+    pub night_sfx_id: i32,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Items {
     pub infinite_use_items: Vec<i32>,
@@ -281,12 +436,19 @@ pub struct Settings {
     pub jail: Jail,
     pub rescue: Rescue,
     pub world: World,
+    pub movement: Movement,
+    pub bot_detection: BotDetection,
     pub character: Character,
     pub npcs: NPCs,
     pub sln: Sln,
     pub bank: Bank,
     pub limits: Limits,
     pub board: Board,
+    pub reports: Reports,
+    pub bans: Bans,
+    pub audit: Audit,
+    pub economy: Economy,
+    pub quests: Quests,
     pub chest: Chest,
     pub map: Map,
     pub combat: Combat,
@@ -295,6 +457,11 @@ pub struct Settings {
     pub guild: Guild,
     pub marriage: Marriage,
     pub evacuate: Evacuate,
+    pub shutdown: Shutdown,
+    pub maintenance: Maintenance,
+    pub karma: Karma,
+    pub guards: Guards,
+    pub clock: Clock,
     pub items: Items,
     pub bard: Bard,
     pub smtp: Smtp,
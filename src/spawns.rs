@@ -0,0 +1,163 @@
+use config::{Config, ConfigError, File};
+use eolib::protocol::{Coords, map::MapNpc};
+
+use crate::{
+    SETTINGS,
+    clock::{TimeOfDay, get_time_of_day, is_hour_between},
+};
+
+/// An npc spawn from either a map's EMF file or its spawn overlay
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Spawn {
+    #[serde(rename = "npc_id")]
+    pub id: i32,
+    pub coords: Coords,
+    #[serde(default = "default_amount")]
+    pub amount: i32,
+    /// Movement speed, the same as a map spawn's spawn type (0-7)
+    #[serde(rename = "speed", default = "default_speed")]
+    pub spawn_type: i32,
+    /// Seconds before a killed npc respawns
+    #[serde(default)]
+    pub spawn_time: i32,
+    /// Waypoints walked in a loop while the npc isn't chasing anyone
+    #[serde(default)]
+    pub patrol: Vec<Coords>,
+    /// In-game hour (0-23) the spawn appears at
+    #[serde(default)]
+    pub start_hour: Option<u32>,
+    /// In-game hour (0-23) the spawn disappears at
+    #[serde(default)]
+    pub end_hour: Option<u32>,
+    /// Only spawn during the day or night
+    #[serde(default)]
+    pub time_of_day: Option<TimeOfDay>,
+    /// World event that has to be running for the spawn to appear
+    #[serde(default)]
+    pub event: Option<String>,
+    /// Seconds each respawn is randomly shortened or lengthened by
+    #[serde(default)]
+    pub respawn_variance: Option<i32>,
+    /// Tiles around coords the npc can appear at
+    #[serde(default)]
+    pub spawn_radius: Option<i32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SpawnOverlay {
+    pub spawns: Vec<Spawn>,
+}
+
+impl SpawnOverlay {
+    pub fn load(map_id: i32) -> Result<Self, ConfigError> {
+        let s = Config::builder()
+            .add_source(
+                File::with_name(&format!("config/spawns/{:0>5}.ron", map_id)).required(false),
+            )
+            .set_default("spawns", Vec::<String>::new())?
+            .build()?;
+
+        let mut s: SpawnOverlay = s.try_deserialize()?;
+
+        for spawn in s.spawns.iter_mut() {
+            spawn.spawn_type = spawn.spawn_type.clamp(0, 7);
+        }
+
+        Ok(s)
+    }
+}
+
+impl Spawn {
+    pub fn is_active(&self, hour: u32, events: &[String]) -> bool {
+        if let Some(event) = self.event.as_ref()
+            && !events.iter().any(|e| e.eq_ignore_ascii_case(event))
+        {
+            return false;
+        }
+
+        if let Some(time_of_day) = self.time_of_day
+            && get_time_of_day(hour) != time_of_day
+        {
+            return false;
+        }
+
+        match (self.start_hour, self.end_hour) {
+            (Some(start), Some(end)) => is_hour_between(hour, start, end),
+            (Some(start), None) => hour >= start,
+            (None, Some(end)) => hour < end,
+            (None, None) => true,
+        }
+    }
+
+    /// Falls back to the rare spawn settings for npcs listed as rare
+    pub fn get_respawn_variance(&self) -> i32 {
+        match self.respawn_variance {
+            Some(variance) => variance,
+            None if self.is_rare() => SETTINGS.npcs.rare_respawn_variance,
+            None => 0,
+        }
+    }
+
+    pub fn get_spawn_radius(&self) -> i32 {
+        match self.spawn_radius {
+            Some(radius) => radius,
+            None if self.is_rare() => SETTINGS.npcs.rare_spawn_radius,
+            None => 2,
+        }
+    }
+
+    fn is_rare(&self) -> bool {
+        SETTINGS.npcs.rare_npcs.contains(&self.id)
+    }
+}
+
+impl From<&MapNpc> for Spawn {
+    fn from(npc: &MapNpc) -> Self {
+        Self {
+            id: npc.id,
+            coords: npc.coords,
+            amount: npc.amount,
+            spawn_type: npc.spawn_type,
+            spawn_time: npc.spawn_time,
+            ..Default::default()
+        }
+    }
+}
+
+fn default_amount() -> i32 {
+    1
+}
+
+fn default_speed() -> i32 {
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Spawn;
+
+    #[test]
+    fn spawn_schedule_wraps_past_midnight() {
+        let spawn = Spawn {
+            start_hour: Some(20),
+            end_hour: Some(6),
+            ..Default::default()
+        };
+
+        assert!(spawn.is_active(20, &[]));
+        assert!(spawn.is_active(3, &[]));
+        assert!(!spawn.is_active(6, &[]));
+        assert!(!spawn.is_active(12, &[]));
+    }
+
+    #[test]
+    fn event_spawn_waits_for_event() {
+        let spawn = Spawn {
+            event: Some("Halloween".to_string()),
+            ..Default::default()
+        };
+
+        assert!(!spawn.is_active(12, &[]));
+        assert!(spawn.is_active(12, &["halloween".to_string()]));
+    }
+}
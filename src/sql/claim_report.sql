@@ -0,0 +1,5 @@
+UPDATE `reports`
+SET `status` = :status,
+    `assignee` = :assignee
+WHERE `id` = :report_id
+AND `status` <> :resolved_status;
@@ -0,0 +1,21 @@
+INSERT INTO `audit_log` (
+  `character_id`,
+  `admin_name`,
+  `admin_level`,
+  `command`,
+  `target`,
+  `args`,
+  `map`,
+  `x`,
+  `y`
+) VALUES (
+  :character_id,
+  :admin_name,
+  :admin_level,
+  :command,
+  :target,
+  :args,
+  :map,
+  :x,
+  :y
+);
@@ -1,2 +1,2 @@
-INSERT INTO `bans` (account_id, ip, duration)
-VALUES (:account_id, :ip, :duration);
+INSERT INTO `bans` (account_id, ip, hdid, duration, reason, banned_by)
+VALUES (:account_id, :ip, :hdid, :duration, :reason, :banned_by);
@@ -0,0 +1,11 @@
+INSERT INTO `boss_kills` (
+  `npc_id`,
+  `map`,
+  `killer_character_id`,
+  `duration`
+) VALUES (
+  :npc_id,
+  :map,
+  :killer_character_id,
+  :duration
+);
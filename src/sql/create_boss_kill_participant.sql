@@ -0,0 +1,9 @@
+INSERT INTO `boss_kill_participants` (
+  `boss_kill_id`,
+  `character_id`,
+  `damage_dealt`
+) VALUES (
+  :boss_kill_id,
+  :character_id,
+  :damage_dealt
+);
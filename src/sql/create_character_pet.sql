@@ -0,0 +1,2 @@
+INSERT INTO `character_pets` (`character_id`, `npc_id`, `experience`, `summoned`)
+VALUES (:character_id, :npc_id, :experience, :summoned);
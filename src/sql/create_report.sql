@@ -0,0 +1,28 @@
+INSERT INTO `reports` (
+    `reporter_id`,
+    `reporter_name`,
+    `reportee_name`,
+    `message`,
+    `reporter_map`,
+    `reporter_x`,
+    `reporter_y`,
+    `reporter_chat`,
+    `reportee_map`,
+    `reportee_x`,
+    `reportee_y`,
+    `reportee_chat`
+)
+VALUES (
+    :reporter_id,
+    :reporter_name,
+    :reportee_name,
+    :message,
+    :reporter_map,
+    :reporter_x,
+    :reporter_y,
+    :reporter_chat,
+    :reportee_map,
+    :reportee_x,
+    :reportee_y,
+    :reportee_chat
+);
@@ -0,0 +1,2 @@
+DELETE FROM `bans`
+WHERE `id` = :ban_id;
@@ -0,0 +1,6 @@
+DELETE FROM `bans`
+WHERE `account_id` = (
+  SELECT `account_id`
+  FROM `characters`
+  WHERE `name` = :character_name
+);
@@ -0,0 +1,6 @@
+SELECT a.`name`, IFNULL(MAX(c.`admin_level`), 0) 'admin_level'
+FROM `accounts` a
+LEFT JOIN `characters` c
+  ON c.`account_id` = a.`id`
+WHERE a.`id` = :account_id
+GROUP BY a.`name`
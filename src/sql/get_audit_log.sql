@@ -0,0 +1,7 @@
+SELECT `id`, `admin_name`, `command`, `args`, `map`, `x`, `y`, `created_at`
+FROM `audit_log`
+WHERE :name = ''
+  OR `admin_name` = :name
+  OR `target` = :name
+ORDER BY `id` DESC
+LIMIT :limit
@@ -0,0 +1,5 @@
+SELECT b.`id`, a.`name`, b.`ip`, b.`hdid`, IFNULL(b.`duration`, 0) 'duration', b.`reason`, b.`banned_by`, b.`created_at`
+FROM `bans` b
+LEFT JOIN `accounts` a
+  ON a.id = b.account_id
+ORDER BY b.`id` DESC
@@ -50,7 +50,8 @@ SELECT c.account_id,
        c.sitting,
        c.hidden,
        g.name,
-       g.tag
+       g.tag,
+       c.pk_ticks
 FROM `characters` c
 LEFT JOIN `guilds` g
 	ON g.id = c.guild_id
@@ -1,4 +1,4 @@
-SELECT a.id, h.ip
+SELECT a.id, h.ip, CAST(a.hdid AS CHAR) 'hdid'
 FROM `characters` c
 INNER JOIN `accounts` a
 	ON a.id = c.account_id
@@ -0,0 +1,8 @@
+SELECT b.`id`, a.`name`, b.`ip`, b.`hdid`, IFNULL(b.`duration`, 0) 'duration', b.`reason`, b.`banned_by`, b.`created_at`
+FROM `bans` b
+INNER JOIN `accounts` a
+  ON a.id = b.account_id
+INNER JOIN `characters` c
+  ON c.account_id = a.id
+  AND c.name = :character_name
+ORDER BY b.`id` DESC
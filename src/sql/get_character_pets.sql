@@ -0,0 +1,3 @@
+SELECT `npc_id`, `experience`, `summoned`
+FROM `character_pets`
+WHERE `character_id` = :character_id;
@@ -0,0 +1,3 @@
+SELECT
+  CAST((SELECT IFNULL(SUM(`gold_bank`), 0) FROM `characters`) AS CHAR) 'gold_bank',
+  CAST((SELECT IFNULL(SUM(`bank`), 0) FROM `guilds`) AS CHAR) 'guild_gold'
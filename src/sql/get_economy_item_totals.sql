@@ -0,0 +1,37 @@
+SELECT `item_id`, CAST(SUM(`quantity`) AS CHAR) 'quantity'
+FROM (
+  SELECT `item_id`, `quantity` FROM `character_inventory`
+  UNION ALL
+  SELECT `item_id`, `quantity` FROM `character_bank`
+  UNION ALL
+  SELECT `boots`, 1 FROM `characters` WHERE `boots` > 0
+  UNION ALL
+  SELECT `accessory`, 1 FROM `characters` WHERE `accessory` > 0
+  UNION ALL
+  SELECT `gloves`, 1 FROM `characters` WHERE `gloves` > 0
+  UNION ALL
+  SELECT `belt`, 1 FROM `characters` WHERE `belt` > 0
+  UNION ALL
+  SELECT `armor`, 1 FROM `characters` WHERE `armor` > 0
+  UNION ALL
+  SELECT `necklace`, 1 FROM `characters` WHERE `necklace` > 0
+  UNION ALL
+  SELECT `hat`, 1 FROM `characters` WHERE `hat` > 0
+  UNION ALL
+  SELECT `shield`, 1 FROM `characters` WHERE `shield` > 0
+  UNION ALL
+  SELECT `weapon`, 1 FROM `characters` WHERE `weapon` > 0
+  UNION ALL
+  SELECT `ring`, 1 FROM `characters` WHERE `ring` > 0
+  UNION ALL
+  SELECT `ring2`, 1 FROM `characters` WHERE `ring2` > 0
+  UNION ALL
+  SELECT `armlet`, 1 FROM `characters` WHERE `armlet` > 0
+  UNION ALL
+  SELECT `armlet2`, 1 FROM `characters` WHERE `armlet2` > 0
+  UNION ALL
+  SELECT `bracer`, 1 FROM `characters` WHERE `bracer` > 0
+  UNION ALL
+  SELECT `bracer2`, 1 FROM `characters` WHERE `bracer2` > 0
+) items
+GROUP BY `item_id`
@@ -1,4 +1,4 @@
 SELECT IFNULL(`duration`, 0) 'duration', `created_at`
 FROM `bans`
-WHERE `ip` = :ip
+WHERE `hdid` = :hdid
 ORDER BY `created_at` DESC
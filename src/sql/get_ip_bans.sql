@@ -0,0 +1,3 @@
+SELECT `ip`, IFNULL(`duration`, 0) 'duration', `created_at`
+FROM `bans`
+WHERE `ip` IS NOT NULL
@@ -0,0 +1,6 @@
+SELECT `character_quest_progress`.`character_id`,
+       `characters`.`name`,
+       `character_quest_progress`.`quest_id`,
+       `character_quest_progress`.`state`
+FROM `character_quest_progress`
+INNER JOIN `characters` ON `characters`.`id` = `character_quest_progress`.`character_id`;
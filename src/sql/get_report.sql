@@ -0,0 +1,18 @@
+SELECT `id`,
+       `status`,
+       `reporter_name`,
+       `reportee_name`,
+       `assignee`,
+       `message`,
+       `resolution`,
+       `reporter_map`,
+       `reporter_x`,
+       `reporter_y`,
+       `reporter_chat`,
+       `reportee_map`,
+       `reportee_x`,
+       `reportee_y`,
+       `reportee_chat`,
+       `created_at`
+FROM `reports`
+WHERE `id` = :report_id;
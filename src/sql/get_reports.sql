@@ -0,0 +1,4 @@
+SELECT `id`, `status`, `reporter_name`, `reportee_name`, `assignee`, `message`
+FROM `reports`
+WHERE `status` < :status
+ORDER BY `id` ASC LIMIT :limit;
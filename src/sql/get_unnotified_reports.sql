@@ -0,0 +1,7 @@
+SELECT r.`id`, r.`reportee_name`, r.`resolution`
+FROM `reports` r
+INNER JOIN `characters` c
+    ON c.`id` = r.`reporter_id`
+WHERE c.`name` = :name
+AND r.`status` = :status
+AND r.`reporter_notified` = 0;
@@ -0,0 +1,4 @@
+UPDATE `reports`
+SET `reporter_notified` = 1
+WHERE `reporter_id` = (SELECT `id` FROM `characters` WHERE `name` = :name)
+AND `status` = :status;
@@ -0,0 +1,8 @@
+UPDATE `reports`
+SET `status` = :status,
+    `assignee` = COALESCE(`assignee`, :assignee),
+    `resolution` = :resolution,
+    `reporter_notified` = :reporter_notified,
+    `resolved_at` = CURRENT_TIMESTAMP
+WHERE `id` = :report_id
+AND `status` <> :status;
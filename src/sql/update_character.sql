@@ -48,5 +48,6 @@ SET `title` = :title,
     `stat_points` = :stat_points,
     `skill_points` = :skill_points,
     `karma` = :karma,
-    `usage` = :usage
+    `usage` = :usage,
+    `pk_ticks` = :pk_ticks
 WHERE `id` = :character_id;
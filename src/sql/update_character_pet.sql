@@ -0,0 +1,4 @@
+UPDATE `character_pets`
+SET `experience` = :experience,
+    `summoned` = :summoned
+WHERE `character_id` = :character_id AND `npc_id` = :npc_id;
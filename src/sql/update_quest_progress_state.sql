@@ -0,0 +1,3 @@
+UPDATE `character_quest_progress`
+SET `state` = :state
+WHERE `character_id` = :character_id AND `quest_id` = :quest_id;
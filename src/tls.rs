@@ -0,0 +1,106 @@
+use std::{
+    fs,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+use tokio::time;
+use tokio_rustls::{
+    TlsAcceptor,
+    rustls::{
+        ServerConfig,
+        pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+    },
+};
+
+use crate::SETTINGS;
+
+/// Seconds between checks for a changed certificate
+const WATCH_INTERVAL: u64 = 30;
+
+/// TLS for the websocket listener, with a certificate that can be swapped at runtime
+#[derive(Clone)]
+pub struct Tls {
+    acceptor: Arc<RwLock<TlsAcceptor>>,
+}
+
+impl Tls {
+    pub fn new() -> anyhow::Result<Self> {
+        Ok(Self {
+            acceptor: Arc::new(RwLock::new(load_acceptor()?)),
+        })
+    }
+
+    pub fn acceptor(&self) -> TlsAcceptor {
+        self.acceptor.read().unwrap().clone()
+    }
+
+    /// Reloads the certificate whenever its files change or on SIGHUP
+    pub async fn watch(self) {
+        let mut modified = get_modified();
+        let mut interval = time::interval(Duration::from_secs(WATCH_INTERVAL));
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    let current = get_modified();
+                    if current == modified {
+                        continue;
+                    }
+
+                    modified = current;
+                }
+                _ = hangup() => {}
+            }
+
+            match load_acceptor() {
+                Ok(acceptor) => {
+                    *self.acceptor.write().unwrap() = acceptor;
+                    info!("Reloaded TLS certificate");
+                }
+                Err(e) => error!("Failed to reload TLS certificate: {}", e),
+            }
+        }
+    }
+}
+
+fn load_acceptor() -> anyhow::Result<TlsAcceptor> {
+    let certs =
+        CertificateDer::pem_file_iter(&SETTINGS.server.tls_cert)?.collect::<Result<Vec<_>, _>>()?;
+    let key = PrivateKeyDer::from_pem_file(&SETTINGS.server.tls_key)?;
+
+    let config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)?;
+
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+fn get_modified() -> Option<(SystemTime, SystemTime)> {
+    let cert = fs::metadata(&SETTINGS.server.tls_cert)
+        .ok()?
+        .modified()
+        .ok()?;
+    let key = fs::metadata(&SETTINGS.server.tls_key)
+        .ok()?
+        .modified()
+        .ok()?;
+    Some((cert, key))
+}
+
+#[cfg(unix)]
+async fn hangup() {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    match signal(SignalKind::hangup()) {
+        Ok(mut stream) => {
+            stream.recv().await;
+        }
+        Err(_) => std::future::pending().await,
+    }
+}
+
+#[cfg(windows)]
+async fn hangup() {
+    std::future::pending().await
+}
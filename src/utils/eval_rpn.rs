@@ -0,0 +1,78 @@
+/// Evaluates a space separated reverse polish notation expression like `level 10 >=`.
+/// Words that aren't numbers or operators are resolved with `lookup`.
+pub fn eval_rpn(expression: &str, lookup: impl Fn(&str) -> Option<i32>) -> Option<i64> {
+    let mut stack: Vec<i64> = Vec::new();
+
+    for token in expression.split_whitespace() {
+        if token == "!" {
+            let value = stack.pop()?;
+            stack.push((value == 0) as i64);
+            continue;
+        }
+
+        let value = match token {
+            "+" | "-" | "*" | "/" | "%" | "<" | ">" | "<=" | ">=" | "==" | "=" | "!=" | "&"
+            | "|" | "min" | "max" => {
+                let right = stack.pop()?;
+                let left = stack.pop()?;
+                match token {
+                    "+" => left.checked_add(right)?,
+                    "-" => left.checked_sub(right)?,
+                    "*" => left.checked_mul(right)?,
+                    "/" => left.checked_div(right)?,
+                    "%" => left.checked_rem(right)?,
+                    "<" => (left < right) as i64,
+                    ">" => (left > right) as i64,
+                    "<=" => (left <= right) as i64,
+                    ">=" => (left >= right) as i64,
+                    "==" | "=" => (left == right) as i64,
+                    "!=" => (left != right) as i64,
+                    "&" => (left != 0 && right != 0) as i64,
+                    "|" => (left != 0 || right != 0) as i64,
+                    "min" => left.min(right),
+                    _ => left.max(right),
+                }
+            }
+            _ => match token.parse::<i64>() {
+                Ok(value) => value,
+                Err(_) => lookup(token)? as i64,
+            },
+        };
+
+        stack.push(value);
+    }
+
+    match stack.as_slice() {
+        [value] => Some(*value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::eval_rpn;
+
+    fn lookup(name: &str) -> Option<i32> {
+        match name {
+            "level" => Some(12),
+            "str" => Some(5),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn evaluates_expressions() {
+        assert_eq!(eval_rpn("level 10 >=", lookup), Some(1));
+        assert_eq!(eval_rpn("level str + 20 <", lookup), Some(1));
+        assert_eq!(eval_rpn("level 2 * str max", lookup), Some(24));
+        assert_eq!(eval_rpn("level 10 < !", lookup), Some(1));
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        assert_eq!(eval_rpn("level +", lookup), None);
+        assert_eq!(eval_rpn("level 0 /", lookup), None);
+        assert_eq!(eval_rpn("mana 1 >", lookup), None);
+        assert_eq!(eval_rpn("1 2", lookup), None);
+    }
+}
@@ -0,0 +1,15 @@
+pub fn format_countdown(seconds: i32) -> String {
+    let plural = |amount: i32, unit: &str| {
+        format!("{} {}{}", amount, unit, if amount == 1 { "" } else { "s" })
+    };
+
+    match (seconds / 60, seconds % 60) {
+        (0, seconds) => plural(seconds, "second"),
+        (minutes, 0) => plural(minutes, "minute"),
+        (minutes, seconds) => format!(
+            "{} {}",
+            plural(minutes, "minute"),
+            plural(seconds, "second")
+        ),
+    }
+}
@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use chrono::{NaiveDateTime, Utc};
+
+/// Returns Some(0) for permanent bans, the minutes left on temporary bans,
+/// or None if the ban has expired
+pub fn get_ban_minutes_remaining(duration: i32, created_at: NaiveDateTime) -> Option<i32> {
+    if duration == 0 {
+        return Some(0);
+    }
+
+    let now = Utc::now().naive_utc();
+    let expires_at = created_at + Duration::from_mins(duration as u64);
+    let remaining = (expires_at - now).num_minutes();
+    if remaining <= 0 {
+        return None;
+    }
+
+    Some(remaining as i32)
+}
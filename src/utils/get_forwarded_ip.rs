@@ -0,0 +1,120 @@
+use std::net::{IpAddr, SocketAddr};
+
+use tokio_tungstenite::tungstenite::http::HeaderMap;
+
+/// Finds the client's address in the Forwarded or X-Forwarded-For headers of a
+/// request from a trusted proxy. Hops are read from the closest one back and
+/// the first untrusted address is taken as the client
+pub fn get_forwarded_ip(
+    headers: &HeaderMap,
+    is_trusted: impl Fn(&IpAddr) -> bool,
+) -> Option<IpAddr> {
+    let hops = match join_headers(headers, "forwarded") {
+        Some(forwarded) => forwarded
+            .split(',')
+            .map(|element| {
+                element
+                    .split(';')
+                    .map(str::trim)
+                    .find_map(|pair| {
+                        pair.get(..4)
+                            .filter(|key| key.eq_ignore_ascii_case("for="))
+                            .map(|_| &pair[4..])
+                    })
+                    .and_then(parse_node)
+            })
+            .collect::<Vec<_>>(),
+        None => join_headers(headers, "x-forwarded-for")?
+            .split(',')
+            .map(|hop| parse_node(hop.trim()))
+            .collect(),
+    };
+
+    let mut client = None;
+    for hop in hops.into_iter().rev() {
+        // Anything before an address we can't read can't be trusted
+        let hop = hop?;
+        client = Some(hop);
+
+        if !is_trusted(&hop) {
+            break;
+        }
+    }
+
+    client
+}
+
+fn join_headers(headers: &HeaderMap, name: &str) -> Option<String> {
+    let values = headers
+        .get_all(name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>();
+
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.join(","))
+    }
+}
+
+/// Parses an address that may be quoted, bracketed or include a port
+fn parse_node(node: &str) -> Option<IpAddr> {
+    let node = node.trim_matches('"');
+
+    node.parse::<IpAddr>()
+        .ok()
+        .or_else(|| node.parse::<SocketAddr>().ok().map(|addr| addr.ip()))
+        .or_else(|| {
+            node.strip_prefix('[')
+                .and_then(|node| node.strip_suffix(']'))
+                .and_then(|node| node.parse().ok())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use tokio_tungstenite::tungstenite::http::{HeaderMap, HeaderValue};
+
+    use super::get_forwarded_ip;
+
+    fn is_trusted(ip: &IpAddr) -> bool {
+        ip.to_string().starts_with("10.")
+    }
+
+    #[test]
+    fn reads_x_forwarded_for() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            HeaderValue::from_static("1.1.1.1, 203.0.113.7, 10.0.0.2"),
+        );
+
+        // The client could have sent 1.1.1.1 itself
+        assert_eq!(
+            get_forwarded_ip(&headers, is_trusted),
+            Some("203.0.113.7".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn reads_forwarded() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "forwarded",
+            HeaderValue::from_static(
+                "for=\"[2001:db8:cafe::17]:4711\";proto=https, for=10.0.0.3:1234",
+            ),
+        );
+
+        assert_eq!(
+            get_forwarded_ip(&headers, is_trusted),
+            Some("2001:db8:cafe::17".parse().unwrap())
+        );
+
+        headers.insert("forwarded", HeaderValue::from_static("for=unknown"));
+        assert_eq!(get_forwarded_ip(&headers, is_trusted), None);
+    }
+}
@@ -0,0 +1,58 @@
+use eolib::protocol::Coords;
+
+/// Tiles crossed by a straight line between two tiles, not counting either end
+pub fn get_tiles_between(from: &Coords, to: &Coords) -> Vec<Coords> {
+    let dx = (to.x - from.x).abs();
+    let dy = -(to.y - from.y).abs();
+    let step_x = if from.x < to.x { 1 } else { -1 };
+    let step_y = if from.y < to.y { 1 } else { -1 };
+
+    let mut tiles = Vec::new();
+    let mut error = dx + dy;
+    let mut coords = *from;
+
+    loop {
+        let doubled = error * 2;
+        if doubled >= dy {
+            error += dy;
+            coords.x += step_x;
+        }
+        if doubled <= dx {
+            error += dx;
+            coords.y += step_y;
+        }
+
+        if coords == *to || (coords.x - from.x).abs() > dx || (coords.y - from.y).abs() > -dy {
+            return tiles;
+        }
+
+        tiles.push(coords);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eolib::protocol::Coords;
+
+    use super::get_tiles_between;
+
+    #[test]
+    fn tiles_between() {
+        let at = |x, y| Coords { x, y };
+
+        assert_eq!(get_tiles_between(&at(1, 1), &at(1, 1)), vec![]);
+        assert_eq!(get_tiles_between(&at(1, 1), &at(2, 1)), vec![]);
+        assert_eq!(
+            get_tiles_between(&at(1, 1), &at(4, 1)),
+            vec![at(2, 1), at(3, 1)]
+        );
+        assert_eq!(
+            get_tiles_between(&at(3, 3), &at(0, 0)),
+            vec![at(2, 2), at(1, 1)]
+        );
+        assert_eq!(
+            get_tiles_between(&at(0, 0), &at(4, 2)),
+            vec![at(1, 1), at(2, 1), at(3, 2)]
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{
+    SETTINGS,
+    db::{DbHandle, insert_params},
+};
+
+/// True if the account has an admin character or is on the maintenance allowlist
+pub async fn has_maintenance_access(db: &DbHandle, account_id: i32) -> anyhow::Result<bool> {
+    let row = match db
+        .query_one(&insert_params(
+            include_str!("../sql/get_account_maintenance_access.sql"),
+            &[("account_id", &account_id)],
+        ))
+        .await?
+    {
+        Some(row) => row,
+        None => return Ok(false),
+    };
+
+    let name = row.get_string(0).unwrap_or_default();
+    let admin_level = row.get_int(1).unwrap_or_default();
+
+    Ok(admin_level > 0
+        || SETTINGS
+            .maintenance
+            .allowlist
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(&name)))
+}
@@ -0,0 +1,55 @@
+use std::net::IpAddr;
+
+use ipnet::IpNet;
+
+/// Parses a ban rule which is either a single address or a CIDR range
+pub fn parse_ip_range(range: &str) -> Option<IpNet> {
+    if let Ok(net) = range.parse::<IpNet>() {
+        return Some(net.trunc());
+    }
+
+    range
+        .parse::<IpAddr>()
+        .ok()
+        .map(|ip| IpNet::from(ip.to_canonical()))
+}
+
+pub fn ip_in_range(ip: &str, range: &str) -> bool {
+    let Ok(ip) = ip.parse::<IpAddr>() else {
+        return false;
+    };
+
+    match parse_ip_range(range) {
+        Some(net) => net.contains(&ip.to_canonical()),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ip_in_range, parse_ip_range};
+
+    #[test]
+    fn matches_exact_addresses() {
+        assert!(ip_in_range("10.0.0.5", "10.0.0.5"));
+        assert!(!ip_in_range("10.0.0.6", "10.0.0.5"));
+    }
+
+    #[test]
+    fn matches_cidr_ranges() {
+        assert!(ip_in_range("192.168.1.200", "192.168.1.0/24"));
+        assert!(!ip_in_range("192.168.2.1", "192.168.1.0/24"));
+        assert!(ip_in_range("2001:db8::1", "2001:db8::/32"));
+    }
+
+    #[test]
+    fn matches_ipv4_mapped_addresses() {
+        assert!(ip_in_range("::ffff:10.0.0.5", "10.0.0.0/8"));
+    }
+
+    #[test]
+    fn rejects_invalid_ranges() {
+        assert!(parse_ip_range("not an ip").is_none());
+        assert!(parse_ip_range("10.0.0.0/33").is_none());
+    }
+}
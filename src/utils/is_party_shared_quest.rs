@@ -0,0 +1,5 @@
+use crate::SETTINGS;
+
+pub fn is_party_shared_quest(quest_id: i32) -> bool {
+    SETTINGS.quests.party_share || SETTINGS.quests.party_shared_quests.contains(&quest_id)
+}
@@ -0,0 +1,15 @@
+use std::net::IpAddr;
+
+use crate::SETTINGS;
+
+use super::parse_ip_range;
+
+pub fn is_trusted_proxy(ip: &IpAddr) -> bool {
+    let ip = ip.to_canonical();
+    SETTINGS
+        .server
+        .trusted_proxies
+        .iter()
+        .filter_map(|range| parse_ip_range(range))
+        .any(|range| range.contains(&ip))
+}
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, fs::File, io::Read, path::PathBuf};
+use std::{collections::HashMap, fs::File, io::Read, path::Path};
 
 use eoplus::{Quest, parse_quest};
 use glob::glob;
@@ -8,7 +8,7 @@ pub fn load_quests() -> HashMap<i32, Quest> {
 
     for entry in glob("data/quests/*.eqf").unwrap() {
         match entry {
-            Ok(path) => match load_quest(path) {
+            Ok(path) => match load_quest(&path) {
                 Some((id, quest)) => {
                     let _ = quests.insert(id, quest);
                 }
@@ -24,7 +24,20 @@ pub fn load_quests() -> HashMap<i32, Quest> {
     quests
 }
 
-fn load_quest(path: PathBuf) -> Option<(i32, Quest)> {
+fn load_quest(path: &Path) -> Option<(i32, Quest)> {
+    let (id, input) = read_quest_file(path)?;
+
+    match parse_quest_source(&input) {
+        Ok(quest) => Some((id, quest)),
+        Err(e) => {
+            error!("Failed to parse quest: {}", e);
+            None
+        }
+    }
+}
+
+/// Reads a quest file returning the quest id from its file name and the raw source
+pub fn read_quest_file(path: &Path) -> Option<(i32, String)> {
     let id = match path.file_name() {
         Some(name) => match name.to_str().to_owned() {
             Some(name) => match name.split('.').collect::<Vec<&str>>()[0].parse::<i32>() {
@@ -62,8 +75,10 @@ fn load_quest(path: PathBuf) -> Option<(i32, Quest)> {
         return None;
     }
 
-    let input = String::from_utf8_lossy(&buf);
+    Some((id, String::from_utf8_lossy(&buf).into_owned()))
+}
 
+pub fn parse_quest_source(input: &str) -> Result<Quest, String> {
     // Remove comments
     let input = input
         .lines()
@@ -71,11 +86,5 @@ fn load_quest(path: PathBuf) -> Option<(i32, Quest)> {
         .collect::<Vec<&str>>()
         .join("\n");
 
-    match parse_quest(&input) {
-        Ok(quest) => Some((id, quest)),
-        Err(e) => {
-            error!("Failed to parse quest: {}", e);
-            None
-        }
-    }
+    parse_quest(&input).map_err(|e| e.to_string())
 }
@@ -8,12 +8,18 @@ mod get_guild_ranks;
 pub use get_guild_ranks::get_guild_ranks;
 mod in_range;
 pub use in_range::{get_distance, in_client_range, in_range};
+mod format_countdown;
+pub use format_countdown::format_countdown;
 mod format_duration;
 pub use format_duration::format_duration;
 mod get_board_tile_spec;
 pub use get_board_tile_spec::get_board_tile_spec;
+mod get_ban_minutes_remaining;
+pub use get_ban_minutes_remaining::get_ban_minutes_remaining;
 mod get_next_coords;
 pub use get_next_coords::get_next_coords;
+mod ip_in_range;
+pub use ip_in_range::{ip_in_range, parse_ip_range};
 mod is_deep;
 pub use is_deep::is_deep;
 mod load_class_file;
@@ -37,7 +43,11 @@ pub use load_talk_file::load_talk_file;
 mod save_pub_file;
 pub use save_pub_file::save_pub_file;
 mod load_quests;
-pub use load_quests::load_quests;
+pub use load_quests::{load_quests, parse_quest_source, read_quest_file};
+mod quest_commands;
+pub use quest_commands::{QUEST_ACTIONS, QUEST_RULES};
+mod eval_rpn;
+pub use eval_rpn::eval_rpn;
 mod pad_string;
 pub use pad_string::pad_string;
 mod validate_character_name;
@@ -52,5 +62,19 @@ mod truncate_to_chars;
 pub use truncate_to_chars::truncate_to_chars;
 mod normalize_email;
 pub use normalize_email::normalize_email;
+mod validate_quests;
+pub use validate_quests::validate_quests;
 mod validate_account_name;
 pub use validate_account_name::validate_account_name;
+mod read_proxy_header;
+pub use read_proxy_header::read_proxy_header;
+mod get_forwarded_ip;
+pub use get_forwarded_ip::get_forwarded_ip;
+mod is_trusted_proxy;
+pub use is_trusted_proxy::is_trusted_proxy;
+mod has_maintenance_access;
+pub use has_maintenance_access::has_maintenance_access;
+mod get_tiles_between;
+pub use get_tiles_between::get_tiles_between;
+mod is_party_shared_quest;
+pub use is_party_shared_quest::is_party_shared_quest;
@@ -0,0 +1,76 @@
+/// EO+ actions supported by the quest engine with their minimum and maximum argument counts
+pub const QUEST_ACTIONS: &[(&str, usize, usize)] = &[
+    ("AddNpcChat", 2, 2),
+    ("AddNpcInput", 3, 3),
+    ("AddNpcText", 2, 2),
+    ("EffectOnCoord", 3, 3),
+    ("EffectOnPlayer", 1, 1),
+    ("End", 0, 0),
+    ("GiveExp", 1, 1),
+    ("GiveItem", 1, 2),
+    ("GiveKarma", 1, 1),
+    ("GiveSpell", 1, 1),
+    ("GiveStat", 2, 2),
+    ("PlayMusic", 1, 1),
+    ("PlaySound", 1, 1),
+    ("Quake", 1, 1),
+    ("QuakeWorld", 1, 1),
+    ("RemoveItem", 1, 2),
+    ("RemoveKarma", 1, 1),
+    ("RemoveSpell", 1, 1),
+    ("RemoveStat", 2, 2),
+    ("Reset", 0, 0),
+    ("ResetDaily", 0, 0),
+    ("ResetQuest", 1, 1),
+    ("Roll", 1, 1),
+    ("SetClass", 1, 1),
+    ("SetCoord", 3, 3),
+    ("SetFiance", 1, 1),
+    ("SetHome", 1, 1),
+    ("SetMap", 3, 3),
+    ("SetPartner", 1, 1),
+    ("SetQuestState", 2, 2),
+    ("SetRace", 1, 1),
+    ("SetStat", 2, 2),
+    ("SetState", 1, 1),
+    ("SetTitle", 1, 1),
+    ("ShowHint", 1, 1),
+    ("StartQuest", 1, 2),
+];
+
+/// EO+ rules supported by the quest engine with their minimum and maximum argument counts
+pub const QUEST_RULES: &[(&str, usize, usize)] = &[
+    ("Always", 0, 0),
+    ("CitizenOf", 1, 1),
+    ("DoneDaily", 1, 1),
+    ("EnterCoord", 3, 3),
+    ("EnterMap", 1, 1),
+    ("EquippedItem", 1, 1),
+    ("GotItems", 2, 2),
+    ("GotSpell", 1, 2),
+    ("HourBetween", 2, 2),
+    ("InputNpc", 1, 1),
+    ("IsClass", 1, 1),
+    ("IsDay", 0, 0),
+    ("IsGender", 1, 1),
+    ("IsNight", 0, 0),
+    ("IsRace", 1, 1),
+    ("IsWearing", 1, 1),
+    ("KilledNpcs", 2, 2),
+    ("KilledPlayers", 1, 1),
+    ("LeaveCoord", 3, 3),
+    ("LeaveMap", 1, 1),
+    ("LostItems", 2, 2),
+    ("LostSpell", 1, 1),
+    ("Rolled", 1, 1),
+    ("StatBetween", 3, 3),
+    ("StatGreater", 2, 2),
+    ("StatIs", 2, 2),
+    ("StatLess", 2, 2),
+    ("StatNot", 2, 2),
+    ("StatRpn", 1, 1),
+    ("TalkedToNpc", 1, 1),
+    ("UnequippedItem", 1, 1),
+    ("UsedItem", 2, 2),
+    ("UsedSpell", 2, 2),
+];
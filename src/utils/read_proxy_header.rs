@@ -0,0 +1,132 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+const V2_SIGNATURE: [u8; 12] = [
+    0x0d, 0x0a, 0x0d, 0x0a, 0x00, 0x0d, 0x0a, 0x51, 0x55, 0x49, 0x54, 0x0a,
+];
+
+const V1_MAX_LENGTH: usize = 107;
+
+/// Reads a HAProxy PROXY protocol (v1 or v2) header, returning the client's
+/// address or None for connections made by the proxy itself (health checks)
+pub async fn read_proxy_header<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> anyhow::Result<Option<IpAddr>> {
+    // The shortest v1 header ("PROXY UNKNOWN\r\n") is longer than the v2 signature
+    let mut buf = vec![0; V2_SIGNATURE.len()];
+    reader.read_exact(&mut buf).await?;
+
+    if buf == V2_SIGNATURE {
+        let mut header = [0; 4];
+        reader.read_exact(&mut header).await?;
+
+        let length = u16::from_be_bytes([header[2], header[3]]) as usize;
+        let mut addresses = vec![0; length];
+        reader.read_exact(&mut addresses).await?;
+
+        return parse_proxy_v2(header[0], header[1], &addresses);
+    }
+
+    if !buf.starts_with(b"PROXY ") {
+        return Err(anyhow::anyhow!("Missing PROXY protocol header"));
+    }
+
+    while !buf.ends_with(b"\r\n") {
+        if buf.len() >= V1_MAX_LENGTH {
+            return Err(anyhow::anyhow!("PROXY protocol header is too long"));
+        }
+
+        buf.push(reader.read_u8().await?);
+    }
+
+    parse_proxy_v1(std::str::from_utf8(&buf)?)
+}
+
+fn parse_proxy_v1(header: &str) -> anyhow::Result<Option<IpAddr>> {
+    let mut parts = header.trim_end().split(' ').skip(1);
+
+    match parts.next() {
+        Some("TCP4") | Some("TCP6") => {}
+        Some("UNKNOWN") => return Ok(None),
+        _ => return Err(anyhow::anyhow!("Invalid PROXY protocol header")),
+    }
+
+    match parts.next() {
+        Some(source) => Ok(Some(source.parse()?)),
+        None => Err(anyhow::anyhow!("Invalid PROXY protocol header")),
+    }
+}
+
+fn parse_proxy_v2(
+    version_command: u8,
+    family: u8,
+    addresses: &[u8],
+) -> anyhow::Result<Option<IpAddr>> {
+    if version_command >> 4 != 2 {
+        return Err(anyhow::anyhow!("Unsupported PROXY protocol version"));
+    }
+
+    // LOCAL command
+    if version_command & 0x0f == 0 {
+        return Ok(None);
+    }
+
+    match family >> 4 {
+        0 => Ok(None),
+        1 if addresses.len() >= 12 => Ok(Some(IpAddr::V4(Ipv4Addr::new(
+            addresses[0],
+            addresses[1],
+            addresses[2],
+            addresses[3],
+        )))),
+        2 if addresses.len() >= 36 => {
+            let mut octets = [0; 16];
+            octets.copy_from_slice(&addresses[..16]);
+            Ok(Some(IpAddr::V6(Ipv6Addr::from(octets))))
+        }
+        _ => Err(anyhow::anyhow!("Invalid PROXY protocol address")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use super::{V2_SIGNATURE, read_proxy_header};
+
+    #[tokio::test]
+    async fn reads_v1_headers() {
+        let mut stream: &[u8] = b"PROXY TCP4 203.0.113.7 10.0.0.1 51234 8078\r\n\x01\x02";
+        let ip = read_proxy_header(&mut stream).await.unwrap();
+        assert_eq!(ip, Some("203.0.113.7".parse::<IpAddr>().unwrap()));
+        // Game data after the header is left untouched
+        assert_eq!(stream, b"\x01\x02");
+
+        let mut stream: &[u8] = b"PROXY UNKNOWN\r\n";
+        assert_eq!(read_proxy_header(&mut stream).await.unwrap(), None);
+
+        let mut stream: &[u8] = b"\x05\x00\xff\x01\x02\x03\x04\x05\x06\x07\x08\x09";
+        assert!(read_proxy_header(&mut stream).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn reads_v2_headers() {
+        let mut header = V2_SIGNATURE.to_vec();
+        header.extend_from_slice(&[0x21, 0x11, 0x00, 0x0c]);
+        header.extend_from_slice(&[198, 51, 100, 9, 10, 0, 0, 1, 0xc8, 0x22, 0x1f, 0x8e]);
+        header.push(0x01);
+
+        let mut stream = header.as_slice();
+        let ip = read_proxy_header(&mut stream).await.unwrap();
+        assert_eq!(ip, Some("198.51.100.9".parse::<IpAddr>().unwrap()));
+        assert_eq!(stream, [0x01]);
+
+        let mut local = V2_SIGNATURE.to_vec();
+        local.extend_from_slice(&[0x20, 0x00, 0x00, 0x00]);
+        assert_eq!(
+            read_proxy_header(&mut local.as_slice()).await.unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,441 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use eolib::protocol::r#pub::NpcType;
+use eoplus::{Arg, Quest};
+use glob::glob;
+
+use crate::{CLASS_DB, ITEM_DB, NPC_DB, SPELL_DB, character::Character};
+
+use super::{QUEST_ACTIONS, QUEST_RULES, parse_quest_source, read_quest_file};
+
+enum Reference {
+    Class,
+    Item,
+    Map,
+    Npc,
+    Quest,
+    QuestNpc,
+    Spell,
+    Stat,
+}
+
+/// Checks every quest in data/quests for unknown rules and actions, bad
+/// argument counts, dangling ids and state names, and unreachable states.
+/// Each issue is formatted as `file:line: message`.
+pub fn validate_quests() -> Vec<String> {
+    let mut issues = Vec::new();
+    let mut quests: Vec<(String, String, i32, Quest)> = Vec::new();
+
+    for entry in glob("data/quests/*.eqf").unwrap() {
+        let path = match entry {
+            Ok(path) => path,
+            Err(e) => {
+                issues.push(format!("{}: failed to read entry", e.path().display()));
+                continue;
+            }
+        };
+
+        let file = path.display().to_string();
+        let (id, source) = match read_quest_file(&path) {
+            Some(quest) => quest,
+            None => {
+                issues.push(format!("{}: failed to read quest file", file));
+                continue;
+            }
+        };
+
+        match parse_quest_source(&source) {
+            Ok(quest) => quests.push((file, source, id, quest)),
+            Err(e) => issues.push(format!("{}: failed to parse quest: {}", file, e)),
+        }
+    }
+
+    let map_ids = get_map_ids();
+    let quest_states: HashMap<i32, &Quest> = quests
+        .iter()
+        .map(|(_, _, id, quest)| (*id, quest))
+        .collect();
+
+    // States entered from other quests with StartQuest or SetQuestState
+    let mut external_states: HashSet<(i32, String)> = HashSet::new();
+    for (_, _, id, quest) in &quests {
+        for action in quest.states.iter().flat_map(|state| state.actions.iter()) {
+            if !matches!(action.name.as_str(), "StartQuest" | "SetQuestState") {
+                continue;
+            }
+
+            if let (Some(Arg::Int(quest_id)), Some(Arg::Str(state))) =
+                (action.args.first(), action.args.get(1))
+                && quest_id != id
+            {
+                external_states.insert((*quest_id, state.to_lowercase()));
+            }
+        }
+    }
+
+    for (file, source, id, quest) in &quests {
+        let lines = SourceLines::new(source);
+        let mut report = |line: usize, message: String| {
+            issues.push(format!("{}:{}: {}", file, line, message));
+        };
+
+        let inputs: HashSet<i32> = quest
+            .states
+            .iter()
+            .flat_map(|state| state.actions.iter())
+            .filter(|action| action.name == "AddNpcInput")
+            .filter_map(|action| match action.args.get(1) {
+                Some(Arg::Int(input_id)) => Some(*input_id),
+                _ => None,
+            })
+            .collect();
+
+        if quest.states.is_empty() {
+            report(1, "quest has no states".to_owned());
+            continue;
+        }
+
+        let mut reachable = HashSet::from([0]);
+        let mut pending = vec![0];
+        for (index, state) in quest.states.iter().enumerate() {
+            if external_states.contains(&(*id, state.name.to_lowercase()))
+                && reachable.insert(index)
+            {
+                pending.push(index);
+            }
+        }
+
+        while let Some(index) = pending.pop() {
+            let state = &quest.states[index];
+            let targets = state.rules.iter().map(|rule| rule.goto.as_str()).chain(
+                state.actions.iter().filter_map(|action| {
+                    match (
+                        action.name.as_str(),
+                        action.args.first(),
+                        action.args.get(1),
+                    ) {
+                        ("SetState", Some(Arg::Str(target)), _) => Some(target.as_str()),
+                        ("SetQuestState", Some(Arg::Int(quest_id)), Some(Arg::Str(target)))
+                            if quest_id == id =>
+                        {
+                            Some(target.as_str())
+                        }
+                        _ => None,
+                    }
+                }),
+            );
+
+            for target in targets {
+                if let Some(next) = get_state_index(quest, target)
+                    && reachable.insert(next)
+                {
+                    pending.push(next);
+                }
+            }
+        }
+
+        for (state_index, state) in quest.states.iter().enumerate() {
+            let state_lines = lines.get_state(state_index, &state.name);
+            let state_line = state_lines.map(|s| s.line).unwrap_or(1);
+
+            if !reachable.contains(&state_index) {
+                report(state_line, format!("state {} is unreachable", state.name));
+            }
+
+            for (index, action) in state.actions.iter().enumerate() {
+                let line = state_lines
+                    .and_then(|s| s.actions.get(index).copied())
+                    .unwrap_or(state_line);
+
+                let (min_args, max_args) = match QUEST_ACTIONS
+                    .iter()
+                    .find(|(name, _, _)| *name == action.name)
+                {
+                    Some((_, min_args, max_args)) => (*min_args, *max_args),
+                    None => {
+                        report(line, format!("unknown action {}", action.name));
+                        continue;
+                    }
+                };
+
+                if let Some(message) =
+                    check_arg_count(&action.name, &action.args, min_args, max_args)
+                {
+                    report(line, message);
+                }
+
+                for message in check_references(&action.name, &action.args, &map_ids, &quest_states)
+                {
+                    report(line, message);
+                }
+
+                let state_target = match (action.name.as_str(), action.args.first()) {
+                    ("SetState", Some(Arg::Str(target))) => Some((*id, target)),
+                    ("StartQuest" | "SetQuestState", Some(Arg::Int(quest_id))) => {
+                        match action.args.get(1) {
+                            Some(Arg::Str(target)) => Some((*quest_id, target)),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+
+                if let Some((quest_id, target)) = state_target
+                    && let Some(target_quest) = quest_states.get(&quest_id)
+                    && get_state_index(target_quest, target).is_none()
+                {
+                    report(
+                        line,
+                        format!(
+                            "{} references unknown state {} in quest {}",
+                            action.name, target, quest_id
+                        ),
+                    );
+                }
+            }
+
+            for (index, rule) in state.rules.iter().enumerate() {
+                let line = state_lines
+                    .and_then(|s| s.rules.get(index).copied())
+                    .unwrap_or(state_line);
+
+                match QUEST_RULES.iter().find(|(name, _, _)| *name == rule.name) {
+                    Some((_, min_args, max_args)) => {
+                        if let Some(message) =
+                            check_arg_count(&rule.name, &rule.args, *min_args, *max_args)
+                        {
+                            report(line, message);
+                        }
+
+                        for message in
+                            check_references(&rule.name, &rule.args, &map_ids, &quest_states)
+                        {
+                            report(line, message);
+                        }
+                    }
+                    None => report(line, format!("unknown rule {}", rule.name)),
+                }
+
+                if rule.name == "InputNpc"
+                    && let Some(Arg::Int(input_id)) = rule.args.first()
+                    && !inputs.contains(input_id)
+                {
+                    report(
+                        line,
+                        format!("InputNpc references input {} with no AddNpcInput", input_id),
+                    );
+                }
+
+                if get_state_index(quest, &rule.goto).is_none() {
+                    report(
+                        line,
+                        format!("{} goes to unknown state {}", rule.name, rule.goto),
+                    );
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+fn check_arg_count(name: &str, args: &[Arg], min_args: usize, max_args: usize) -> Option<String> {
+    if args.len() < min_args || args.len() > max_args {
+        Some(if min_args == max_args {
+            format!(
+                "{} expects {} arguments but got {}",
+                name,
+                min_args,
+                args.len()
+            )
+        } else {
+            format!(
+                "{} expects {} to {} arguments but got {}",
+                name,
+                min_args,
+                max_args,
+                args.len()
+            )
+        })
+    } else {
+        None
+    }
+}
+
+fn check_references(
+    name: &str,
+    args: &[Arg],
+    map_ids: &HashSet<i32>,
+    quests: &HashMap<i32, &Quest>,
+) -> Vec<String> {
+    let reference = match name {
+        "AddNpcText" | "AddNpcInput" | "AddNpcChat" | "TalkedToNpc" => Reference::QuestNpc,
+        "KilledNpcs" => Reference::Npc,
+        "GiveItem" | "RemoveItem" | "GotItems" | "LostItems" | "UsedItem" | "IsWearing"
+        | "EquippedItem" | "UnequippedItem" => Reference::Item,
+        "SetMap" | "SetCoord" | "EnterMap" | "EnterCoord" | "LeaveMap" | "LeaveCoord" => {
+            Reference::Map
+        }
+        "GiveSpell" | "RemoveSpell" | "GotSpell" | "LostSpell" | "UsedSpell" => Reference::Spell,
+        "SetClass" | "IsClass" => Reference::Class,
+        "StartQuest" | "ResetQuest" | "SetQuestState" => Reference::Quest,
+        "GiveStat" | "RemoveStat" | "SetStat" | "StatIs" | "StatNot" | "StatGreater"
+        | "StatLess" | "StatBetween" => Reference::Stat,
+        _ => return Vec::new(),
+    };
+
+    let arg = match args.first() {
+        Some(arg) => arg,
+        None => return Vec::new(),
+    };
+
+    if let Reference::Stat = reference {
+        let stat = match arg {
+            Arg::Str(stat) => stat,
+            Arg::Int(stat) => {
+                return vec![format!("{} expects a stat name but got {}", name, stat)];
+            }
+        };
+
+        return if Character::default().get_quest_stat(stat).is_none() {
+            vec![format!("{} references unknown stat {}", name, stat)]
+        } else {
+            Vec::new()
+        };
+    }
+
+    let id = match arg {
+        Arg::Int(id) => *id,
+        Arg::Str(value) => return vec![format!("{} expects an id but got \"{}\"", name, value)],
+    };
+
+    let (kind, exists) = match reference {
+        Reference::Class => ("class", id > 0 && id as usize <= CLASS_DB.classes.len()),
+        Reference::Item => ("item", id > 0 && id as usize <= ITEM_DB.items.len()),
+        Reference::Map => ("map", map_ids.contains(&id)),
+        Reference::Npc => ("npc", id > 0 && id as usize <= NPC_DB.npcs.len()),
+        Reference::Quest => ("quest", quests.contains_key(&id)),
+        Reference::QuestNpc => (
+            "quest npc",
+            NPC_DB
+                .npcs
+                .iter()
+                .any(|npc| npc.r#type == NpcType::Quest && npc.behavior_id == id),
+        ),
+        Reference::Spell => ("spell", id > 0 && id as usize <= SPELL_DB.skills.len()),
+        Reference::Stat => unreachable!(),
+    };
+
+    if exists {
+        Vec::new()
+    } else {
+        vec![format!("{} references unknown {} {}", name, kind, id)]
+    }
+}
+
+fn get_map_ids() -> HashSet<i32> {
+    glob("data/maps/*.emf")
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|path| {
+            Path::new(&path)
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse::<i32>().ok())
+        })
+        .collect()
+}
+
+fn get_state_index(quest: &Quest, name: &str) -> Option<usize> {
+    quest
+        .states
+        .iter()
+        .position(|state| state.name.eq_ignore_ascii_case(name))
+}
+
+/// Line numbers of states, actions and rules since the parser doesn't keep them
+struct SourceLines {
+    states: Vec<StateLines>,
+}
+
+struct StateLines {
+    name: String,
+    line: usize,
+    actions: Vec<usize>,
+    rules: Vec<usize>,
+}
+
+impl SourceLines {
+    fn new(source: &str) -> Self {
+        let mut states: Vec<StateLines> = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            let lowercase = line.to_lowercase();
+
+            if let Some(rest) = strip_keyword(line, &lowercase, "state") {
+                states.push(StateLines {
+                    name: rest
+                        .chars()
+                        .take_while(|c| c.is_alphanumeric() || *c == '_')
+                        .collect(),
+                    line: line_number,
+                    actions: Vec::new(),
+                    rules: Vec::new(),
+                });
+            } else if let Some(state) = states.last_mut() {
+                if strip_keyword(line, &lowercase, "action").is_some() {
+                    state.actions.push(line_number);
+                } else if strip_keyword(line, &lowercase, "rule").is_some() {
+                    state.rules.push(line_number);
+                }
+            }
+        }
+
+        Self { states }
+    }
+
+    fn get_state(&self, index: usize, name: &str) -> Option<&StateLines> {
+        match self.states.get(index) {
+            Some(state) if state.name.eq_ignore_ascii_case(name) => Some(state),
+            _ => self
+                .states
+                .iter()
+                .find(|state| state.name.eq_ignore_ascii_case(name)),
+        }
+    }
+}
+
+fn strip_keyword<'a>(line: &'a str, lowercase: &str, keyword: &str) -> Option<&'a str> {
+    if lowercase.starts_with(keyword)
+        && line[keyword.len()..].starts_with(|c: char| c.is_whitespace())
+    {
+        Some(line[keyword.len()..].trim_start())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SourceLines;
+
+    #[test]
+    fn finds_statement_lines() {
+        let source = "Main\n{\n    questname \"Test\"\n}\n\nstate Begin\n{\n    // action Ignored();\n    action AddNpcText(1, \"Hi\");\n\n    rule TalkedToNpc(1) goto Next\n}\nState Next {\n    action End();\n}\n";
+        let lines = SourceLines::new(source);
+
+        let begin = lines.get_state(0, "begin").unwrap();
+        assert_eq!(begin.line, 6);
+        assert_eq!(begin.actions, vec![9]);
+        assert_eq!(begin.rules, vec![11]);
+
+        let next = lines.get_state(1, "Next").unwrap();
+        assert_eq!(next.line, 13);
+        assert_eq!(next.actions, vec![14]);
+    }
+}
@@ -0,0 +1,137 @@
+use config::{Config, ConfigError, File};
+use rand::RngExt;
+
+use crate::clock::is_hour_between;
+
+#[derive(Debug, Deserialize)]
+pub struct Weather {
+    pub name: String,
+    /// Sent to everyone on the map when the weather begins
+    #[serde(default)]
+    pub message: String,
+    /// Played to everyone on the map when the weather begins, 0 for none
+    #[serde(default)]
+    pub sfx_id: i32,
+    /// Effect played on every player each time the weather strikes, 0 for none
+    #[serde(default)]
+    pub effect_id: i32,
+    /// Seconds between strikes, 0 for a weather with no periodic effects
+    #[serde(default)]
+    pub effect_seconds: i32,
+    /// Fraction of max HP drained on each strike, never below 1 HP
+    #[serde(default)]
+    pub hp_drain: f32,
+    /// Fraction of max TP drained on each strike
+    #[serde(default)]
+    pub tp_drain: f32,
+    /// Percent chance each step is blocked by the weather
+    #[serde(default)]
+    pub stumble_chance: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WeatherChance {
+    /// Weather name, anything that isn't a configured weather is clear skies
+    pub weather: String,
+    pub weight: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScheduledWeather {
+    pub weather: String,
+    /// In-game hour (0-23) the weather begins at
+    pub start_hour: u32,
+    /// In-game hour (0-23) the weather ends at, wrapping past midnight
+    pub end_hour: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WeatherRegion {
+    pub name: String,
+    pub maps: Vec<i32>,
+    #[serde(default)]
+    pub rotation: Vec<WeatherChance>,
+    #[serde(default = "default_min_seconds")]
+    pub min_seconds: i32,
+    #[serde(default = "default_max_seconds")]
+    pub max_seconds: i32,
+    /// Takes over from the rotation while the in-game hour is inside a window
+    #[serde(default)]
+    pub schedule: Vec<ScheduledWeather>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Weathers {
+    pub weathers: Vec<Weather>,
+    pub regions: Vec<WeatherRegion>,
+}
+
+impl Weathers {
+    pub fn new() -> Result<Self, ConfigError> {
+        let s = Config::builder()
+            .add_source(File::with_name("config/Weather.ron").required(false))
+            .add_source(File::with_name("config/Weather.local.ron").required(false))
+            .set_default("weathers", Vec::<String>::new())?
+            .set_default("regions", Vec::<String>::new())?
+            .build()?;
+
+        s.try_deserialize()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Weather> {
+        self.weathers
+            .iter()
+            .find(|weather| weather.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn get_region_index(&self, map_id: i32) -> Option<usize> {
+        self.regions
+            .iter()
+            .position(|region| region.maps.contains(&map_id))
+    }
+}
+
+impl WeatherRegion {
+    pub fn get_scheduled(&self, hour: u32) -> Option<&str> {
+        self.schedule
+            .iter()
+            .find(|scheduled| is_hour_between(hour, scheduled.start_hour, scheduled.end_hour))
+            .map(|scheduled| scheduled.weather.as_str())
+    }
+
+    /// Rolls the next weather from the rotation along with how long it lasts
+    pub fn roll(&self) -> (Option<&str>, i32) {
+        let mut rng = rand::rng();
+        let min_seconds = self.min_seconds.max(1);
+        let seconds = rng.random_range(min_seconds..=self.max_seconds.max(min_seconds));
+
+        let total = self
+            .rotation
+            .iter()
+            .map(|chance| chance.weight.max(0))
+            .sum::<i32>();
+
+        if total == 0 {
+            return (None, seconds);
+        }
+
+        let mut roll = rng.random_range(0..total);
+        for chance in &self.rotation {
+            let weight = chance.weight.max(0);
+            if roll < weight {
+                return (Some(chance.weather.as_str()), seconds);
+            }
+            roll -= weight;
+        }
+
+        (None, seconds)
+    }
+}
+
+fn default_min_seconds() -> i32 {
+    300
+}
+
+fn default_max_seconds() -> i32 {
+    900
+}
@@ -1,8 +1,12 @@
-use chrono::{DateTime, Utc};
 use eolib::protocol::net::{PartyRequestType, server::PartyExpShare};
 use tokio::sync::oneshot;
 
-use crate::{character::Character, map::MapHandle, player::PlayerHandle};
+use crate::{
+    character::Character,
+    connection_log::ConnectionRejection,
+    map::{MapHandle, QuestUpdate},
+    player::PlayerHandle,
+};
 
 use super::{Party, WorldHandle};
 
@@ -24,7 +28,10 @@ pub enum Command {
     },
     AddConnection {
         ip: String,
-        respond_to: oneshot::Sender<()>,
+        respond_to: oneshot::Sender<Result<(), ConnectionRejection>>,
+    },
+    RemoveConnection {
+        ip: String,
     },
     AddLoggedInAccount {
         account_id: i32,
@@ -40,10 +47,18 @@ pub enum Command {
         player_id: i32,
         player: PlayerHandle,
     },
+    BanIp {
+        player_id: i32,
+        admin_name: String,
+        range: String,
+        duration: String,
+        reason: String,
+    },
     BanPlayer {
         victim_name: String,
         admin_name: String,
         duration: String,
+        reason: String,
         silent: bool,
     },
     BroadcastAdminMessage {
@@ -69,6 +84,18 @@ pub enum Command {
         name: String,
         message: String,
     },
+    BroadcastServerMessage {
+        message: String,
+    },
+    CancelShutdown {
+        player_id: Option<i32>,
+        admin_name: String,
+    },
+    ClaimReport {
+        player_id: i32,
+        admin_name: String,
+        report_id: i32,
+    },
     DisbandGuild {
         guild_tag: String,
     },
@@ -99,14 +126,6 @@ pub enum Command {
     GetConnectionCount {
         respond_to: oneshot::Sender<i32>,
     },
-    GetIpConnectionCount {
-        ip: String,
-        respond_to: oneshot::Sender<i32>,
-    },
-    GetIpLastConnect {
-        ip: String,
-        respond_to: oneshot::Sender<Option<DateTime<Utc>>>,
-    },
     GetMap {
         map_id: i32,
         respond_to: oneshot::Sender<Result<MapHandle, Box<dyn std::error::Error + Send + Sync>>>,
@@ -128,6 +147,12 @@ pub enum Command {
     GetStartTime {
         respond_to: oneshot::Sender<i64>,
     },
+    IsMaintenance {
+        respond_to: oneshot::Sender<bool>,
+    },
+    IsLoginBlocked {
+        respond_to: oneshot::Sender<bool>,
+    },
     IsLoggedIn {
         account_id: i32,
         respond_to: oneshot::Sender<bool>,
@@ -141,6 +166,22 @@ pub enum Command {
         admin_name: String,
         silent: bool,
     },
+    ListAuditLog {
+        player_id: i32,
+        name: Option<String>,
+    },
+    ListBans {
+        player_id: i32,
+        character_name: Option<String>,
+    },
+    ListReports {
+        player_id: i32,
+        report_id: Option<i32>,
+    },
+    ListPlayerQuests {
+        player_id: i32,
+        name: String,
+    },
     LoadMapFiles {
         world: WorldHandle,
         respond_to: oneshot::Sender<()>,
@@ -152,6 +193,11 @@ pub enum Command {
     Quake {
         magnitude: i32,
     },
+    RecordAdminAction {
+        character: Box<Character>,
+        command: String,
+        args: Vec<String>,
+    },
     ReportPlayer {
         player_id: i32,
         reportee_name: String,
@@ -182,10 +228,23 @@ pub enum Command {
         player_id: i32,
         target_player_id: i32,
     },
+    ReloadQuests {
+        player_id: i32,
+    },
     ReloadMap {
         map_id: i32,
     },
+    ResolveReport {
+        player_id: i32,
+        admin_name: String,
+        report_id: i32,
+        resolution: String,
+    },
     Save,
+    NotifyAdmins {
+        name: String,
+        message: String,
+    },
     SendAdminMessage {
         player_id: i32,
         message: String,
@@ -200,6 +259,20 @@ pub enum Command {
         property: String,
         value: String,
     },
+    SetMaintenance {
+        admin_name: String,
+        enabled: Option<bool>,
+        kick: bool,
+    },
+    SetTime {
+        player_id: i32,
+        hour: Option<u32>,
+    },
+    SetWeather {
+        player_id: i32,
+        map_id: i32,
+        weather: Option<String>,
+    },
     ShowCaptcha {
         victim_name: String,
         experience: i32,
@@ -207,10 +280,23 @@ pub enum Command {
     Shutdown {
         respond_to: oneshot::Sender<()>,
     },
+    StartShutdown {
+        admin_name: String,
+        seconds: i32,
+        reason: String,
+    },
     Tick,
+    ToggleEvent {
+        player_id: i32,
+        name: Option<String>,
+    },
     ToggleGlobal {
         admin_name: String,
     },
+    UnbanPlayer {
+        player_id: i32,
+        target: String,
+    },
     UnfreezePlayer {
         victim_name: String,
         admin_name: String,
@@ -223,4 +309,15 @@ pub enum Command {
         player_id: i32,
         exp_gains: Vec<PartyExpShare>,
     },
+    UpdatePlayerQuest {
+        player_id: i32,
+        name: String,
+        quest_id: i32,
+        update: QuestUpdate,
+    },
+    ViewPlayerQuest {
+        player_id: i32,
+        name: String,
+        quest_id: i32,
+    },
 }
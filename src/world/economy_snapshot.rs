@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Default)]
+pub struct EconomySnapshot {
+    pub taken_at: DateTime<Utc>,
+    /// Item totals across inventories, paperdolls, lockers, chests and the ground
+    pub items: HashMap<i32, i64>,
+    pub gold_bank: i64,
+    pub guild_gold: i64,
+}
+
+/// Snapshots kept between saves to compare new ones against
+#[derive(Debug, Default)]
+pub struct EconomyHistory {
+    /// Snapshot from the previous save
+    pub last: Option<EconomySnapshot>,
+    /// First snapshot of the current day
+    pub day: Option<EconomySnapshot>,
+}
+
+impl EconomySnapshot {
+    pub fn get_item_amount(&self, item_id: i32) -> i64 {
+        self.items.get(&item_id).copied().unwrap_or_default()
+    }
+
+    pub fn get_total_gold(&self) -> i64 {
+        self.get_item_amount(1) + self.gold_bank + self.guild_gold
+    }
+
+    /// Items (excluding gold) whose total grew since `previous`, largest increase first
+    pub fn get_item_increases(&self, previous: &EconomySnapshot) -> Vec<(i32, i64)> {
+        let mut increases = self
+            .items
+            .iter()
+            .filter(|(item_id, _)| **item_id != 1)
+            .filter_map(|(item_id, amount)| {
+                let increase = amount - previous.get_item_amount(*item_id);
+                (increase > 0).then_some((*item_id, increase))
+            })
+            .collect::<Vec<_>>();
+
+        increases.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        increases
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::EconomySnapshot;
+
+    #[test]
+    fn item_increases_skip_gold_and_sort_by_amount() {
+        let previous = EconomySnapshot {
+            items: HashMap::from([(1, 100), (2, 10), (3, 10)]),
+            ..Default::default()
+        };
+        let current = EconomySnapshot {
+            items: HashMap::from([(1, 500), (2, 15), (3, 5), (4, 20)]),
+            ..Default::default()
+        };
+
+        assert_eq!(current.get_item_increases(&previous), vec![(4, 20), (2, 5)]);
+    }
+
+    #[test]
+    fn total_gold_includes_banks() {
+        let snapshot = EconomySnapshot {
+            items: HashMap::from([(1, 100)]),
+            gold_bank: 50,
+            guild_gold: 25,
+            ..Default::default()
+        };
+
+        assert_eq!(snapshot.get_total_gold(), 175);
+    }
+}
@@ -1,5 +1,7 @@
 mod command;
 pub use command::Command;
+mod economy_snapshot;
+pub use economy_snapshot::{EconomyHistory, EconomySnapshot};
 mod load_maps;
 #[allow(clippy::module_inception)]
 mod world;
@@ -7,3 +9,7 @@ mod world_handle;
 pub use world_handle::WorldHandle;
 mod party;
 pub use party::Party;
+mod region_weather;
+pub use region_weather::RegionWeather;
+mod report_status;
+pub use report_status::ReportStatus;
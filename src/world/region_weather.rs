@@ -0,0 +1,10 @@
+/// Current weather of a weather region, indexed the same as the configured regions
+#[derive(Debug, Clone, Default)]
+pub struct RegionWeather {
+    pub weather: Option<String>,
+    /// Seconds until the rotation rolls a new weather
+    pub seconds: i32,
+    pub scheduled: bool,
+    /// Set by admins, holds the weather until rotation is turned back on
+    pub forced: bool,
+}
@@ -0,0 +1,26 @@
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ReportStatus {
+    Open = 0,
+    Claimed = 1,
+    Resolved = 2,
+}
+
+impl From<i32> for ReportStatus {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => Self::Claimed,
+            2 => Self::Resolved,
+            _ => Self::Open,
+        }
+    }
+}
+
+impl std::fmt::Display for ReportStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Open => write!(f, "open"),
+            Self::Claimed => write!(f, "claimed"),
+            Self::Resolved => write!(f, "resolved"),
+        }
+    }
+}
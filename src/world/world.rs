@@ -1,11 +1,12 @@
 use crate::{
-    connection_log::ConnectionLog, errors::DataNotFoundError, map::MapHandle, player::PlayerHandle,
+    SETTINGS, WEATHERS, clock::Clock, connection_log::ConnectionLog, errors::DataNotFoundError,
+    map::MapHandle, player::PlayerHandle,
 };
 
-use super::{Command, Party, load_maps::load_maps};
+use super::{Command, EconomyHistory, Party, RegionWeather, load_maps::load_maps};
 use chrono::Utc;
-use std::collections::HashMap;
-use tokio::sync::mpsc::UnboundedReceiver;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::{Mutex, Notify, mpsc::UnboundedReceiver};
 
 #[derive(Debug)]
 pub struct World {
@@ -30,10 +31,21 @@ pub struct World {
     global_locked: bool,
     connection_log: ConnectionLog,
     start_time: i64,
+    economy: Arc<Mutex<EconomyHistory>>,
+    shutdown_seconds: Option<i32>,
+    shutdown_reason: String,
+    shutdown_notify: Arc<Notify>,
+    maintenance: bool,
+    maintenance_kick_seconds: Option<i32>,
+    /// World events that event-only spawns are waiting on
+    events: Vec<String>,
+    clock: Clock,
+    weather: Vec<RegionWeather>,
 }
 
 mod add_player;
 mod admin;
+mod analyze_economy;
 mod chat;
 mod disband_guild;
 mod drop_player;
@@ -47,9 +59,17 @@ mod request_player_name_list;
 mod save;
 mod shutdown;
 mod tick;
+mod timed_clock;
+mod timed_maintenance_kick;
+mod timed_shutdown;
+mod timed_weather;
 
 impl World {
-    pub fn new(rx: UnboundedReceiver<Command>, db: crate::db::DbHandle) -> Self {
+    pub fn new(
+        rx: UnboundedReceiver<Command>,
+        db: crate::db::DbHandle,
+        shutdown_notify: Arc<Notify>,
+    ) -> Self {
         Self {
             rx,
             db,
@@ -72,6 +92,15 @@ impl World {
             global_locked: false,
             connection_log: ConnectionLog::new(),
             start_time: Utc::now().timestamp(),
+            economy: Arc::new(Mutex::new(EconomyHistory::default())),
+            shutdown_seconds: None,
+            shutdown_reason: String::new(),
+            shutdown_notify,
+            maintenance: SETTINGS.maintenance.enabled,
+            maintenance_kick_seconds: None,
+            events: Vec::new(),
+            clock: Clock::new(),
+            weather: vec![RegionWeather::default(); WEATHERS.regions.len()],
         }
     }
 
@@ -87,8 +116,11 @@ impl World {
             }
 
             Command::AddConnection { ip, respond_to } => {
-                self.connection_log.add_connection(&ip);
-                let _ = respond_to.send(());
+                let _ = respond_to.send(self.connection_log.add_connection(&ip));
+            }
+
+            Command::RemoveConnection { ip } => {
+                self.connection_log.remove_connection(&ip);
             }
 
             Command::AddLoggedInAccount { account_id } => {
@@ -109,6 +141,7 @@ impl World {
                 name,
                 guild_tag,
             } => {
+                self.notify_resolved_reports(player_id, name.clone());
                 self.characters.insert(name, player_id);
                 if let Some(guild_tag) = guild_tag {
                     match self.guilds.get_mut(&guild_tag) {
@@ -170,6 +203,21 @@ impl World {
                 self.broadcast_guild_message(player_id, guild_tag, name, message);
             }
 
+            Command::BroadcastServerMessage { message } => {
+                self.broadcast_server_message(&message);
+            }
+
+            Command::CancelShutdown {
+                player_id,
+                admin_name,
+            } => self.cancel_shutdown(player_id, admin_name),
+
+            Command::ClaimReport {
+                player_id,
+                admin_name,
+                report_id,
+            } => self.claim_report(player_id, admin_name, report_id),
+
             Command::DisbandGuild { guild_tag } => self.disband_guild(guild_tag),
 
             Command::DropPlayer {
@@ -196,14 +244,6 @@ impl World {
                 let _ = respond_to.send(self.connection_log.len());
             }
 
-            Command::GetIpConnectionCount { ip, respond_to } => {
-                let _ = respond_to.send(self.connection_log.get_num_of_connections(&ip));
-            }
-
-            Command::GetIpLastConnect { ip, respond_to } => {
-                let _ = respond_to.send(self.connection_log.get_last_connect(&ip));
-            }
-
             Command::GetMap { map_id, respond_to } => {
                 let maps = self.maps.as_ref().expect("maps not loaded");
                 match maps.get(&map_id) {
@@ -246,6 +286,14 @@ impl World {
                 let _ = respond_to.send(self.get_player_party(player_id));
             }
 
+            Command::IsMaintenance { respond_to } => {
+                let _ = respond_to.send(self.maintenance);
+            }
+
+            Command::IsLoginBlocked { respond_to } => {
+                let _ = respond_to.send(self.is_login_blocked());
+            }
+
             Command::IsLoggedIn {
                 account_id,
                 respond_to,
@@ -256,6 +304,15 @@ impl World {
                 );
             }
 
+            Command::ListReports {
+                player_id,
+                report_id,
+            } => self.list_reports(player_id, report_id),
+
+            Command::ListPlayerQuests { player_id, name } => {
+                self.list_player_quests(player_id, name)
+            }
+
             Command::LoadMapFiles { world, respond_to } => {
                 match load_maps(self.db.to_owned(), world).await {
                     Ok(maps) => {
@@ -301,7 +358,23 @@ impl World {
 
             Command::ReloadMap { map_id } => self.reload_map(map_id).await,
 
-            Command::Save => self.save_async().await,
+            Command::ReloadQuests { player_id } => self.reload_quests(player_id),
+
+            Command::ResolveReport {
+                player_id,
+                admin_name,
+                report_id,
+                resolution,
+            } => self.resolve_report(player_id, admin_name, report_id, resolution),
+
+            Command::Save => {
+                let totals = self.save_async().await;
+                self.analyze_economy(totals);
+            }
+
+            Command::NotifyAdmins { name, message } => {
+                self.notify_message_to_online_admins(&name, &message).await
+            }
 
             Command::SendAdminMessage { player_id, message } => {
                 self.send_admin_message(player_id, message).await
@@ -321,6 +394,20 @@ impl World {
                 self.set_character_property(name, property, value);
             }
 
+            Command::SetMaintenance {
+                admin_name,
+                enabled,
+                kick,
+            } => self.set_maintenance(admin_name, enabled, kick),
+
+            Command::SetTime { player_id, hour } => self.set_time(player_id, hour),
+
+            Command::SetWeather {
+                player_id,
+                map_id,
+                weather,
+            } => self.set_weather(player_id, map_id, weather),
+
             Command::ShowCaptcha {
                 victim_name,
                 experience,
@@ -328,6 +415,12 @@ impl World {
 
             Command::Shutdown { respond_to } => self.shutdown(respond_to).await,
 
+            Command::StartShutdown {
+                admin_name,
+                seconds,
+                reason,
+            } => self.start_shutdown(admin_name, seconds, reason),
+
             Command::Tick => {
                 self.tick().await;
             }
@@ -342,13 +435,35 @@ impl World {
                 exp_gains,
             } => self.update_party_exp(player_id, exp_gains),
 
+            Command::UpdatePlayerQuest {
+                player_id,
+                name,
+                quest_id,
+                update,
+            } => self.update_player_quest(player_id, name, quest_id, update),
+
+            Command::ViewPlayerQuest {
+                player_id,
+                name,
+                quest_id,
+            } => self.view_player_quest(player_id, name, quest_id),
+
+            Command::BanIp {
+                player_id,
+                admin_name,
+                range,
+                duration,
+                reason,
+            } => self.ban_ip(player_id, admin_name, range, duration, reason),
+
             Command::BanPlayer {
                 victim_name,
                 admin_name,
                 duration,
+                reason,
                 silent,
             } => {
-                self.ban_player(victim_name, admin_name, duration, silent)
+                self.ban_player(victim_name, admin_name, duration, reason, silent)
                     .await
             }
 
@@ -372,6 +487,13 @@ impl World {
                 silent,
             } => self.kick_player(victim_name, admin_name, silent),
 
+            Command::ListAuditLog { player_id, name } => self.list_audit_log(player_id, name),
+
+            Command::ListBans {
+                player_id,
+                character_name,
+            } => self.list_bans(player_id, character_name),
+
             Command::MutePlayer {
                 victim_name,
                 admin_name,
@@ -379,6 +501,12 @@ impl World {
 
             Command::Quake { magnitude } => self.quake(magnitude),
 
+            Command::RecordAdminAction {
+                character,
+                command,
+                args,
+            } => self.record_admin_action(character, command, args),
+
             Command::RequestPlayerInfo {
                 player_id,
                 victim_name,
@@ -389,8 +517,12 @@ impl World {
                 victim_name,
             } => self.request_player_inventory(player_id, victim_name),
 
+            Command::ToggleEvent { player_id, name } => self.toggle_event(player_id, name),
+
             Command::ToggleGlobal { admin_name } => self.toggle_global(admin_name),
 
+            Command::UnbanPlayer { player_id, target } => self.unban_player(player_id, target),
+
             Command::UnfreezePlayer {
                 victim_name,
                 admin_name,
@@ -0,0 +1,89 @@
+use crate::{
+    IP_BANS,
+    db::insert_params,
+    utils::{ip_in_range, parse_ip_range},
+};
+
+use super::super::World;
+
+impl World {
+    pub fn ban_ip(
+        &self,
+        player_id: i32,
+        admin_name: String,
+        range: String,
+        duration: String,
+        reason: String,
+    ) {
+        let admin = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let range = match parse_ip_range(&range) {
+            Some(net) => net.to_string(),
+            None => {
+                admin.send_server_message(&format!("Invalid IP or range: {}", range));
+                return;
+            }
+        };
+
+        let players = self.players.values().cloned().collect::<Vec<_>>();
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            let duration = duration_str::parse(&duration);
+
+            if let Err(err) = db
+                .execute(&insert_params(
+                    include_str!("../../../sql/create_ban.sql"),
+                    &[
+                        ("account_id", &None::<i32>),
+                        ("ip", &range),
+                        ("hdid", &None::<String>),
+                        (
+                            "duration",
+                            &match duration {
+                                Ok(duration) => Some(format!("{}", duration.as_secs() / 60)),
+                                Err(_) => None,
+                            },
+                        ),
+                        (
+                            "reason",
+                            &if reason.is_empty() {
+                                None
+                            } else {
+                                Some(reason)
+                            },
+                        ),
+                        ("banned_by", &admin_name),
+                    ],
+                ))
+                .await
+            {
+                error!("Failed to ban ip: {}", err);
+                return;
+            }
+
+            if let Err(err) = IP_BANS.load(&db).await {
+                error!("Failed to reload ip bans: {}", err);
+            }
+
+            let mut kicked = 0;
+            for player in players {
+                if let Ok(ip) = player.get_ip().await
+                    && ip_in_range(&ip, &range)
+                {
+                    player.close("IP banned".to_string());
+                    kicked += 1;
+                }
+            }
+
+            admin.send_server_message(&format!(
+                "Banned {} ({} connection{} closed)",
+                range,
+                kicked,
+                if kicked == 1 { "" } else { "s" }
+            ));
+        });
+    }
+}
@@ -1,5 +1,5 @@
 use super::super::World;
-use crate::{LANG, db::insert_params};
+use crate::{IP_BANS, LANG, db::insert_params};
 
 impl World {
     pub async fn ban_player(
@@ -7,6 +7,7 @@ impl World {
         victim_name: String,
         admin_name: String,
         duration: String,
+        reason: String,
         silent: bool,
     ) {
         if let Some(player_id) = self.characters.get(&victim_name)
@@ -51,6 +52,8 @@ impl World {
                 None => return,
             };
 
+            let hdid = row.get_text(2);
+
             let duration = duration_str::parse(&duration);
 
             match db
@@ -59,6 +62,7 @@ impl World {
                     &[
                         ("account_id", &account_id),
                         ("ip", &ip),
+                        ("hdid", &hdid),
                         (
                             "duration",
                             &match duration {
@@ -66,11 +70,24 @@ impl World {
                                 Err(_) => None,
                             },
                         ),
+                        (
+                            "reason",
+                            &if reason.is_empty() {
+                                None
+                            } else {
+                                Some(reason)
+                            },
+                        ),
+                        ("banned_by", &admin_name),
                     ],
                 ))
                 .await
             {
-                Ok(_) => {}
+                Ok(_) => {
+                    if let Err(err) = IP_BANS.load(&db).await {
+                        error!("Failed to reload ip bans: {}", err);
+                    }
+                }
                 Err(err) => {
                     error!("Failed to ban player: {}", err);
                 }
@@ -0,0 +1,26 @@
+use crate::LANG;
+
+use super::super::World;
+
+impl World {
+    pub fn cancel_shutdown(&mut self, player_id: Option<i32>, admin_name: String) {
+        match self.shutdown_seconds {
+            Some(seconds) if seconds > 0 => {}
+            _ => {
+                if let Some(player) = player_id.and_then(|id| self.players.get(&id)) {
+                    player.send_server_message("There is no shutdown to cancel");
+                }
+                return;
+            }
+        }
+
+        info!("{} cancelled the shutdown countdown", admin_name);
+
+        self.shutdown_seconds = None;
+        self.shutdown_reason.clear();
+        self.broadcast_server_message(&get_lang_string!(
+            &LANG.shutdown_cancelled,
+            name = admin_name
+        ));
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{db::insert_params, world::ReportStatus};
+
+use super::super::World;
+
+impl World {
+    pub fn claim_report(&self, player_id: i32, admin_name: String, report_id: i32) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            match db
+                .execute_affected(&insert_params(
+                    include_str!("../../../sql/claim_report.sql"),
+                    &[
+                        ("report_id", &report_id),
+                        ("status", &(ReportStatus::Claimed as i32)),
+                        ("assignee", &admin_name),
+                        ("resolved_status", &(ReportStatus::Resolved as i32)),
+                    ],
+                ))
+                .await
+            {
+                Ok(0) => {
+                    player.send_server_message(&format!(
+                        "Ticket #{} not found or already resolved",
+                        report_id
+                    ));
+                    return;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    error!("Failed to claim report: {}", e);
+                    return;
+                }
+            }
+
+            player.send_server_message(&format!("You have claimed ticket #{}", report_id));
+        });
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{SETTINGS, db::insert_params, utils::capitalize};
+
+use super::super::World;
+
+impl World {
+    pub fn list_audit_log(&self, player_id: i32, name: Option<String>) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            let rows = match db
+                .query(&insert_params(
+                    include_str!("../../../sql/get_audit_log.sql"),
+                    &[
+                        ("name", &name.unwrap_or_default().to_lowercase()),
+                        ("limit", &SETTINGS.audit.list_limit),
+                    ],
+                ))
+                .await
+            {
+                Ok(rows) => rows,
+                Err(e) => {
+                    error!("Failed to get audit log: {}", e);
+                    return;
+                }
+            };
+
+            if rows.is_empty() {
+                player.send_server_message("No audit log entries found");
+                return;
+            }
+
+            for row in rows {
+                player.send_server_message(&format!(
+                    "#{} {} {}: ${} {} (map {} {},{})",
+                    row.get_int(0).unwrap_or_default(),
+                    row.get_date(7)
+                        .map(|date| date.format("%Y-%m-%d %H:%M").to_string())
+                        .unwrap_or_default(),
+                    capitalize(&row.get_string(1).unwrap_or_default()),
+                    row.get_string(2).unwrap_or_default(),
+                    row.get_text(3).unwrap_or_default(),
+                    row.get_int(4).unwrap_or_default(),
+                    row.get_int(5).unwrap_or_default(),
+                    row.get_int(6).unwrap_or_default(),
+                ));
+            }
+        });
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    SETTINGS,
+    db::{Row, insert_params},
+    player::PlayerHandle,
+    utils::{capitalize, get_ban_minutes_remaining},
+};
+
+use super::super::World;
+
+impl World {
+    pub fn list_bans(&self, player_id: i32, character_name: Option<String>) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            let query = match &character_name {
+                Some(name) => insert_params(
+                    include_str!("../../../sql/get_character_bans.sql"),
+                    &[("character_name", name)],
+                ),
+                None => insert_params(include_str!("../../../sql/get_bans.sql"), &[]),
+            };
+
+            let rows = match db.query(&query).await {
+                Ok(rows) => rows,
+                Err(e) => {
+                    error!("Failed to get bans: {}", e);
+                    return;
+                }
+            };
+
+            let active = rows
+                .iter()
+                .filter_map(|row| {
+                    get_ban_minutes_remaining(row.get_int(4)?, row.get_date(7)?)
+                        .map(|remaining| (row, remaining))
+                })
+                .take(SETTINGS.bans.list_limit as usize)
+                .collect::<Vec<_>>();
+
+            if active.is_empty() {
+                player.send_server_message("No active bans");
+                return;
+            }
+
+            for (row, remaining) in active {
+                send_ban_line(&player, row, remaining);
+            }
+        });
+    }
+}
+
+fn send_ban_line(player: &PlayerHandle, row: &Row, remaining: i32) {
+    let mut targets = Vec::new();
+    if let Some(account) = row.get_string(1) {
+        targets.push(format!("account {}", account));
+    }
+    if let Some(ip) = row.get_string(2) {
+        targets.push(format!("ip {}", ip));
+    }
+    if let Some(hdid) = row.get_text(3) {
+        targets.push(format!("hdid {}", hdid));
+    }
+
+    let mut message = format!(
+        "#{} {} ({})",
+        row.get_int(0).unwrap_or_default(),
+        targets.join(", "),
+        if remaining == 0 {
+            "permanent".to_string()
+        } else {
+            format!("{} minutes left", remaining)
+        },
+    );
+
+    if let Some(banned_by) = row.get_string(6) {
+        message.push_str(&format!(" by {}", capitalize(&banned_by)));
+    }
+
+    if let Some(reason) = row.get_text(5) {
+        message.push_str(&format!(": {}", reason));
+    }
+
+    player.send_server_message(&message);
+}
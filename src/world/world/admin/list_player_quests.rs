@@ -0,0 +1,71 @@
+use crate::{QUEST_DB, utils::capitalize};
+
+use super::super::World;
+
+impl World {
+    pub fn list_player_quests(&self, player_id: i32, name: String) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let name = name.to_lowercase();
+        let target = match self
+            .characters
+            .get(&name)
+            .and_then(|target_id| self.players.get(target_id))
+        {
+            Some(target) => target.to_owned(),
+            None => {
+                player.send_server_message(&format!("{} is not online", capitalize(&name)));
+                return;
+            }
+        };
+
+        tokio::spawn(async move {
+            let character = match target.get_character().await {
+                Ok(character) => character,
+                Err(e) => {
+                    error!("Failed to get character: {}", e);
+                    return;
+                }
+            };
+
+            if character.quests.is_empty() {
+                player.send_server_message(&format!("{} has no quests", capitalize(&name)));
+                return;
+            }
+
+            let mut quests = character.quests.to_owned();
+            quests.sort_by_key(|progress| (progress.done_at.is_some(), progress.id));
+
+            for progress in quests {
+                let (quest_name, state_name) = match QUEST_DB.get(&progress.id) {
+                    Some(quest) => (
+                        quest.name.to_owned(),
+                        match quest.states.get(progress.state as usize) {
+                            Some(state) => state.name.to_owned(),
+                            None => format!("#{}", progress.state),
+                        },
+                    ),
+                    None => ("Unknown quest".to_owned(), format!("#{}", progress.state)),
+                };
+
+                player.send_server_message(&match progress.done_at {
+                    Some(done_at) => format!(
+                        "#{} {} completed {} ({} times), state {}",
+                        progress.id,
+                        quest_name,
+                        done_at.format("%Y-%m-%d %H:%M"),
+                        progress.completions.max(1),
+                        state_name
+                    ),
+                    None => format!(
+                        "#{} {} active, state {}",
+                        progress.id, quest_name, state_name
+                    ),
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{SETTINGS, db::insert_params, utils::capitalize, world::ReportStatus};
+
+use super::super::World;
+
+impl World {
+    pub fn list_reports(&self, player_id: i32, report_id: Option<i32>) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            if let Some(report_id) = report_id {
+                let row = match db
+                    .query_one(&insert_params(
+                        include_str!("../../../sql/get_report.sql"),
+                        &[("report_id", &report_id)],
+                    ))
+                    .await
+                {
+                    Ok(Some(row)) => row,
+                    Ok(None) => {
+                        player.send_server_message(&format!("Ticket #{} not found", report_id));
+                        return;
+                    }
+                    Err(e) => {
+                        error!("Failed to get report: {}", e);
+                        return;
+                    }
+                };
+
+                let status = ReportStatus::from(row.get_int(1).unwrap_or_default());
+                player.send_server_message(&format!(
+                    "#{} [{}] {} reports {}: {}",
+                    report_id,
+                    status,
+                    capitalize(&row.get_string(2).unwrap_or_default()),
+                    capitalize(&row.get_string(3).unwrap_or_default()),
+                    row.get_text(5).unwrap_or_default(),
+                ));
+
+                if let Some(assignee) = row.get_string(4) {
+                    player.send_server_message(&format!("Assignee: {}", capitalize(&assignee)));
+                }
+
+                if let Some(resolution) = row.get_text(6) {
+                    player.send_server_message(&format!("Resolution: {}", resolution));
+                }
+
+                player.send_server_message(&format!(
+                    "Reporter at map {} ({}, {})",
+                    row.get_int(7).unwrap_or_default(),
+                    row.get_int(8).unwrap_or_default(),
+                    row.get_int(9).unwrap_or_default(),
+                ));
+
+                send_chat_lines(&player, row.get_text(10));
+
+                if let Some(map_id) = row.get_int(11) {
+                    player.send_server_message(&format!(
+                        "Reportee at map {} ({}, {})",
+                        map_id,
+                        row.get_int(12).unwrap_or_default(),
+                        row.get_int(13).unwrap_or_default(),
+                    ));
+                } else {
+                    player.send_server_message("Reportee was offline");
+                }
+
+                send_chat_lines(&player, row.get_text(14));
+
+                return;
+            }
+
+            let rows = match db
+                .query(&insert_params(
+                    include_str!("../../../sql/get_reports.sql"),
+                    &[
+                        ("status", &(ReportStatus::Resolved as i32)),
+                        ("limit", &SETTINGS.reports.list_limit),
+                    ],
+                ))
+                .await
+            {
+                Ok(rows) => rows,
+                Err(e) => {
+                    error!("Failed to get reports: {}", e);
+                    return;
+                }
+            };
+
+            if rows.is_empty() {
+                player.send_server_message("There are no open tickets");
+                return;
+            }
+
+            for row in rows {
+                let status = ReportStatus::from(row.get_int(1).unwrap_or_default());
+                let assignee = match row.get_string(4) {
+                    Some(assignee) => format!(" ({})", capitalize(&assignee)),
+                    None => String::new(),
+                };
+
+                player.send_server_message(&format!(
+                    "#{} [{}{}] {} reports {}: {}",
+                    row.get_int(0).unwrap_or_default(),
+                    status,
+                    assignee,
+                    capitalize(&row.get_string(2).unwrap_or_default()),
+                    capitalize(&row.get_string(3).unwrap_or_default()),
+                    row.get_text(5).unwrap_or_default(),
+                ));
+            }
+        });
+    }
+}
+
+fn send_chat_lines(player: &crate::player::PlayerHandle, chat: Option<String>) {
+    let chat = match chat {
+        Some(chat) if !chat.is_empty() => chat,
+        _ => return,
+    };
+
+    for line in chat.lines() {
+        player.send_server_message(&format!("  \"{}\"", line));
+    }
+}
@@ -1,15 +1,34 @@
+mod ban_ip;
 mod ban_player;
+mod cancel_shutdown;
+mod claim_report;
 mod free_player;
 mod freeze_player;
 mod jail_player;
 mod kick_player;
+mod list_audit_log;
+mod list_bans;
+mod list_player_quests;
+mod list_reports;
 mod mute_player;
 mod quake;
+mod record_admin_action;
+mod reload_quests;
 mod report_player;
 mod request_player_info;
 mod request_player_inventory;
+mod resolve_report;
 mod send_admin_message;
+pub(super) use send_admin_message::notify_online_admins;
 mod set_character_property;
+mod set_maintenance;
+mod set_time;
+mod set_weather;
 mod show_captcha;
+mod start_shutdown;
+mod toggle_event;
 mod toggle_global;
+mod unban_player;
 mod unfreeze_player;
+mod update_player_quest;
+mod view_player_quest;
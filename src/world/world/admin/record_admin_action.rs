@@ -0,0 +1,46 @@
+use crate::{COMMANDS, character::Character, db::insert_params};
+
+use super::super::World;
+
+impl World {
+    pub fn record_admin_action(
+        &self,
+        character: Box<Character>,
+        command: String,
+        args: Vec<String>,
+    ) {
+        // Prefer the player being acted on, otherwise the first argument (item, npc, map..)
+        let target = COMMANDS
+            .commands
+            .iter()
+            .find(|c| c.name == command)
+            .and_then(|c| c.args.iter().position(|arg| arg.name == "player"))
+            .and_then(|index| args.get(index))
+            .or(args.first())
+            .filter(|target| !target.is_empty())
+            .map(|target| target.to_lowercase());
+
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            if let Err(e) = db
+                .execute(&insert_params(
+                    include_str!("../../../sql/create_audit_log_entry.sql"),
+                    &[
+                        ("character_id", &character.id),
+                        ("admin_name", &character.name),
+                        ("admin_level", &i32::from(character.admin_level)),
+                        ("command", &command),
+                        ("target", &target),
+                        ("args", &args.join(" ")),
+                        ("map", &character.map_id),
+                        ("x", &character.coords.x),
+                        ("y", &character.coords.y),
+                    ],
+                ))
+                .await
+            {
+                error!("Failed to record admin action: {}", e);
+            }
+        });
+    }
+}
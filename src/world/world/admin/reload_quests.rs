@@ -0,0 +1,166 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    QUEST_DB,
+    db::{DbHandle, insert_params},
+    quest_db::{
+        QuestRemap, QuestStateRemaps, StateRemap, get_lost_state_message,
+        get_removed_quest_message, remap_quest_state,
+    },
+    utils::{load_quests, validate_quests},
+};
+
+use super::super::World;
+
+impl World {
+    pub fn reload_quests(&self, player_id: i32) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let maps = match self.maps.as_ref() {
+            Some(maps) => maps.values().cloned().collect::<Vec<_>>(),
+            None => return,
+        };
+
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            let quests = match tokio::task::spawn_blocking(load_quests).await {
+                Ok(quests) => quests,
+                Err(e) => {
+                    error!("Failed to load quests: {}", e);
+                    return;
+                }
+            };
+
+            // Character loads and saves wait until stored progress matches the
+            // new quests, characters in memory catch up by reload generation
+            let lock = QUEST_DB.lock_reload().await;
+            let count = quests.len();
+            let remaps = QUEST_DB.reload(quests);
+            let removed = remaps
+                .iter()
+                .filter(|(_, remap)| matches!(remap, QuestRemap::Removed))
+                .map(|(quest_id, _)| *quest_id)
+                .collect::<BTreeSet<_>>();
+
+            let mut lost = BTreeSet::new();
+            if !remaps.is_empty()
+                && let Err(e) = remap_stored_quest_states(&db, &remaps, &mut lost).await
+            {
+                error!("Failed to remap stored quest progress: {}", e);
+                player.send_server_message("Failed to update stored quest progress");
+            }
+
+            // Released before asking maps, which may be waiting on it to save
+            drop(lock);
+
+            if !remaps.is_empty() {
+                for map in maps {
+                    match map.remap_quest_states().await {
+                        Ok(messages) => lost.extend(messages),
+                        Err(e) => error!("{}", e),
+                    }
+                }
+            }
+
+            let changed = remaps.len() - removed.len();
+            info!(
+                "Reloaded {} quests, {} changed their states",
+                count, changed
+            );
+            player.send_server_message(&format!(
+                "Reloaded {} quests, {} changed their states",
+                count, changed
+            ));
+
+            if !removed.is_empty() {
+                let message = format!(
+                    "Deleted quests {} had their progress cleared",
+                    removed
+                        .iter()
+                        .map(|quest_id| quest_id.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                warn!("{}", message);
+                player.send_server_message(&message);
+            }
+
+            for message in &lost {
+                warn!("{}", message);
+                player.send_server_message(message);
+            }
+
+            let issues = validate_quests();
+            for issue in &issues {
+                warn!("{}", issue);
+            }
+
+            if !issues.is_empty() {
+                player.send_server_message(&format!(
+                    "{} quest issues found, check the server log",
+                    issues.len()
+                ));
+            }
+        });
+    }
+}
+
+async fn remap_stored_quest_states(
+    db: &DbHandle,
+    remaps: &QuestStateRemaps,
+    lost: &mut BTreeSet<String>,
+) -> anyhow::Result<()> {
+    let rows = db
+        .query(&insert_params(
+            include_str!("../../../sql/get_quest_progress_states.sql"),
+            &[],
+        ))
+        .await?;
+
+    for row in rows {
+        let (character_id, name, quest_id, state) = match (
+            row.get_int(0),
+            row.get_text(1),
+            row.get_int(2),
+            row.get_int(3),
+        ) {
+            (Some(character_id), Some(name), Some(quest_id), Some(state)) => {
+                (character_id, name, quest_id, state)
+            }
+            _ => continue,
+        };
+
+        let new_state = match remap_quest_state(remaps, quest_id, state) {
+            StateRemap::Unchanged => continue,
+            StateRemap::Moved(new_state) => new_state,
+            StateRemap::Lost(state) => {
+                lost.insert(get_lost_state_message(&name, quest_id, &state));
+                0
+            }
+            StateRemap::Removed => {
+                lost.insert(get_removed_quest_message(&name, quest_id));
+                db.execute(&insert_params(
+                    include_str!("../../../sql/delete_quest_progress.sql"),
+                    &[("character_id", &character_id), ("quest_id", &quest_id)],
+                ))
+                .await?;
+                continue;
+            }
+        };
+
+        db.execute(&insert_params(
+            include_str!("../../../sql/update_quest_progress_state.sql"),
+            &[
+                ("character_id", &character_id),
+                ("quest_id", &quest_id),
+                ("state", &new_state),
+            ],
+        ))
+        .await?;
+    }
+
+    Ok(())
+}
@@ -10,7 +10,7 @@ use eolib::{
 };
 
 use super::super::World;
-use crate::{SETTINGS, db::insert_params, utils::capitalize};
+use crate::{character::Character, db::insert_params};
 
 impl World {
     pub async fn report_player(&self, player_id: i32, reportee_name: String, message: String) {
@@ -27,15 +27,18 @@ impl World {
             }
         };
 
+        let reportee = match self.characters.get(&reportee_name) {
+            Some(reportee_player_id) => match self.players.get(reportee_player_id) {
+                Some(reportee_player) => reportee_player.get_character().await.ok(),
+                None => None,
+            },
+            None => None,
+        };
+
         self.notify_report_to_online_admins(&character.name, &message, &reportee_name)
             .await;
 
-        self.add_report_to_admin_board(
-            character.id,
-            character.name.clone(),
-            message,
-            reportee_name,
-        );
+        self.create_report(character, reportee, reportee_name, message);
     }
 
     async fn notify_report_to_online_admins(
@@ -78,35 +81,39 @@ impl World {
         }
     }
 
-    fn add_report_to_admin_board(
+    fn create_report(
         &self,
-        character_id: i32,
-        player_name: String,
-        message: String,
+        reporter: Box<Character>,
+        reportee: Option<Box<Character>>,
         reportee_name: String,
+        message: String,
     ) {
         let db = self.db.clone();
         tokio::spawn(async move {
             if let Err(e) = db
                 .execute(&insert_params(
-                    include_str!("../../../sql/create_board_post.sql"),
+                    include_str!("../../../sql/create_report.sql"),
                     &[
-                        ("board_id", &SETTINGS.board.admin_board),
-                        ("character_id", &character_id),
+                        ("reporter_id", &reporter.id),
+                        ("reporter_name", &reporter.name),
+                        ("reportee_name", &reportee_name),
+                        ("message", &message),
+                        ("reporter_map", &reporter.map_id),
+                        ("reporter_x", &reporter.coords.x),
+                        ("reporter_y", &reporter.coords.y),
+                        ("reporter_chat", &reporter.recent_chat.join("\n")),
+                        ("reportee_map", &reportee.as_ref().map(|c| c.map_id)),
+                        ("reportee_x", &reportee.as_ref().map(|c| c.coords.x)),
+                        ("reportee_y", &reportee.as_ref().map(|c| c.coords.y)),
                         (
-                            "subject",
-                            &format!(
-                                "[Report] {} reports {}",
-                                capitalize(&player_name),
-                                capitalize(&reportee_name)
-                            ),
+                            "reportee_chat",
+                            &reportee.as_ref().map(|c| c.recent_chat.join("\n")),
                         ),
-                        ("body", &message),
                     ],
                 ))
                 .await
             {
-                error!("Failed to add report to admin board: {}", e);
+                error!("Failed to create report: {}", e);
             }
         });
     }
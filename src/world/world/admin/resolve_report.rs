@@ -0,0 +1,152 @@
+use crate::{db::insert_params, utils::capitalize, world::ReportStatus};
+
+use super::super::World;
+
+impl World {
+    pub fn resolve_report(
+        &self,
+        player_id: i32,
+        admin_name: String,
+        report_id: i32,
+        resolution: String,
+    ) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let characters = self.characters.clone();
+        let players = self.players.clone();
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            let row = match db
+                .query_one(&insert_params(
+                    include_str!("../../../sql/get_report.sql"),
+                    &[("report_id", &report_id)],
+                ))
+                .await
+            {
+                Ok(Some(row)) => row,
+                Ok(None) => {
+                    player.send_server_message(&format!("Ticket #{} not found", report_id));
+                    return;
+                }
+                Err(e) => {
+                    error!("Failed to get report: {}", e);
+                    return;
+                }
+            };
+
+            let reporter_name = row.get_string(2).unwrap_or_default();
+            let reportee_name = row.get_string(3).unwrap_or_default();
+
+            let reporter = characters
+                .get(&reporter_name)
+                .and_then(|player_id| players.get(player_id));
+
+            match db
+                .execute_affected(&insert_params(
+                    include_str!("../../../sql/resolve_report.sql"),
+                    &[
+                        ("report_id", &report_id),
+                        ("status", &(ReportStatus::Resolved as i32)),
+                        ("assignee", &admin_name),
+                        ("resolution", &resolution),
+                        ("reporter_notified", &reporter.is_some()),
+                    ],
+                ))
+                .await
+            {
+                Ok(0) => {
+                    player
+                        .send_server_message(&format!("Ticket #{} is already resolved", report_id));
+                    return;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    error!("Failed to resolve report: {}", e);
+                    return;
+                }
+            }
+
+            player.send_server_message(&format!("Ticket #{} has been resolved", report_id));
+
+            if let Some(reporter) = reporter {
+                reporter.send_server_message(&format_resolved_message(
+                    report_id,
+                    &reportee_name,
+                    &resolution,
+                ));
+            }
+        });
+    }
+
+    pub fn notify_resolved_reports(&self, player_id: i32, name: String) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            let rows = match db
+                .query(&insert_params(
+                    include_str!("../../../sql/get_unnotified_reports.sql"),
+                    &[
+                        ("name", &name),
+                        ("status", &(ReportStatus::Resolved as i32)),
+                    ],
+                ))
+                .await
+            {
+                Ok(rows) => rows,
+                Err(e) => {
+                    error!("Failed to get resolved reports: {}", e);
+                    return;
+                }
+            };
+
+            if rows.is_empty() {
+                return;
+            }
+
+            for row in rows {
+                player.send_server_message(&format_resolved_message(
+                    row.get_int(0).unwrap_or_default(),
+                    &row.get_string(1).unwrap_or_default(),
+                    &row.get_text(2).unwrap_or_default(),
+                ));
+            }
+
+            if let Err(e) = db
+                .execute(&insert_params(
+                    include_str!("../../../sql/mark_reports_notified.sql"),
+                    &[
+                        ("name", &name),
+                        ("status", &(ReportStatus::Resolved as i32)),
+                    ],
+                ))
+                .await
+            {
+                error!("Failed to mark reports as notified: {}", e);
+            }
+        });
+    }
+}
+
+fn format_resolved_message(report_id: i32, reportee_name: &str, resolution: &str) -> String {
+    if resolution.is_empty() {
+        format!(
+            "Your report #{} against {} has been closed",
+            report_id,
+            capitalize(reportee_name)
+        )
+    } else {
+        format!(
+            "Your report #{} against {} has been closed: {}",
+            report_id,
+            capitalize(reportee_name),
+            resolution
+        )
+    }
+}
@@ -9,7 +9,7 @@ use eolib::{
     },
 };
 
-use crate::{SETTINGS, db::insert_params, utils::capitalize};
+use crate::{SETTINGS, db::insert_params, player::PlayerHandle, utils::capitalize};
 
 use super::super::World;
 
@@ -34,38 +34,8 @@ impl World {
         self.add_message_to_admin_board(character.id, character.name.clone(), message);
     }
 
-    async fn notify_message_to_online_admins(&self, player_name: &str, message: &str) {
-        let packet = AdminInteractReplyServerPacket {
-            message_type: AdminMessageType::Message,
-            message_type_data: Some(AdminInteractReplyServerPacketMessageTypeData::Message(
-                AdminInteractReplyServerPacketMessageTypeDataMessage {
-                    player_name: player_name.to_owned(),
-                    message: message.to_owned(),
-                },
-            )),
-        };
-
-        let mut writer = EoWriter::new();
-
-        if let Err(e) = packet.serialize(&mut writer) {
-            error!("Failed to serialize AdminInteractReplyServerPacket: {}", e);
-            return;
-        }
-
-        let buf = writer.to_byte_array();
-
-        for player in self.players.values() {
-            if let Ok(character) = player.get_character().await
-                && character.name != player_name
-                && i32::from(character.admin_level) >= 1
-            {
-                player.send_buf(
-                    PacketAction::Reply,
-                    PacketFamily::AdminInteract,
-                    buf.clone(),
-                );
-            }
-        }
+    pub async fn notify_message_to_online_admins(&self, player_name: &str, message: &str) {
+        notify_online_admins(self.players.values(), player_name, message).await;
     }
 
     fn add_message_to_admin_board(&self, character_id: i32, player_name: String, message: String) {
@@ -91,3 +61,42 @@ impl World {
         });
     }
 }
+
+/// Sends an admin message to every online admin except the sender
+pub async fn notify_online_admins<'a>(
+    players: impl IntoIterator<Item = &'a PlayerHandle>,
+    player_name: &str,
+    message: &str,
+) {
+    let packet = AdminInteractReplyServerPacket {
+        message_type: AdminMessageType::Message,
+        message_type_data: Some(AdminInteractReplyServerPacketMessageTypeData::Message(
+            AdminInteractReplyServerPacketMessageTypeDataMessage {
+                player_name: player_name.to_owned(),
+                message: message.to_owned(),
+            },
+        )),
+    };
+
+    let mut writer = EoWriter::new();
+
+    if let Err(e) = packet.serialize(&mut writer) {
+        error!("Failed to serialize AdminInteractReplyServerPacket: {}", e);
+        return;
+    }
+
+    let buf = writer.to_byte_array();
+
+    for player in players {
+        if let Ok(character) = player.get_character().await
+            && character.name != player_name
+            && i32::from(character.admin_level) >= 1
+        {
+            player.send_buf(
+                PacketAction::Reply,
+                PacketFamily::AdminInteract,
+                buf.clone(),
+            );
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{LANG, SETTINGS};
+
+use super::super::World;
+
+impl World {
+    pub fn set_maintenance(&mut self, admin_name: String, enabled: Option<bool>, kick: bool) {
+        self.maintenance = enabled.unwrap_or(!self.maintenance);
+
+        info!(
+            "{} turned maintenance mode {}",
+            admin_name,
+            if self.maintenance { "on" } else { "off" }
+        );
+
+        if !self.maintenance {
+            self.maintenance_kick_seconds = None;
+            self.broadcast_server_message(&get_lang_string!(
+                &LANG.maintenance_off,
+                name = admin_name
+            ));
+            return;
+        }
+
+        self.broadcast_server_message(&get_lang_string!(&LANG.maintenance_on, name = admin_name));
+
+        if kick || SETTINGS.maintenance.kick_players {
+            let seconds = SETTINGS.maintenance.kick_delay_seconds.max(0);
+            self.maintenance_kick_seconds = Some(seconds);
+            if seconds > 0 {
+                self.broadcast_server_message(&get_lang_string!(
+                    &LANG.maintenance_kick_warning,
+                    seconds = seconds
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::SETTINGS;
+
+use super::super::World;
+
+impl World {
+    pub fn set_time(&mut self, player_id: i32, hour: Option<u32>) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let hour = match hour {
+            Some(hour) if hour < 24 => hour,
+            Some(_) => {
+                player.send_server_message("Hour must be between 0 and 23");
+                return;
+            }
+            None => {
+                player.send_server_message(&format!(
+                    "It is {:0>2}:{:0>2}",
+                    self.clock.get_hour(),
+                    self.clock.get_minute()
+                ));
+                return;
+            }
+        };
+
+        if SETTINGS.clock.day_length <= 0 {
+            player.send_server_message("The clock follows server time and can't be set");
+            return;
+        }
+
+        self.clock.set_hour(hour);
+        self.send_clock();
+
+        player.send_server_message(&format!("Time set to {:0>2}:00", hour));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::WEATHERS;
+
+use super::super::World;
+
+impl World {
+    pub fn set_weather(&mut self, player_id: i32, map_id: i32, weather: Option<String>) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let index = match WEATHERS.get_region_index(map_id) {
+            Some(index) => index,
+            None => {
+                player.send_server_message(&format!("Map {} isn't in a weather region", map_id));
+                return;
+            }
+        };
+
+        let region = &WEATHERS.regions[index];
+        let state = match self.weather.get_mut(index) {
+            Some(state) => state,
+            None => return,
+        };
+
+        let weather = match weather {
+            Some(weather) => weather.to_lowercase(),
+            None => {
+                player.send_server_message(&format!(
+                    "Weather in {}: {}{}",
+                    region.name,
+                    state.weather.as_deref().unwrap_or("clear"),
+                    if state.forced {
+                        " (forced)".to_string()
+                    } else if state.scheduled {
+                        " (scheduled)".to_string()
+                    } else {
+                        format!(" for {}s", state.seconds)
+                    }
+                ));
+                return;
+            }
+        };
+
+        match weather.as_str() {
+            "auto" => {
+                state.forced = false;
+                state.seconds = 0;
+                player.send_server_message(&format!("Weather rotation resumed in {}", region.name));
+                return;
+            }
+            "clear" => {
+                state.weather = None;
+            }
+            name => match WEATHERS.get(name) {
+                Some(weather) => state.weather = Some(weather.name.to_owned()),
+                None => {
+                    player.send_server_message(&format!("Unknown weather {}", name));
+                    return;
+                }
+            },
+        }
+
+        state.forced = true;
+        player.send_server_message(&format!(
+            "Weather in {} set to {}",
+            region.name,
+            state.weather.as_deref().unwrap_or("clear")
+        ));
+
+        self.send_region_weather(index);
+    }
+}
@@ -0,0 +1,22 @@
+use super::super::World;
+
+impl World {
+    pub fn start_shutdown(&mut self, admin_name: String, seconds: i32, reason: String) {
+        let seconds = seconds.max(1);
+
+        info!(
+            "{} started a {} second shutdown countdown{}",
+            admin_name,
+            seconds,
+            if reason.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", reason)
+            }
+        );
+
+        self.shutdown_seconds = Some(seconds);
+        self.shutdown_reason = reason;
+        self.send_shutdown_warning(seconds);
+    }
+}
@@ -0,0 +1,36 @@
+use super::super::World;
+
+impl World {
+    pub fn toggle_event(&mut self, player_id: i32, name: Option<String>) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let name = match name {
+            Some(name) => name.to_lowercase(),
+            None => {
+                player.send_server_message(&if self.events.is_empty() {
+                    "No events are running".to_string()
+                } else {
+                    format!("Running events: {}", self.events.join(", "))
+                });
+                return;
+            }
+        };
+
+        if self.events.contains(&name) {
+            self.events.retain(|event| event != &name);
+            player.send_server_message(&format!("Event {} stopped", name));
+        } else {
+            self.events.push(name.to_owned());
+            player.send_server_message(&format!("Event {} started", name));
+        }
+
+        if let Some(maps) = self.maps.as_ref() {
+            for map in maps.values() {
+                map.set_events(self.events.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{IP_BANS, db::insert_params};
+
+use super::super::World;
+
+impl World {
+    pub fn unban_player(&self, player_id: i32, target: String) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            let (lookup, delete) = match target.parse::<i32>() {
+                Ok(ban_id) => (
+                    insert_params(
+                        include_str!("../../../sql/get_ban.sql"),
+                        &[("ban_id", &ban_id)],
+                    ),
+                    insert_params(
+                        include_str!("../../../sql/delete_ban.sql"),
+                        &[("ban_id", &ban_id)],
+                    ),
+                ),
+                Err(_) => (
+                    insert_params(
+                        include_str!("../../../sql/get_character_bans.sql"),
+                        &[("character_name", &target)],
+                    ),
+                    insert_params(
+                        include_str!("../../../sql/delete_character_bans.sql"),
+                        &[("character_name", &target)],
+                    ),
+                ),
+            };
+
+            let count = match db.query(&lookup).await {
+                Ok(rows) => rows.len(),
+                Err(e) => {
+                    error!("Failed to get bans: {}", e);
+                    return;
+                }
+            };
+
+            if count == 0 {
+                player.send_server_message(&format!("No bans found for {}", target));
+                return;
+            }
+
+            if let Err(e) = db.execute(&delete).await {
+                error!("Failed to remove bans: {}", e);
+                return;
+            }
+
+            if let Err(e) = IP_BANS.load(&db).await {
+                error!("Failed to reload ip bans: {}", e);
+            }
+
+            player.send_server_message(&format!(
+                "Removed {} ban{} for {}",
+                count,
+                if count == 1 { "" } else { "s" },
+                target
+            ));
+        });
+    }
+}
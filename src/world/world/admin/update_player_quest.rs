@@ -0,0 +1,43 @@
+use crate::{map::QuestUpdate, utils::capitalize};
+
+use super::super::World;
+
+impl World {
+    pub fn update_player_quest(
+        &self,
+        player_id: i32,
+        name: String,
+        quest_id: i32,
+        update: QuestUpdate,
+    ) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let name = name.to_lowercase();
+        let (target_id, target) = match self
+            .characters
+            .get(&name)
+            .and_then(|target_id| Some((*target_id, self.players.get(target_id)?)))
+        {
+            Some((target_id, target)) => (target_id, target.to_owned()),
+            None => {
+                player.send_server_message(&format!("{} is not online", capitalize(&name)));
+                return;
+            }
+        };
+
+        tokio::spawn(async move {
+            let map = match target.get_map().await {
+                Ok(map) => map,
+                Err(e) => {
+                    error!("Error getting map for update_player_quest: {}", e);
+                    return;
+                }
+            };
+
+            map.update_quest(target_id, quest_id, update, player);
+        });
+    }
+}
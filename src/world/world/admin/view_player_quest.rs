@@ -0,0 +1,111 @@
+use eoplus::Arg;
+
+use crate::{NPC_DB, QUEST_DB, utils::capitalize};
+
+use super::super::World;
+
+impl World {
+    pub fn view_player_quest(&self, player_id: i32, name: String, quest_id: i32) {
+        let player = match self.players.get(&player_id) {
+            Some(player) => player.to_owned(),
+            None => return,
+        };
+
+        let name = name.to_lowercase();
+        let target = match self
+            .characters
+            .get(&name)
+            .and_then(|target_id| self.players.get(target_id))
+        {
+            Some(target) => target.to_owned(),
+            None => {
+                player.send_server_message(&format!("{} is not online", capitalize(&name)));
+                return;
+            }
+        };
+
+        let quest = match QUEST_DB.get(&quest_id) {
+            Some(quest) => quest,
+            None => {
+                player.send_server_message(&format!("Quest {} does not exist", quest_id));
+                return;
+            }
+        };
+
+        tokio::spawn(async move {
+            let character = match target.get_character().await {
+                Ok(character) => character,
+                Err(e) => {
+                    error!("Failed to get character: {}", e);
+                    return;
+                }
+            };
+
+            let progress = match character.quests.iter().find(|q| q.id == quest_id) {
+                Some(progress) => progress,
+                None => {
+                    player.send_server_message(&format!(
+                        "{} has not started {}",
+                        capitalize(&name),
+                        quest.name
+                    ));
+                    return;
+                }
+            };
+
+            let state = quest.states.get(progress.state as usize);
+            player.send_server_message(&format!(
+                "#{} {}: state {} ({}/{})",
+                quest_id,
+                quest.name,
+                state.map(|state| state.name.as_str()).unwrap_or("unknown"),
+                progress.state + 1,
+                quest.states.len()
+            ));
+
+            if !progress.npc_kills.is_empty() {
+                let kills = progress
+                    .npc_kills
+                    .iter()
+                    .map(|(npc_id, kills)| {
+                        let npc_name = match NPC_DB.npcs.get(*npc_id as usize - 1) {
+                            Some(npc) => npc.name.to_owned(),
+                            None => format!("NPC {}", npc_id),
+                        };
+                        format!("{} x{}", npc_name, kills)
+                    })
+                    .collect::<Vec<_>>();
+                player.send_server_message(&format!("NPC kills: {}", kills.join(", ")));
+            }
+
+            player.send_server_message(&format!(
+                "Player kills: {}, completions: {}, completed: {}",
+                progress.player_kills,
+                progress.completions,
+                match progress.done_at {
+                    Some(done_at) => done_at.format("%Y-%m-%d %H:%M").to_string(),
+                    None => "no".to_owned(),
+                }
+            ));
+
+            if let Some(state) = state {
+                for rule in &state.rules {
+                    let args = rule
+                        .args
+                        .iter()
+                        .map(|arg| match arg {
+                            Arg::Int(value) => value.to_string(),
+                            Arg::Str(value) => format!("\"{}\"", value),
+                        })
+                        .collect::<Vec<_>>();
+                    player.send_server_message(&format!(
+                        "Rule {}({}) goto {}",
+                        rule.name,
+                        args.join(", "),
+                        rule.goto
+                    ));
+                }
+            }
+        });
+    }
+}
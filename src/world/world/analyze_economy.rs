@@ -0,0 +1,254 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::Utc;
+
+use crate::{
+    DROP_DB, GLOBAL_DROPS, ITEM_DB, QUEST_DB, SETTINGS, SHOP_DB,
+    db::{DbHandle, insert_params},
+    map::ItemTotals,
+    world::EconomySnapshot,
+};
+
+use super::{World, admin::notify_online_admins};
+
+impl World {
+    /// Compares item and gold totals with the previous save, alerting online
+    /// admins about spikes and posting a daily summary to the admin board
+    pub fn analyze_economy(&self, totals: ItemTotals) {
+        if !SETTINGS.economy.enabled {
+            return;
+        }
+
+        let players = self.players.values().cloned().collect::<Vec<_>>();
+        let economy = self.economy.clone();
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            // Held for the whole analysis so back to back saves can't interleave
+            let mut economy = economy.lock().await;
+
+            let snapshot = match take_economy_snapshot(&db, totals.items).await {
+                Ok(snapshot) => snapshot,
+                Err(e) => {
+                    error!("Failed to take economy snapshot: {}", e);
+                    return;
+                }
+            };
+
+            if let Some(previous) = economy.last.as_ref() {
+                let anomalies =
+                    find_anomalies(previous, &snapshot, &get_sourced_items(totals.chest_spawns));
+                for anomaly in &anomalies {
+                    warn!("Economy anomaly: {}", anomaly);
+                }
+
+                for anomaly in anomalies.iter().take(SETTINGS.economy.max_alerts) {
+                    notify_online_admins(&players, "Economy", anomaly).await;
+                }
+
+                if anomalies.len() > SETTINGS.economy.max_alerts {
+                    notify_online_admins(
+                        &players,
+                        "Economy",
+                        &format!(
+                            "{} more anomalies, check the server log",
+                            anomalies.len() - SETTINGS.economy.max_alerts
+                        ),
+                    )
+                    .await;
+                }
+            }
+
+            match economy.day.as_ref() {
+                Some(day) if day.taken_at.date_naive() == snapshot.taken_at.date_naive() => {}
+                Some(day) => {
+                    post_economy_summary(&db, day, &snapshot).await;
+                    economy.day = Some(snapshot.clone());
+                }
+                None => economy.day = Some(snapshot.clone()),
+            }
+
+            economy.last = Some(snapshot);
+        });
+    }
+}
+
+/// Adds the database totals to the ground and chest totals collected while saving
+async fn take_economy_snapshot(
+    db: &DbHandle,
+    items: HashMap<i32, i64>,
+) -> anyhow::Result<EconomySnapshot> {
+    let mut snapshot = EconomySnapshot {
+        taken_at: Utc::now(),
+        items,
+        ..Default::default()
+    };
+
+    let rows = db
+        .query(&insert_params(
+            include_str!("../../sql/get_economy_item_totals.sql"),
+            &[],
+        ))
+        .await?;
+
+    for row in rows {
+        if let (Some(item_id), Some(amount)) = (row.get_int(0), row.get_big_int(1)) {
+            *snapshot.items.entry(item_id).or_default() += amount;
+        }
+    }
+
+    if let Some(row) = db
+        .query_one(&insert_params(
+            include_str!("../../sql/get_economy_gold_totals.sql"),
+            &[],
+        ))
+        .await?
+    {
+        snapshot.gold_bank = row.get_big_int(0).unwrap_or_default();
+        snapshot.guild_gold = row.get_big_int(1).unwrap_or_default();
+    }
+
+    Ok(snapshot)
+}
+
+async fn post_economy_summary(db: &DbHandle, day: &EconomySnapshot, snapshot: &EconomySnapshot) {
+    let mut body = format!(
+        "Gold: {} ({:+})\nBank gold: {} ({:+})\nGuild gold: {} ({:+})\n\nLargest item increases:",
+        snapshot.get_total_gold(),
+        snapshot.get_total_gold() - day.get_total_gold(),
+        snapshot.gold_bank,
+        snapshot.gold_bank - day.gold_bank,
+        snapshot.guild_gold,
+        snapshot.guild_gold - day.guild_gold,
+    );
+
+    for (item_id, increase) in snapshot
+        .get_item_increases(day)
+        .into_iter()
+        .take(SETTINGS.economy.summary_items)
+    {
+        body.push_str(&format!(
+            "\n{}: +{} ({} total)",
+            get_item_name(item_id),
+            increase,
+            snapshot.get_item_amount(item_id)
+        ));
+    }
+
+    // Posted without a character so the board shows it under the system author
+    if let Err(e) = db
+        .execute(&insert_params(
+            include_str!("../../sql/create_board_post.sql"),
+            &[
+                ("board_id", &SETTINGS.board.admin_board),
+                ("character_id", &None::<i32>),
+                (
+                    "subject",
+                    &format!("[Economy] {}", day.taken_at.format("%Y-%m-%d")),
+                ),
+                ("body", &body),
+            ],
+        ))
+        .await
+    {
+        error!("Failed to post economy summary: {}", e);
+    }
+}
+
+fn find_anomalies(
+    previous: &EconomySnapshot,
+    snapshot: &EconomySnapshot,
+    sourced_items: &HashSet<i32>,
+) -> Vec<String> {
+    let mut anomalies = Vec::new();
+
+    let gold_increase = snapshot.get_total_gold() - previous.get_total_gold();
+    if is_spike(
+        previous.get_total_gold(),
+        gold_increase,
+        SETTINGS.economy.min_gold_increase,
+    ) {
+        anomalies.push(format!(
+            "Gold grew by {} to {} since the last save",
+            gold_increase,
+            snapshot.get_total_gold()
+        ));
+    }
+
+    for (item_id, increase) in snapshot.get_item_increases(previous) {
+        if !sourced_items.contains(&item_id) {
+            anomalies.push(format!(
+                "{} has no known source but grew by {} to {}",
+                get_item_name(item_id),
+                increase,
+                snapshot.get_item_amount(item_id)
+            ));
+        } else if is_spike(
+            previous.get_item_amount(item_id),
+            increase,
+            SETTINGS.economy.min_item_increase,
+        ) {
+            anomalies.push(format!(
+                "{} grew by {} to {} since the last save",
+                get_item_name(item_id),
+                increase,
+                snapshot.get_item_amount(item_id)
+            ));
+        }
+    }
+
+    anomalies
+}
+
+fn is_spike(previous: i64, increase: i64, min_increase: i64) -> bool {
+    increase >= min_increase && increase * 100 >= previous * SETTINGS.economy.spike_percent
+}
+
+/// Items the server can legitimately create: drops, shops, crafts, quests and chests
+fn get_sourced_items(chest_spawns: HashSet<i32>) -> HashSet<i32> {
+    let mut items = chest_spawns;
+
+    items.extend(
+        DROP_DB
+            .npcs
+            .iter()
+            .flat_map(|npc| npc.drops.iter().map(|drop| drop.item_id)),
+    );
+    items.extend(GLOBAL_DROPS.drops.iter().map(|drop| drop.item_id));
+    items.extend(
+        GLOBAL_DROPS
+            .timed
+            .iter()
+            .flat_map(|timed| timed.drops.iter().map(|drop| drop.item_id)),
+    );
+
+    for shop in &SHOP_DB.shops {
+        items.extend(
+            shop.trades
+                .iter()
+                .filter(|trade| trade.buy_price > 0)
+                .map(|trade| trade.item_id),
+        );
+        items.extend(shop.crafts.iter().map(|craft| craft.item_id));
+    }
+
+    for quest in QUEST_DB.all().values() {
+        for state in &quest.states {
+            for action in state.actions.iter().filter(|a| a.name == "GiveItem") {
+                if let Some(eoplus::Arg::Int(item_id)) = action.args.first() {
+                    items.insert(*item_id);
+                }
+            }
+        }
+    }
+
+    items.insert(SETTINGS.marriage.ring_item_id);
+    items.extend(SETTINGS.economy.sourced_items.iter());
+    items
+}
+
+fn get_item_name(item_id: i32) -> String {
+    match ITEM_DB.items.get((item_id as usize).wrapping_sub(1)) {
+        Some(item) => format!("{} (#{})", item.name, item_id),
+        None => format!("Item #{}", item_id),
+    }
+}
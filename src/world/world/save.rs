@@ -1,7 +1,13 @@
+use crate::map::ItemTotals;
+
 use super::World;
 
 impl World {
-    pub async fn save_async(&mut self) {
+    /// Saves every map, returning the combined ground and chest item totals
+    /// taken alongside each map's character saves
+    pub async fn save_async(&mut self) -> ItemTotals {
+        let mut totals = ItemTotals::default();
+
         if let Some(maps) = self.maps.as_ref() {
             const SAVE_BATCH_SIZE: usize = 4;
 
@@ -10,11 +16,19 @@ impl World {
                 let saves = batch.iter().map(|map| map.save_async());
                 let results = futures::future::join_all(saves).await;
                 for result in results {
-                    if let Err(e) = result {
-                        error!("Failed to save map: {}", e);
+                    match result {
+                        Ok(map_totals) => {
+                            for (item_id, amount) in map_totals.items {
+                                *totals.items.entry(item_id).or_default() += amount;
+                            }
+                            totals.chest_spawns.extend(map_totals.chest_spawns);
+                        }
+                        Err(e) => error!("Failed to save map: {}", e),
                     }
                 }
             }
         }
+
+        totals
     }
 }
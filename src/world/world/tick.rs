@@ -46,13 +46,16 @@ impl World {
                 map.timed_wedding();
                 map.timed_evacuate();
                 map.timed_arena();
+                map.timed_boss_fights();
                 if SETTINGS.jukebox.track_timer > 0 {
                     map.jukebox_timer();
                 }
                 map.timed_drop_protection();
                 map.timed_ghost();
+                map.timed_karma();
                 map.timed_cleanup();
                 map.timed_usage();
+                map.timed_weather();
             }
 
             if self.item_spawn_ticks >= SETTINGS.world.chest_spawn_rate {
@@ -81,6 +84,10 @@ impl World {
         }
 
         if self.second_ticks >= ONE_SECOND {
+            self.timed_clock();
+            self.timed_weather();
+            self.timed_shutdown();
+            self.timed_maintenance_kick();
             self.second_ticks = 0;
         }
 
@@ -0,0 +1,22 @@
+use crate::SETTINGS;
+
+use super::World;
+
+impl World {
+    pub fn timed_clock(&mut self) {
+        let (hour, minute) = (self.clock.get_hour(), self.clock.get_minute());
+        self.clock.tick(SETTINGS.clock.day_length);
+
+        if self.clock.get_hour() != hour || self.clock.get_minute() != minute {
+            self.send_clock();
+        }
+    }
+
+    pub fn send_clock(&self) {
+        if let Some(maps) = self.maps.as_ref() {
+            for map in maps.values() {
+                map.set_clock(self.clock.get_hour(), self.clock.get_minute());
+            }
+        }
+    }
+}
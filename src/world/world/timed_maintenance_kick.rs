@@ -0,0 +1,40 @@
+use crate::utils::has_maintenance_access;
+
+use super::World;
+
+impl World {
+    pub fn timed_maintenance_kick(&mut self) {
+        let seconds = match self.maintenance_kick_seconds {
+            Some(seconds) => seconds,
+            None => return,
+        };
+
+        if seconds > 0 {
+            self.maintenance_kick_seconds = Some(seconds - 1);
+            return;
+        }
+
+        self.maintenance_kick_seconds = None;
+
+        let players = self.players.values().cloned().collect::<Vec<_>>();
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            for player in players {
+                // Players still at character select have no character loaded
+                // and are disconnected too. Staff can simply log back in
+                if let Ok(character) = player.get_character().await {
+                    match has_maintenance_access(&db, character.account_id).await {
+                        Ok(true) => continue,
+                        Ok(false) => {}
+                        Err(e) => {
+                            error!("Failed to check maintenance access: {}", e);
+                            continue;
+                        }
+                    }
+                }
+
+                player.close("Server in maintenance".to_string());
+            }
+        });
+    }
+}
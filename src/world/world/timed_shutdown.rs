@@ -0,0 +1,62 @@
+use eolib::{
+    data::{EoSerialize, EoWriter},
+    protocol::net::{PacketAction, PacketFamily, server::MusicPlayerServerPacket},
+};
+
+use crate::{LANG, SETTINGS, utils::format_countdown};
+
+use super::World;
+
+impl World {
+    pub fn timed_shutdown(&mut self) {
+        let seconds = match self.shutdown_seconds {
+            Some(seconds) if seconds >= 0 => seconds,
+            _ => return,
+        };
+
+        if seconds == 0 {
+            info!("Shutdown countdown finished");
+            self.shutdown_notify.notify_one();
+        } else if SETTINGS.shutdown.announce_at.contains(&seconds) {
+            self.send_shutdown_warning(seconds);
+        }
+
+        // Stays below zero so logins remain blocked while the server closes
+        self.shutdown_seconds = Some(seconds - 1);
+    }
+
+    pub fn is_login_blocked(&self) -> bool {
+        match self.shutdown_seconds {
+            Some(seconds) => seconds <= SETTINGS.shutdown.block_logins_seconds,
+            None => false,
+        }
+    }
+
+    pub fn send_shutdown_warning(&self, seconds: i32) {
+        let time = format_countdown(seconds);
+        self.broadcast_server_message(&if self.shutdown_reason.is_empty() {
+            get_lang_string!(&LANG.shutdown_warning, time = time)
+        } else {
+            get_lang_string!(
+                &LANG.shutdown_warning_reason,
+                time = time,
+                reason = self.shutdown_reason
+            )
+        });
+
+        let packet = MusicPlayerServerPacket {
+            sound_id: SETTINGS.shutdown.sfx_id,
+        };
+        let mut writer = EoWriter::new();
+
+        if let Err(e) = packet.serialize(&mut writer) {
+            error!("Failed to serialize MusicPlayerServerPacket: {}", e);
+            return;
+        }
+
+        let buf = writer.to_byte_array();
+        for player in self.players.values() {
+            player.send_buf(PacketAction::Player, PacketFamily::Music, buf.clone());
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::WEATHERS;
+
+use super::World;
+
+impl World {
+    pub fn timed_weather(&mut self) {
+        let hour = self.clock.get_hour();
+        for (index, region) in WEATHERS.regions.iter().enumerate() {
+            let state = match self.weather.get_mut(index) {
+                Some(state) => state,
+                None => continue,
+            };
+
+            if state.forced {
+                continue;
+            }
+
+            let weather = if let Some(weather) = region.get_scheduled(hour) {
+                state.scheduled = true;
+                Some(weather)
+            } else if state.scheduled || state.seconds <= 1 {
+                let (weather, seconds) = region.roll();
+                state.scheduled = false;
+                state.seconds = seconds;
+                weather
+            } else {
+                state.seconds -= 1;
+                continue;
+            };
+
+            // Names that aren't configured weathers are clear skies
+            let weather = weather
+                .and_then(|name| WEATHERS.get(name))
+                .map(|weather| weather.name.to_owned());
+
+            if weather != state.weather {
+                state.weather = weather;
+                self.send_region_weather(index);
+            }
+        }
+    }
+
+    pub fn send_region_weather(&self, index: usize) {
+        let (region, state) = match (WEATHERS.regions.get(index), self.weather.get(index)) {
+            (Some(region), Some(state)) => (region, state),
+            _ => return,
+        };
+
+        let maps = match self.maps.as_ref() {
+            Some(maps) => maps,
+            None => return,
+        };
+
+        for map in region.maps.iter().filter_map(|map_id| maps.get(map_id)) {
+            map.set_weather(state.weather.clone());
+        }
+    }
+}
@@ -1,26 +1,34 @@
-use chrono::{DateTime, Utc};
 use eolib::protocol::net::{PartyRequestType, server::PartyExpShare};
-use std::time::Duration;
-use tokio::sync::{mpsc, oneshot};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{Notify, mpsc, oneshot};
 use tokio::time::timeout;
 
-use crate::{character::Character, map::MapHandle, player::PlayerHandle};
+use crate::{
+    character::Character,
+    connection_log::ConnectionRejection,
+    map::{MapHandle, QuestUpdate},
+    player::PlayerHandle,
+};
 
 use super::{Command, Party, world::World};
 
 #[derive(Debug, Clone)]
 pub struct WorldHandle {
     tx: mpsc::UnboundedSender<Command>,
-    pub is_alive: bool,
+    shutdown_notify: Arc<Notify>,
 }
 
 impl WorldHandle {
     pub fn new(db: crate::db::DbHandle) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
-        let world = World::new(rx, db);
+        let shutdown_notify = Arc::new(Notify::new());
+        let world = World::new(rx, db, shutdown_notify.clone());
         tokio::spawn(run_world(world));
 
-        Self { tx, is_alive: true }
+        Self {
+            tx,
+            shutdown_notify,
+        }
     }
 
     pub fn accept_party_request(
@@ -63,7 +71,10 @@ impl WorldHandle {
         });
     }
 
-    pub async fn add_connection(&self, ip: &str) -> Result<(), String> {
+    pub async fn add_connection(
+        &self,
+        ip: &str,
+    ) -> Result<Result<(), ConnectionRejection>, String> {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::AddConnection {
             ip: ip.to_string(),
@@ -75,6 +86,12 @@ impl WorldHandle {
             .map_err(|_| "Failed to add connection. Channel closed".to_string())
     }
 
+    pub fn remove_connection(&self, ip: &str) {
+        let _ = self
+            .tx
+            .send(Command::RemoveConnection { ip: ip.to_string() });
+    }
+
     pub async fn add_player(
         &mut self,
         player_id: i32,
@@ -97,16 +114,35 @@ impl WorldHandle {
         Ok(())
     }
 
+    pub fn ban_ip(
+        &self,
+        player_id: i32,
+        admin_name: String,
+        range: String,
+        duration: String,
+        reason: String,
+    ) {
+        let _ = self.tx.send(Command::BanIp {
+            player_id,
+            admin_name,
+            range,
+            duration,
+            reason,
+        });
+    }
+
     pub fn ban_player(
         &self,
         victim_name: String,
         duration: String,
+        reason: String,
         admin_name: String,
         silent: bool,
     ) {
         let _ = self.tx.send(Command::BanPlayer {
             victim_name,
             duration,
+            reason,
             admin_name,
             silent,
         });
@@ -153,6 +189,25 @@ impl WorldHandle {
         });
     }
 
+    pub fn broadcast_server_message(&self, message: String) {
+        let _ = self.tx.send(Command::BroadcastServerMessage { message });
+    }
+
+    pub fn cancel_shutdown(&self, player_id: Option<i32>, admin_name: String) {
+        let _ = self.tx.send(Command::CancelShutdown {
+            player_id,
+            admin_name,
+        });
+    }
+
+    pub fn claim_report(&self, player_id: i32, admin_name: String, report_id: i32) {
+        let _ = self.tx.send(Command::ClaimReport {
+            player_id,
+            admin_name,
+            report_id,
+        });
+    }
+
     pub fn disband_guild(&self, guild_tag: String) {
         let _ = self.tx.send(Command::DisbandGuild { guild_tag });
     }
@@ -253,30 +308,6 @@ impl WorldHandle {
             .map_err(|_| "Failed to get connection count. Channel closed".to_string())
     }
 
-    pub async fn get_ip_connection_count(&self, ip: &str) -> Result<i32, String> {
-        let (tx, rx) = oneshot::channel();
-        let _ = self.tx.send(Command::GetIpConnectionCount {
-            ip: ip.to_string(),
-            respond_to: tx,
-        });
-        timeout(Duration::from_secs(1), rx)
-            .await
-            .map_err(|_| "Failed to get IP connection count. Timeout".to_string())?
-            .map_err(|_| "Failed to get IP connection count. Channel closed".to_string())
-    }
-
-    pub async fn get_ip_last_connect(&self, ip: &str) -> Result<Option<DateTime<Utc>>, String> {
-        let (tx, rx) = oneshot::channel();
-        let _ = self.tx.send(Command::GetIpLastConnect {
-            ip: ip.to_string(),
-            respond_to: tx,
-        });
-        timeout(Duration::from_secs(1), rx)
-            .await
-            .map_err(|_| "Failed to get IP last connect. Timeout".to_string())?
-            .map_err(|_| "Failed to get IP last connect. Channel closed".to_string())
-    }
-
     pub async fn get_start_time(&self) -> Result<i64, String> {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::GetStartTime { respond_to: tx });
@@ -319,6 +350,24 @@ impl WorldHandle {
             .map_err(|_| "Failed to get player party. Channel closed".to_string())
     }
 
+    pub async fn is_maintenance(&self) -> Result<bool, String> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::IsMaintenance { respond_to: tx });
+        timeout(Duration::from_secs(1), rx)
+            .await
+            .map_err(|_| "Failed to check maintenance mode. Timeout".to_string())?
+            .map_err(|_| "Failed to check maintenance mode. Channel closed".to_string())
+    }
+
+    pub async fn is_login_blocked(&self) -> Result<bool, String> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::IsLoginBlocked { respond_to: tx });
+        timeout(Duration::from_secs(1), rx)
+            .await
+            .map_err(|_| "Failed to check if logins are blocked. Timeout".to_string())?
+            .map_err(|_| "Failed to check if logins are blocked. Channel closed".to_string())
+    }
+
     pub async fn is_logged_in(&self, account_id: i32) -> Result<bool, String> {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::IsLoggedIn {
@@ -346,6 +395,28 @@ impl WorldHandle {
         });
     }
 
+    pub fn list_audit_log(&self, player_id: i32, name: Option<String>) {
+        let _ = self.tx.send(Command::ListAuditLog { player_id, name });
+    }
+
+    pub fn list_bans(&self, player_id: i32, character_name: Option<String>) {
+        let _ = self.tx.send(Command::ListBans {
+            player_id,
+            character_name,
+        });
+    }
+
+    pub fn list_player_quests(&self, player_id: i32, name: String) {
+        let _ = self.tx.send(Command::ListPlayerQuests { player_id, name });
+    }
+
+    pub fn list_reports(&self, player_id: i32, report_id: Option<i32>) {
+        let _ = self.tx.send(Command::ListReports {
+            player_id,
+            report_id,
+        });
+    }
+
     pub async fn load_maps(&self) -> Result<(), String> {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::LoadMapFiles {
@@ -369,6 +440,19 @@ impl WorldHandle {
         let _ = self.tx.send(Command::Quake { magnitude });
     }
 
+    pub fn record_admin_action(
+        &self,
+        character: Box<Character>,
+        command: String,
+        args: Vec<String>,
+    ) {
+        let _ = self.tx.send(Command::RecordAdminAction {
+            character,
+            command,
+            args,
+        });
+    }
+
     pub fn report_player(&self, player_id: i32, reportee_name: String, message: String) {
         let _ = self.tx.send(Command::ReportPlayer {
             player_id,
@@ -421,10 +505,33 @@ impl WorldHandle {
         let _ = self.tx.send(Command::ReloadMap { map_id });
     }
 
+    pub fn reload_quests(&self, player_id: i32) {
+        let _ = self.tx.send(Command::ReloadQuests { player_id });
+    }
+
+    pub fn resolve_report(
+        &self,
+        player_id: i32,
+        admin_name: String,
+        report_id: i32,
+        resolution: String,
+    ) {
+        let _ = self.tx.send(Command::ResolveReport {
+            player_id,
+            admin_name,
+            report_id,
+            resolution,
+        });
+    }
+
     pub fn save(&self) {
         let _ = self.tx.send(Command::Save);
     }
 
+    pub fn notify_admins(&self, name: String, message: String) {
+        let _ = self.tx.send(Command::NotifyAdmins { name, message });
+    }
+
     pub fn send_admin_message(&self, player_id: i32, message: String) {
         let _ = self
             .tx
@@ -447,6 +554,26 @@ impl WorldHandle {
         });
     }
 
+    pub fn set_maintenance(&self, admin_name: String, enabled: Option<bool>, kick: bool) {
+        let _ = self.tx.send(Command::SetMaintenance {
+            admin_name,
+            enabled,
+            kick,
+        });
+    }
+
+    pub fn set_time(&self, player_id: i32, hour: Option<u32>) {
+        let _ = self.tx.send(Command::SetTime { player_id, hour });
+    }
+
+    pub fn set_weather(&self, player_id: i32, map_id: i32, weather: Option<String>) {
+        let _ = self.tx.send(Command::SetWeather {
+            player_id,
+            map_id,
+            weather,
+        });
+    }
+
     pub fn show_captcha(&self, victim_name: String, experience: i32) {
         let _ = self.tx.send(Command::ShowCaptcha {
             victim_name,
@@ -463,14 +590,35 @@ impl WorldHandle {
             .map_err(|_| "Failed to shutdown. Channel closed".to_string())
     }
 
+    pub fn start_shutdown(&self, admin_name: String, seconds: i32, reason: String) {
+        let _ = self.tx.send(Command::StartShutdown {
+            admin_name,
+            seconds,
+            reason,
+        });
+    }
+
+    /// Resolves once a shutdown countdown runs out
+    pub async fn wait_for_shutdown(&self) {
+        self.shutdown_notify.notified().await;
+    }
+
     pub fn tick(&self) {
         let _ = self.tx.send(Command::Tick);
     }
 
+    pub fn toggle_event(&self, player_id: i32, name: Option<String>) {
+        let _ = self.tx.send(Command::ToggleEvent { player_id, name });
+    }
+
     pub fn toggle_global(&self, admin_name: String) {
         let _ = self.tx.send(Command::ToggleGlobal { admin_name });
     }
 
+    pub fn unban_player(&self, player_id: i32, target: String) {
+        let _ = self.tx.send(Command::UnbanPlayer { player_id, target });
+    }
+
     pub fn unfreeze_player(&self, victim_name: String, admin_name: String) {
         let _ = self.tx.send(Command::UnfreezePlayer {
             victim_name,
@@ -491,6 +639,29 @@ impl WorldHandle {
             exp_gains,
         });
     }
+
+    pub fn update_player_quest(
+        &self,
+        player_id: i32,
+        name: String,
+        quest_id: i32,
+        update: QuestUpdate,
+    ) {
+        let _ = self.tx.send(Command::UpdatePlayerQuest {
+            player_id,
+            name,
+            quest_id,
+            update,
+        });
+    }
+
+    pub fn view_player_quest(&self, player_id: i32, name: String, quest_id: i32) {
+        let _ = self.tx.send(Command::ViewPlayerQuest {
+            player_id,
+            name,
+            quest_id,
+        });
+    }
 }
 
 async fn run_world(mut world: World) {